@@ -104,6 +104,11 @@ async fn main() -> Result<(), ObsError> {
         .streaming_body(body)
         .content_length(file_size)
         .content_type("image/jpeg")
+        .progress_listener(|transferred: u64, total: Option<u64>| {
+            if let Some(total) = total {
+                println!("Progress: {}/{} bytes", transferred, total);
+            }
+        })
         .send()
         .await?;
 
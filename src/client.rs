@@ -110,11 +110,164 @@ impl Client {
         DeleteBucketFluentBuilder::new(self.clone())
     }
 
+    /// Check that a bucket exists and get its metadata.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// match client.head_bucket().bucket("my-bucket").send().await {
+    ///     Ok(result) => println!("Location: {:?}", result.location()),
+    ///     Err(ObsError::NoSuchBucket(_)) => println!("Bucket does not exist"),
+    ///     Err(e) => return Err(e),
+    /// }
+    /// ```
+    pub fn head_bucket(&self) -> HeadBucketFluentBuilder {
+        HeadBucketFluentBuilder::new(self.clone())
+    }
+
     /// Get bucket location.
     pub fn get_bucket_location(&self) -> GetBucketLocationFluentBuilder {
         GetBucketLocationFluentBuilder::new(self.clone())
     }
 
+    /// Set the bucket policy.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use huaweicloud_sdk_rust_obs::{Policy, Statement};
+    ///
+    /// client.put_bucket_policy()
+    ///     .bucket("my-bucket")
+    ///     .policy(Policy::new().statement(Statement::public_read("my-bucket", "public/")))
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn put_bucket_policy(&self) -> PutBucketPolicyFluentBuilder {
+        PutBucketPolicyFluentBuilder::new(self.clone())
+    }
+
+    /// Get the bucket policy.
+    pub fn get_bucket_policy(&self) -> GetBucketPolicyFluentBuilder {
+        GetBucketPolicyFluentBuilder::new(self.clone())
+    }
+
+    /// Delete the bucket policy.
+    pub fn delete_bucket_policy(&self) -> DeleteBucketPolicyFluentBuilder {
+        DeleteBucketPolicyFluentBuilder::new(self.clone())
+    }
+
+    /// Set the bucket lifecycle rules, replacing any existing rules.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use huaweicloud_sdk_rust_obs::{LifecycleRule, StorageClass};
+    ///
+    /// client.put_bucket_lifecycle()
+    ///     .bucket("my-bucket")
+    ///     .rule(
+    ///         LifecycleRule::new("archive-logs")
+    ///             .prefix("logs/")
+    ///             .transition_after_days(30, StorageClass::Warm)
+    ///             .expire_after_days(365),
+    ///     )
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn put_bucket_lifecycle(&self) -> PutBucketLifecycleFluentBuilder {
+        PutBucketLifecycleFluentBuilder::new(self.clone())
+    }
+
+    /// Get the bucket lifecycle rules.
+    pub fn get_bucket_lifecycle(&self) -> GetBucketLifecycleFluentBuilder {
+        GetBucketLifecycleFluentBuilder::new(self.clone())
+    }
+
+    /// Delete the bucket lifecycle rules.
+    pub fn delete_bucket_lifecycle(&self) -> DeleteBucketLifecycleFluentBuilder {
+        DeleteBucketLifecycleFluentBuilder::new(self.clone())
+    }
+
+    /// Set the bucket CORS rules, replacing any existing rules.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use huaweicloud_sdk_rust_obs::{CorsMethod, CorsRule};
+    ///
+    /// client.put_bucket_cors()
+    ///     .bucket("my-bucket")
+    ///     .rule(
+    ///         CorsRule::new()
+    ///             .allow_origin("https://app.example.com")
+    ///             .allow_method(CorsMethod::Get)
+    ///             .allow_header("*")
+    ///             .max_age(3600),
+    ///     )
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn put_bucket_cors(&self) -> PutBucketCorsFluentBuilder {
+        PutBucketCorsFluentBuilder::new(self.clone())
+    }
+
+    /// Get the bucket CORS rules.
+    pub fn get_bucket_cors(&self) -> GetBucketCorsFluentBuilder {
+        GetBucketCorsFluentBuilder::new(self.clone())
+    }
+
+    /// Delete the bucket CORS rules.
+    pub fn delete_bucket_cors(&self) -> DeleteBucketCorsFluentBuilder {
+        DeleteBucketCorsFluentBuilder::new(self.clone())
+    }
+
+    /// Send a CORS preflight (OPTIONS) request for a bucket.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use huaweicloud_sdk_rust_obs::CorsMethod;
+    ///
+    /// let result = client.options_bucket()
+    ///     .bucket("my-bucket")
+    ///     .origin("https://app.example.com")
+    ///     .request_method(CorsMethod::Put)
+    ///     .send()
+    ///     .await?;
+    /// println!("Allowed methods: {:?}", result.cors().allow_methods());
+    /// ```
+    pub fn options_bucket(&self) -> OptionsBucketFluentBuilder {
+        OptionsBucketFluentBuilder::new(self.clone())
+    }
+
+    /// Set the static website hosting configuration of a bucket.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use huaweicloud_sdk_rust_obs::WebsiteConfiguration;
+    ///
+    /// client.put_bucket_website()
+    ///     .bucket("my-bucket")
+    ///     .configuration(WebsiteConfiguration::index("index.html").error_page("404.html"))
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn put_bucket_website(&self) -> PutBucketWebsiteFluentBuilder {
+        PutBucketWebsiteFluentBuilder::new(self.clone())
+    }
+
+    /// Get the static website hosting configuration of a bucket.
+    pub fn get_bucket_website(&self) -> GetBucketWebsiteFluentBuilder {
+        GetBucketWebsiteFluentBuilder::new(self.clone())
+    }
+
+    /// Delete the static website hosting configuration of a bucket.
+    pub fn delete_bucket_website(&self) -> DeleteBucketWebsiteFluentBuilder {
+        DeleteBucketWebsiteFluentBuilder::new(self.clone())
+    }
+
     /// List objects in a bucket.
     ///
     /// # Example
@@ -140,6 +293,70 @@ impl Client {
         ListObjectsV2FluentBuilder::new(self.clone())
     }
 
+    /// Enable WORM on a bucket and set its default object retention.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// client.put_bucket_object_lock_configuration()
+    ///     .bucket("my-bucket")
+    ///     .mode("COMPLIANCE")
+    ///     .days(365)
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn put_bucket_object_lock_configuration(
+        &self,
+    ) -> PutBucketObjectLockConfigurationFluentBuilder {
+        PutBucketObjectLockConfigurationFluentBuilder::new(self.clone())
+    }
+
+    /// Get the WORM configuration of a bucket.
+    pub fn get_bucket_object_lock_configuration(
+        &self,
+    ) -> GetBucketObjectLockConfigurationFluentBuilder {
+        GetBucketObjectLockConfigurationFluentBuilder::new(self.clone())
+    }
+
+    /// Enable or suspend versioning on a bucket.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// client.put_bucket_versioning()
+    ///     .bucket("my-bucket")
+    ///     .status("Enabled")
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn put_bucket_versioning(&self) -> PutBucketVersioningFluentBuilder {
+        PutBucketVersioningFluentBuilder::new(self.clone())
+    }
+
+    /// Get the versioning status of a bucket.
+    pub fn get_bucket_versioning(&self) -> GetBucketVersioningFluentBuilder {
+        GetBucketVersioningFluentBuilder::new(self.clone())
+    }
+
+    /// List the versions and delete markers of objects in a bucket.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// let result = client.list_object_versions()
+    ///     .bucket("my-bucket")
+    ///     .prefix("reports/")
+    ///     .send()
+    ///     .await?;
+    ///
+    /// for version in result.versions() {
+    ///     println!("{} {} latest={}", version.key(), version.version_id(), version.is_latest());
+    /// }
+    /// ```
+    pub fn list_object_versions(&self) -> ListObjectVersionsFluentBuilder {
+        ListObjectVersionsFluentBuilder::new(self.clone())
+    }
+
     // ========================================
     // Object Operations
     // ========================================
@@ -177,6 +394,16 @@ impl Client {
     /// let data = result.body().bytes().await?;
     /// println!("Content: {:?}", data);
     /// ```
+    ///
+    /// Images can be processed on download, or shared through a presigned URL:
+    ///
+    /// ```rust,no_run
+    /// let url = client.get_object()
+    ///     .bucket("my-bucket")
+    ///     .key("photo.jpg")
+    ///     .image_process(ImageProcess::new().resize(Resize::new().width(200)).format("webp"))
+    ///     .presigned_url(Duration::from_secs(3600))?;
+    /// ```
     pub fn get_object(&self) -> GetObjectFluentBuilder {
         GetObjectFluentBuilder::new(self.clone())
     }
@@ -197,10 +424,38 @@ impl Client {
     }
 
     /// Delete multiple objects from a bucket.
+    ///
+    /// Any number of keys can be added; they are deleted in batches of 1000.
     pub fn delete_objects(&self) -> DeleteObjectsFluentBuilder {
         DeleteObjectsFluentBuilder::new(self.clone())
     }
 
+    /// Delete every object under a prefix.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// // See what would be deleted first
+    /// let preview = client.delete_prefix()
+    ///     .bucket("my-bucket")
+    ///     .prefix("tmp/")
+    ///     .dry_run(true)
+    ///     .send()
+    ///     .await?;
+    /// println!("{} objects", preview.deleted().len());
+    ///
+    /// let result = client.delete_prefix()
+    ///     .bucket("my-bucket")
+    ///     .prefix("tmp/")
+    ///     .all_versions(true)
+    ///     .send()
+    ///     .await?;
+    /// assert!(result.is_all_success());
+    /// ```
+    pub fn delete_prefix(&self) -> DeletePrefixFluentBuilder {
+        DeletePrefixFluentBuilder::new(self.clone())
+    }
+
     /// Copy an object.
     ///
     /// # Example
@@ -222,11 +477,82 @@ impl Client {
         HeadObjectFluentBuilder::new(self.clone())
     }
 
+    /// Send a CORS preflight (OPTIONS) request for an object.
+    pub fn options_object(&self) -> OptionsObjectFluentBuilder {
+        OptionsObjectFluentBuilder::new(self.clone())
+    }
+
     /// Append to an object.
     pub fn append_object(&self) -> AppendObjectFluentBuilder {
         AppendObjectFluentBuilder::new(self.clone())
     }
 
+    /// Create an [`AsyncWrite`](tokio::io::AsyncWrite) that appends to an object.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tokio::io::AsyncWriteExt;
+    ///
+    /// let mut writer = client.append_writer()
+    ///     .bucket("my-bucket")
+    ///     .key("logs/app.log")
+    ///     .flush_size(256 * 1024)
+    ///     .build()
+    ///     .await?;
+    ///
+    /// writer.write_all(b"line 1\n").await?;
+    /// writer.shutdown().await?;
+    /// ```
+    pub fn append_writer(&self) -> AppendWriterBuilder {
+        AppendWriterBuilder::new(self.clone())
+    }
+
+    /// Create an [`AsyncWrite`](tokio::io::AsyncWrite) that uploads an object of unknown length.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tokio::io::AsyncWriteExt;
+    ///
+    /// let mut writer = client.object_writer()
+    ///     .bucket("my-bucket")
+    ///     .key("exports/report.csv")
+    ///     .part_size(16 * 1024 * 1024)
+    ///     .build()?;
+    ///
+    /// writer.write_all(b"id,name\n").await?;
+    /// writer.shutdown().await?;
+    /// ```
+    pub fn object_writer(&self) -> ObjectWriterBuilder {
+        ObjectWriterBuilder::new(self.clone())
+    }
+
+    /// Open an [`AsyncRead`](tokio::io::AsyncRead) + [`AsyncSeek`](tokio::io::AsyncSeek)
+    /// over an object, backed by range requests.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::io::SeekFrom;
+    /// use tokio::io::{AsyncReadExt, AsyncSeekExt};
+    ///
+    /// let mut reader = client.object_reader()
+    ///     .bucket("my-bucket")
+    ///     .key("archives/logs.zip")
+    ///     .block_size(256 * 1024)
+    ///     .build()
+    ///     .await?;
+    ///
+    /// // Read the end of central directory record of the zip file
+    /// let mut eocd = [0u8; 22];
+    /// reader.seek(SeekFrom::End(-22)).await?;
+    /// reader.read_exact(&mut eocd).await?;
+    /// ```
+    pub fn object_reader(&self) -> ObjectReaderBuilder {
+        ObjectReaderBuilder::new(self.clone())
+    }
+
     /// Set object ACL.
     pub fn set_object_acl(&self) -> SetObjectAclFluentBuilder {
         SetObjectAclFluentBuilder::new(self.clone())
@@ -237,6 +563,156 @@ impl Client {
         GetObjectAclFluentBuilder::new(self.clone())
     }
 
+    /// Set the tags of an object, replacing any existing tags.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// client.put_object_tagging()
+    ///     .bucket("my-bucket")
+    ///     .key("data.csv")
+    ///     .tag("project", "lake")
+    ///     .tag("cost-center", "42")
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn put_object_tagging(&self) -> PutObjectTaggingFluentBuilder {
+        PutObjectTaggingFluentBuilder::new(self.clone())
+    }
+
+    /// Get the tags of an object.
+    pub fn get_object_tagging(&self) -> GetObjectTaggingFluentBuilder {
+        GetObjectTaggingFluentBuilder::new(self.clone())
+    }
+
+    /// Remove all tags from an object.
+    pub fn delete_object_tagging(&self) -> DeleteObjectTaggingFluentBuilder {
+        DeleteObjectTaggingFluentBuilder::new(self.clone())
+    }
+
+    /// Set the WORM retention of an object.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// client.put_object_retention()
+    ///     .bucket("my-bucket")
+    ///     .key("ledger/2024.csv")
+    ///     .retain_until_date(Utc::now() + chrono::Duration::days(365))
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn put_object_retention(&self) -> PutObjectRetentionFluentBuilder {
+        PutObjectRetentionFluentBuilder::new(self.clone())
+    }
+
+    /// Get the WORM retention of an object.
+    pub fn get_object_retention(&self) -> GetObjectRetentionFluentBuilder {
+        GetObjectRetentionFluentBuilder::new(self.clone())
+    }
+
+    /// Modify object metadata in place without rewriting the object data.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// client.set_object_metadata()
+    ///     .bucket("my-bucket")
+    ///     .key("report.json")
+    ///     .metadata_directive("REPLACE_NEW")
+    ///     .content_type("application/json")
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn set_object_metadata(&self) -> SetObjectMetadataFluentBuilder {
+        SetObjectMetadataFluentBuilder::new(self.clone())
+    }
+
+    /// Restore an archived (Cold/Deep Archive) object.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// client.restore_object()
+    ///     .bucket("my-bucket")
+    ///     .key("archive/2019.parquet")
+    ///     .days(7)
+    ///     .tier("Standard")
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn restore_object(&self) -> RestoreObjectFluentBuilder {
+        RestoreObjectFluentBuilder::new(self.clone())
+    }
+
+    /// Wait until a restore job on an archived object has completed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// let head = client.wait_until_restored()
+    ///     .bucket("my-bucket")
+    ///     .key("archive/2019.parquet")
+    ///     .poll_interval(Duration::from_secs(300))
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn wait_until_restored(&self) -> WaitUntilRestoredFluentBuilder {
+        WaitUntilRestoredFluentBuilder::new(self.clone())
+    }
+
+    // ========================================
+    // Parallel File System Operations
+    // ========================================
+
+    /// Rename an object in a parallel file system bucket.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// client.rename_object()
+    ///     .bucket("my-pfs-bucket")
+    ///     .key("data/part-0001.tmp")
+    ///     .new_key("data/part-0001.csv")
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn rename_object(&self) -> RenameObjectFluentBuilder {
+        RenameObjectFluentBuilder::new(self.clone())
+    }
+
+    /// Rename a directory in a parallel file system bucket.
+    pub fn rename_directory(&self) -> RenameDirectoryFluentBuilder {
+        RenameDirectoryFluentBuilder::new(self.clone())
+    }
+
+    /// Truncate an object in a parallel file system bucket.
+    pub fn truncate_object(&self) -> TruncateObjectFluentBuilder {
+        TruncateObjectFluentBuilder::new(self.clone())
+    }
+
+    /// Write data at a given position of an object in a parallel file system bucket.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// client.modify_object()
+    ///     .bucket("my-pfs-bucket")
+    ///     .key("data/matrix.bin")
+    ///     .position(4096)
+    ///     .body(vec![0u8; 512])
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn modify_object(&self) -> ModifyObjectFluentBuilder {
+        ModifyObjectFluentBuilder::new(self.clone())
+    }
+
+    /// Recursively delete a directory and everything below it.
+    pub fn delete_directory(&self) -> DeleteDirectoryFluentBuilder {
+        DeleteDirectoryFluentBuilder::new(self.clone())
+    }
+
     // ========================================
     // Multipart Upload Operations
     // ========================================
@@ -374,6 +850,90 @@ impl Client {
         AbortMultipartUploadFluentBuilder::new(self.clone())
     }
 
+    // ========================================
+    // Transfer Operations
+    // ========================================
+
+    /// Download an object to a local file with parallel, resumable ranged requests.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// let result = client.download_file()
+    ///     .bucket("my-bucket")
+    ///     .key("backups/db.tar.gz")
+    ///     .file("/data/db.tar.gz")
+    ///     .part_size(16 * 1024 * 1024)
+    ///     .concurrency(8)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// println!("Downloaded {} bytes", result.content_length());
+    /// ```
+    pub fn download_file(&self) -> DownloadFileFluentBuilder {
+        DownloadFileFluentBuilder::new(self.clone())
+    }
+
+    /// Upload a local file with parallel, resumable multipart upload.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// let result = client.upload_file()
+    ///     .bucket("my-bucket")
+    ///     .key("backups/db.tar.gz")
+    ///     .file("/data/db.tar.gz")
+    ///     .concurrency(8)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// if result.resumed() {
+    ///     println!("Resumed after {} bytes", result.resumed_bytes());
+    /// }
+    /// ```
+    pub fn upload_file(&self) -> UploadFileFluentBuilder {
+        UploadFileFluentBuilder::new(self.clone())
+    }
+
+    /// Create a transfer manager for many concurrent uploads and downloads.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// let manager = client.transfer_manager()
+    ///     .multipart_threshold(64 * 1024 * 1024)
+    ///     .max_concurrency(32)
+    ///     .max_memory(512 * 1024 * 1024)
+    ///     .build()?;
+    ///
+    /// let upload = manager.upload_file("my-bucket", "videos/intro.mp4", "/data/intro.mp4");
+    /// let download = manager.download_directory("my-bucket", "reports/", "/data/reports");
+    ///
+    /// upload.join().await?;
+    /// download.join().await?;
+    /// ```
+    pub fn transfer_manager(&self) -> TransferManagerBuilder {
+        TransferManagerBuilder::new(self.clone())
+    }
+
+    /// Copy an object of any size, using parallel CopyPart requests for large sources.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// let result = client.copy_large_object()
+    ///     .bucket("archive-bucket")
+    ///     .key("backups/db.tar")
+    ///     .copy_source("source-bucket/backups/db.tar")
+    ///     .concurrency(16)
+    ///     .send()
+    ///     .await?;
+    /// println!("Copied {} bytes", result.size());
+    /// ```
+    pub fn copy_large_object(&self) -> CopyLargeObjectFluentBuilder {
+        CopyLargeObjectFluentBuilder::new(self.clone())
+    }
+
     // ========================================
     // Internal Methods
     // ========================================
@@ -496,6 +1056,54 @@ impl Client {
         Ok(res)
     }
 
+    /// Build a presigned URL using query string authentication.
+    ///
+    /// The URL can be used without credentials until it expires.
+    pub(crate) fn presign_url(
+        &self,
+        method: Method,
+        bucket: &str,
+        key: &str,
+        params: Option<HashMap<String, String>>,
+        expires_in: Duration,
+    ) -> Result<String> {
+        if expires_in.is_zero() {
+            return Err(ObsError::InvalidInput(
+                "presigned URL expiry must be greater than zero".to_string(),
+            ));
+        }
+
+        let mut params = params.unwrap_or_default();
+        if let Some(token) = self.config.credentials().security_token() {
+            params.insert("x-obs-security-token".to_string(), token.to_string());
+        }
+
+        let (request_uri, canonicalized_url) =
+            self.format_urls(Some(bucket), Some(key), Some(&params));
+
+        // With query authentication the expiry time takes the place of the Date header.
+        let expires = (chrono::Utc::now().timestamp() + expires_in.as_secs() as i64).to_string();
+        let mut headers = HashMap::new();
+        headers.insert("Date".to_string(), vec![expires.clone()]);
+        let signature = self.signature(method.as_str(), headers, canonicalized_url)?;
+
+        let separator = if request_uri.contains('?') { '&' } else { '?' };
+        let url = format!(
+            "https://{}.{}/{}{}AccessKeyId={}&Expires={}&Signature={}",
+            bucket,
+            self.config.region().endpoint(),
+            request_uri,
+            separator,
+            urlencoding::encode(self.config.credentials().access_key_id()),
+            expires,
+            urlencoding::encode(&signature)
+        );
+
+        let url = reqwest::Url::parse(&url)
+            .map_err(|e| ObsError::InvalidInput(format!("Invalid presigned URL: {}", e)))?;
+        Ok(url.to_string())
+    }
+
     /// Format URLs for the request.
     fn format_urls(
         &self,
@@ -526,10 +1134,12 @@ impl Client {
                         canonicalized_resource.push('?');
                         let mut uri_params = vec![];
                         for (k, v) in &sorted_params {
+                            // Values are percent-encoded in the URL (object keys in
+                            // `name`, prefixes, markers) but signed as-is
                             if v.is_empty() {
                                 uri_params.push(k.to_string());
                             } else {
-                                uri_params.push(format!("{}={}", k, v));
+                                uri_params.push(format!("{}={}", k, urlencoding::encode(v)));
                             }
                             if crate::config::SUB_RESOURCES.contains(&k.as_str()) {
                                 if !canonicalized_resource.ends_with('?') {
@@ -649,3 +1259,33 @@ impl std::fmt::Display for Client {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_urls_encodes_query_values() {
+        let config = Config::builder()
+            .access_key("ak", "sk")
+            .endpoint("obs.cn-north-4.myhuaweicloud.com")
+            .build()
+            .unwrap();
+        let client = Client::from_config(config).unwrap();
+
+        let mut params = HashMap::new();
+        params.insert("rename".to_string(), String::new());
+        params.insert("name".to_string(), "dir/a&b +测试.txt".to_string());
+        let (uri, canonicalized) =
+            client.format_urls(Some("bucket"), Some("dir/old.txt"), Some(&params));
+
+        assert_eq!(
+            uri,
+            "dir/old.txt?name=dir%2Fa%26b%20%2B%E6%B5%8B%E8%AF%95.txt&rename"
+        );
+        assert_eq!(
+            canonicalized,
+            "/bucket/dir/old.txt?name=dir/a&b +测试.txt&rename"
+        );
+    }
+}
@@ -40,6 +40,10 @@ pub enum ObsError {
     #[error("failed to parse XML response: {0}")]
     XmlParse(String),
 
+    /// Error occurred while parsing JSON response.
+    #[error("failed to parse JSON response: {0}")]
+    JsonParse(String),
+
     /// Error occurred while serializing request body.
     #[error("failed to serialize request: {0}")]
     Serialization(String),
@@ -48,6 +52,32 @@ pub enum ObsError {
     #[error("invalid input: {0}")]
     InvalidInput(String),
 
+    /// An append was sent at a position that does not match the object length
+    /// (OBS error code `PositionNotEqualToLength`).
+    #[error("append position {position} does not match the object length (next append position: {next_position:?})")]
+    AppendPositionConflict {
+        /// Position the data was appended at
+        position: u64,
+        /// Next append position reported by OBS, if known
+        next_position: Option<u64>,
+    },
+
+    /// The bucket does not exist (HTTP 404 without an error body, e.g. from HeadBucket).
+    #[error("bucket {0} does not exist")]
+    NoSuchBucket(String),
+
+    /// Access to the bucket is denied (HTTP 403 without an error body, e.g. from HeadBucket).
+    #[error("access to bucket {0} is denied")]
+    BucketAccessDenied(String),
+
+    /// The operation was cancelled before it completed.
+    #[error("operation cancelled")]
+    Cancelled,
+
+    /// The operation did not complete within the allotted time.
+    #[error("operation timed out: {0}")]
+    Timeout(String),
+
     /// IO error occurred.
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
@@ -121,6 +151,9 @@ impl ObsError {
         match self {
             ObsError::ServiceError { status, .. } => Some(*status),
             ObsError::HttpError(e) => e.status(),
+            ObsError::AppendPositionConflict { .. } => Some(StatusCode::CONFLICT),
+            ObsError::NoSuchBucket(_) => Some(StatusCode::NOT_FOUND),
+            ObsError::BucketAccessDenied(_) => Some(StatusCode::FORBIDDEN),
             _ => None,
         }
     }
@@ -68,9 +68,28 @@
 //! - [`Client::list_buckets`] - List all buckets
 //! - [`Client::create_bucket`] - Create a new bucket
 //! - [`Client::delete_bucket`] - Delete a bucket
+//! - [`Client::head_bucket`] - Check that a bucket exists and get its metadata
 //! - [`Client::get_bucket_location`] - Get bucket location
+//! - [`Client::put_bucket_policy`] - Set the bucket policy
+//! - [`Client::get_bucket_policy`] - Get the bucket policy
+//! - [`Client::delete_bucket_policy`] - Delete the bucket policy
+//! - [`Client::put_bucket_lifecycle`] - Set the bucket lifecycle rules
+//! - [`Client::get_bucket_lifecycle`] - Get the bucket lifecycle rules
+//! - [`Client::delete_bucket_lifecycle`] - Delete the bucket lifecycle rules
+//! - [`Client::put_bucket_cors`] - Set the bucket CORS rules
+//! - [`Client::get_bucket_cors`] - Get the bucket CORS rules
+//! - [`Client::delete_bucket_cors`] - Delete the bucket CORS rules
+//! - [`Client::options_bucket`] - Send a CORS preflight request for a bucket
+//! - [`Client::put_bucket_website`] - Set the static website hosting configuration
+//! - [`Client::get_bucket_website`] - Get the static website hosting configuration
+//! - [`Client::delete_bucket_website`] - Delete the static website hosting configuration
 //! - [`Client::list_objects`] - List objects in a bucket (v1)
 //! - [`Client::list_objects_v2`] - List objects in a bucket (v2)
+//! - [`Client::put_bucket_object_lock_configuration`] - Set the bucket WORM configuration
+//! - [`Client::get_bucket_object_lock_configuration`] - Get the bucket WORM configuration
+//! - [`Client::put_bucket_versioning`] - Enable or suspend bucket versioning
+//! - [`Client::get_bucket_versioning`] - Get the bucket versioning status
+//! - [`Client::list_object_versions`] - List object versions and delete markers
 //!
 //! ## Object Operations
 //!
@@ -78,79 +97,228 @@
 //! - [`Client::get_object`] - Download an object
 //! - [`Client::delete_object`] - Delete an object
 //! - [`Client::delete_objects`] - Delete multiple objects
+//! - [`Client::delete_prefix`] - Delete every object under a prefix
 //! - [`Client::copy_object`] - Copy an object
 //! - [`Client::head_object`] - Get object metadata
+//! - [`Client::options_object`] - Send a CORS preflight request for an object
 //! - [`Client::append_object`] - Append to an object
+//! - [`Client::append_writer`] - Stream data into an appendable object
+//! - [`Client::object_reader`] - Random access to an object with range reads
+//! - [`Client::object_writer`] - Stream data of unknown length into an object
+//! - [`Client::restore_object`] - Restore an archived object
+//! - [`Client::set_object_metadata`] - Modify object metadata in place
+//! - [`Client::put_object_tagging`] - Set object tags
+//! - [`Client::get_object_tagging`] - Get object tags
+//! - [`Client::delete_object_tagging`] - Delete object tags
+//! - [`Client::put_object_retention`] - Set object WORM retention
+//! - [`Client::get_object_retention`] - Get object WORM retention
+//!
+//! ## Parallel File System Operations
+//!
+//! - [`Client::rename_object`] - Rename an object
+//! - [`Client::rename_directory`] - Rename a directory
+//! - [`Client::truncate_object`] - Truncate an object
+//! - [`Client::modify_object`] - Write data at a position of an object
+//! - [`Client::delete_directory`] - Recursively delete a directory
+//!
+//! ## Transfer Operations
+//!
+//! - [`Client::download_file`] - Resumable, parallel download to a file
+//! - [`Client::upload_file`] - Resumable, parallel multipart upload of a file
+//! - [`Client::transfer_manager`] - Concurrent file and directory transfers under shared limits
+//! - [`Client::copy_large_object`] - Server-side copy of objects larger than 5 GB
 
 pub mod auth;
 pub mod client;
 pub mod config;
 pub mod error;
 pub mod operations;
+pub mod progress;
 mod xml_utils;
 
 // Re-export main types for convenience
 pub use client::{Client, ClientBuilder};
 pub use config::{Config, ConfigBuilder, Credentials, Region, SignatureType};
 pub use error::{ObsError, Result};
+pub use progress::ProgressListener;
 
 // Re-export operation types
 pub use operations::{
     AbortMultipartUploadFluentBuilder,
     AbortMultipartUploadOutput,
+    AccessControlPolicy,
     AppendObjectFluentBuilder,
     AppendObjectOutput,
+    AppendWriter,
+    AppendWriterBuilder,
 
     Bucket,
+    CannedAcl,
     CompleteMultipartUploadFluentBuilder,
     CompleteMultipartUploadOutput,
     CompletedPart,
+    CopyLargeObjectFluentBuilder,
+    CopyLargeObjectOutput,
     CopyObjectFluentBuilder,
     CopyObjectOutput,
     CopyPartFluentBuilder,
     CopyPartOutput,
+    CorsHeaders,
+    CorsMethod,
+    CorsRule,
     CreateBucketFluentBuilder,
     CreateBucketOutput,
+    DefaultRetention,
+    DeleteBucketCorsFluentBuilder,
+    DeleteBucketCorsOutput,
     DeleteBucketFluentBuilder,
+    DeleteBucketLifecycleFluentBuilder,
+    DeleteBucketLifecycleOutput,
     DeleteBucketOutput,
+    DeleteBucketPolicyFluentBuilder,
+    DeleteBucketPolicyOutput,
+    DeleteBucketWebsiteFluentBuilder,
+    DeleteBucketWebsiteOutput,
+    DeleteDirectoryFluentBuilder,
+    DeleteDirectoryOutput,
+    DeleteMarkerEntry,
     DeleteObjectFluentBuilder,
     DeleteObjectOutput,
+    DeleteObjectTaggingFluentBuilder,
+    DeleteObjectTaggingOutput,
     DeleteObjectsFluentBuilder,
     DeleteObjectsOutput,
+    DeletePrefixFluentBuilder,
+    DeletePrefixOutput,
+    DirectoryTransferOutput,
+    DownloadFileFluentBuilder,
+    DownloadFileOutput,
+    Effect,
+    GetBucketCorsFluentBuilder,
+    GetBucketCorsOutput,
+    GetBucketLifecycleFluentBuilder,
+    GetBucketLifecycleOutput,
     GetBucketLocationFluentBuilder,
     GetBucketLocationOutput,
+    GetBucketObjectLockConfigurationFluentBuilder,
+    GetBucketObjectLockConfigurationOutput,
+    GetBucketPolicyFluentBuilder,
+    GetBucketPolicyOutput,
+    GetBucketVersioningFluentBuilder,
+    GetBucketVersioningOutput,
+    GetBucketWebsiteFluentBuilder,
+    GetBucketWebsiteOutput,
     GetObjectFluentBuilder,
     GetObjectOutput,
+    GetObjectRetentionFluentBuilder,
+    GetObjectRetentionOutput,
+    GetObjectTaggingFluentBuilder,
+    GetObjectTaggingOutput,
+    Grant,
+    GrantHeaders,
+    Grantee,
+    Gravity,
+    HeadBucketFluentBuilder,
+    HeadBucketOutput,
     HeadObjectFluentBuilder,
     HeadObjectOutput,
+    ImageProcess,
+    ImageWatermark,
     InitiateMultipartUploadFluentBuilder,
     InitiateMultipartUploadOutput,
+    LifecycleRule,
+    LifecycleTime,
+    LifecycleTransition,
     // Bucket operations
     ListBucketsFluentBuilder,
     ListBucketsOutput,
     // Multipart upload operations
     ListMultipartUploadsFluentBuilder,
     ListMultipartUploadsOutput,
+    ListObjectVersionsFluentBuilder,
+    ListObjectVersionsOutput,
     ListObjectsFluentBuilder,
     ListObjectsOutput,
     ListObjectsV2FluentBuilder,
     ListObjectsV2Output,
     ListPartsFluentBuilder,
     ListPartsOutput,
+    ModifyObjectFluentBuilder,
+    ModifyObjectOutput,
     MultipartInitiator,
     MultipartOwner,
     MultipartUpload,
+    NoncurrentVersionTransition,
     ObjectInfo,
+    ObjectReader,
+    ObjectReaderBuilder,
+    ObjectRetention,
+    ObjectVersion,
+    ObjectWriter,
+    ObjectWriterBuilder,
 
+    OptionsBucketFluentBuilder,
+    OptionsBucketOutput,
+    OptionsObjectFluentBuilder,
+    OptionsObjectOutput,
     Owner,
     PartInfo,
+    Permission,
+    Policy,
+    Principal,
+    PutBucketCorsFluentBuilder,
+    PutBucketCorsOutput,
+    PutBucketLifecycleFluentBuilder,
+    PutBucketLifecycleOutput,
+    PutBucketObjectLockConfigurationFluentBuilder,
+    PutBucketObjectLockConfigurationOutput,
+    PutBucketPolicyFluentBuilder,
+    PutBucketPolicyOutput,
+    PutBucketVersioningFluentBuilder,
+    PutBucketVersioningOutput,
+    PutBucketWebsiteFluentBuilder,
+    PutBucketWebsiteOutput,
     PutObjectBody,
     // Object operations
     PutObjectFluentBuilder,
     PutObjectOutput,
+    PutObjectRetentionFluentBuilder,
+    PutObjectRetentionOutput,
+    PutObjectTaggingFluentBuilder,
+    PutObjectTaggingOutput,
+    RedirectAllRequestsTo,
+    RenameDirectoryFluentBuilder,
+    RenameObjectFluentBuilder,
+    RenameObjectOutput,
+    ReplaceKey,
+    Resize,
+    RestoreObjectFluentBuilder,
+    RestoreObjectOutput,
+    RestoreStatus,
+    RoutingRule,
+    SetObjectMetadataFluentBuilder,
+    SetObjectMetadataOutput,
+    Statement,
+    StorageClass,
+    Tag,
+    TagSet,
+    TextWatermark,
+    TransferFailure,
+    TransferHandle,
+    TransferManager,
+    TransferManagerBuilder,
+    TransferOutput,
+    TransferStatus,
+    TruncateObjectFluentBuilder,
+    TruncateObjectOutput,
+    UploadFileFluentBuilder,
+    UploadFileOutput,
     UploadPartBody,
     UploadPartFluentBuilder,
     UploadPartOutput,
+    WaitUntilRestoredFluentBuilder,
+    WebsiteConfiguration,
+    WebsiteProtocol,
 };
 
 /// Prelude module for convenient imports.
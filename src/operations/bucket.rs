@@ -1,12 +1,19 @@
 //! Bucket operations - fluent builders for OBS bucket operations.
 
-use std::collections::HashMap;
-
-use reqwest::Method;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveTime, Utc};
+use reqwest::{
+    header::{HeaderMap, HeaderValue},
+    Method, StatusCode,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::client::Client;
 use crate::error::{ObsError, Result};
+use crate::operations::{parse_timestamp, CannedAcl, GrantHeaders, StorageClass, Tag};
 
 // ========================================
 // List Buckets
@@ -120,6 +127,11 @@ impl Bucket {
         &self.creation_date
     }
 
+    /// Get the creation date as a UTC timestamp (`None` if it cannot be parsed).
+    pub fn creation_time(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&self.creation_date)
+    }
+
     /// Get the location.
     pub fn location(&self) -> &str {
         &self.location
@@ -178,6 +190,76 @@ impl CreateBucketFluentBuilder {
         self
     }
 
+    /// Set a canned ACL (predefined ACL).
+    pub fn canned_acl(mut self, acl: CannedAcl) -> Self {
+        self.inner.acl = Some(acl);
+        self
+    }
+
+    /// Set the grants of the bucket, sent as `x-obs-grant-*` headers.
+    pub fn grants(mut self, grants: GrantHeaders) -> Self {
+        self.inner.grants = grants;
+        self
+    }
+
+    /// Set the default storage class of the bucket.
+    ///
+    /// Possible values: STANDARD, WARM, COLD, DEEP_ARCHIVE.
+    pub fn storage_class(mut self, storage_class: impl Into<String>) -> Self {
+        self.inner.storage_class = Some(storage_class.into());
+        self
+    }
+
+    /// Set whether the bucket stores data in multiple AZs.
+    ///
+    /// Cannot be changed after the bucket is created.
+    pub fn multi_az(mut self, multi_az: bool) -> Self {
+        self.inner.multi_az = multi_az;
+        self
+    }
+
+    /// Set whether to create a parallel file system instead of an object bucket.
+    pub fn parallel_file_system(mut self, enabled: bool) -> Self {
+        self.inner.parallel_file_system = enabled;
+        self
+    }
+
+    /// Set the enterprise project ID the bucket belongs to.
+    pub fn epid(mut self, epid: impl Into<String>) -> Self {
+        self.inner.epid = Some(epid.into());
+        self
+    }
+
+    /// Set whether WORM (object lock) is enabled for the bucket.
+    ///
+    /// Enabling it also enables versioning, and neither can be disabled later.
+    pub fn object_lock_enabled(mut self, enabled: bool) -> Self {
+        self.inner.object_lock_enabled = enabled;
+        self
+    }
+
+    /// Set the default server-side encryption of the bucket.
+    ///
+    /// Possible values: kms, AES256.
+    pub fn server_side_encryption(mut self, sse: impl Into<String>) -> Self {
+        self.inner.server_side_encryption = Some(sse.into());
+        self
+    }
+
+    /// Set the default server-side data encryption algorithm.
+    ///
+    /// Possible values: AES256, SM4.
+    pub fn server_side_data_encryption(mut self, algorithm: impl Into<String>) -> Self {
+        self.inner.server_side_data_encryption = Some(algorithm.into());
+        self
+    }
+
+    /// Set the KMS key ID for the default SSE-KMS encryption.
+    pub fn ssekms_key_id(mut self, key_id: impl Into<String>) -> Self {
+        self.inner.ssekms_key_id = Some(key_id.into());
+        self
+    }
+
     /// Send the request.
     pub async fn send(&self) -> Result<CreateBucketOutput> {
         let bucket = &self.inner.bucket;
@@ -187,6 +269,14 @@ impl CreateBucketFluentBuilder {
             ));
         }
 
+        if self.inner.ssekms_key_id.is_some()
+            && self.inner.server_side_encryption.as_deref() != Some("kms")
+        {
+            return Err(ObsError::InvalidInput(
+                "a KMS key ID requires kms server-side encryption".to_string(),
+            ));
+        }
+
         let default_location = self.client.config().region().name().to_string();
         let location = self
             .inner
@@ -199,13 +289,61 @@ impl CreateBucketFluentBuilder {
         };
         let body = crate::xml_utils::to_xml(&xml)?;
 
+        let mut headers = HeaderMap::new();
+        if let Some(acl) = self.inner.acl {
+            headers.insert("x-obs-acl", HeaderValue::from_static(acl.as_str()));
+        }
+        self.inner.grants.apply(&mut headers)?;
+        if self.inner.multi_az {
+            headers.insert("x-obs-az-redundancy", HeaderValue::from_static("3az"));
+        }
+        if self.inner.parallel_file_system {
+            headers.insert(
+                "x-obs-fs-file-interface",
+                HeaderValue::from_static("Enabled"),
+            );
+        }
+        if self.inner.object_lock_enabled {
+            headers.insert(
+                "x-obs-bucket-object-lock-enabled",
+                HeaderValue::from_static("true"),
+            );
+        }
+
+        let optional_headers = [
+            ("x-obs-storage-class", &self.inner.storage_class),
+            ("x-obs-epid", &self.inner.epid),
+            (
+                "x-obs-server-side-encryption",
+                &self.inner.server_side_encryption,
+            ),
+            (
+                "x-obs-server-side-data-encryption",
+                &self.inner.server_side_data_encryption,
+            ),
+            (
+                "x-obs-server-side-encryption-kms-key-id",
+                &self.inner.ssekms_key_id,
+            ),
+        ];
+        for (name, value) in optional_headers {
+            if let Some(value) = value {
+                headers.insert(
+                    name,
+                    HeaderValue::from_str(value).map_err(|e| {
+                        ObsError::InvalidInput(format!("Invalid {} header: {}", name, e))
+                    })?,
+                );
+            }
+        }
+
         let resp = self
             .client
             .do_request(
                 Method::PUT,
                 Some(bucket),
                 None,
-                None,
+                Some(headers),
                 None,
                 Some(body.into_bytes()),
             )
@@ -217,9 +355,13 @@ impl CreateBucketFluentBuilder {
             return Err(ObsError::service_error(status, &text));
         }
 
-        Ok(CreateBucketOutput {
-            location: bucket.to_string(),
-        })
+        let location = resp
+            .headers()
+            .get("Location")
+            .and_then(|v| v.to_str().ok())
+            .map(|s| s.to_string());
+
+        Ok(CreateBucketOutput { location })
     }
 }
 
@@ -228,18 +370,28 @@ impl CreateBucketFluentBuilder {
 pub struct CreateBucketInput {
     bucket: String,
     location_constraint: Option<String>,
+    acl: Option<CannedAcl>,
+    grants: GrantHeaders,
+    storage_class: Option<String>,
+    multi_az: bool,
+    parallel_file_system: bool,
+    epid: Option<String>,
+    object_lock_enabled: bool,
+    server_side_encryption: Option<String>,
+    server_side_data_encryption: Option<String>,
+    ssekms_key_id: Option<String>,
 }
 
 /// Output for the CreateBucket operation.
 #[derive(Debug, Clone)]
 pub struct CreateBucketOutput {
-    location: String,
+    location: Option<String>,
 }
 
 impl CreateBucketOutput {
-    /// Get the bucket name.
-    pub fn location(&self) -> &str {
-        &self.location
+    /// Get the `Location` header of the response (the path of the new bucket).
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
     }
 }
 
@@ -309,6 +461,251 @@ pub struct DeleteBucketInput {
 #[derive(Debug, Clone)]
 pub struct DeleteBucketOutput {}
 
+// ========================================
+// Head Bucket
+// ========================================
+
+/// Fluent builder for the HeadBucket operation.
+///
+/// Checks that a bucket exists and reads its metadata. A missing bucket is
+/// reported as [`ObsError::NoSuchBucket`] and a bucket that exists but cannot
+/// be accessed as [`ObsError::BucketAccessDenied`].
+#[derive(Debug, Clone)]
+pub struct HeadBucketFluentBuilder {
+    client: Client,
+    inner: HeadBucketInput,
+}
+
+impl HeadBucketFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: HeadBucketInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Set the origin of a cross-origin request, to read the CORS settings
+    /// that apply to it.
+    pub fn origin(mut self, origin: impl Into<String>) -> Self {
+        self.inner.origin = Some(origin.into());
+        self
+    }
+
+    /// Add a header that a cross-origin request would send.
+    pub fn access_control_request_header(mut self, header: impl Into<String>) -> Self {
+        self.inner
+            .access_control_request_headers
+            .push(header.into());
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<HeadBucketOutput> {
+        let bucket = &self.inner.bucket;
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+
+        let mut headers = HeaderMap::new();
+        if let Some(ref origin) = self.inner.origin {
+            headers.insert(
+                "Origin",
+                HeaderValue::from_str(origin)
+                    .map_err(|e| ObsError::InvalidInput(format!("Invalid origin: {}", e)))?,
+            );
+        }
+        if !self.inner.access_control_request_headers.is_empty() {
+            let value = self.inner.access_control_request_headers.join(",");
+            headers.insert(
+                "Access-Control-Request-Headers",
+                HeaderValue::from_str(&value).map_err(|e| {
+                    ObsError::InvalidInput(format!("Invalid request headers: {}", e))
+                })?,
+            );
+        }
+
+        let resp = self
+            .client
+            .do_request(Method::HEAD, Some(bucket), None, Some(headers), None, None)
+            .await?;
+
+        let status = resp.status();
+        match status {
+            StatusCode::NOT_FOUND => return Err(ObsError::NoSuchBucket(bucket.clone())),
+            StatusCode::FORBIDDEN => return Err(ObsError::BucketAccessDenied(bucket.clone())),
+            _ if !status.is_success() => {
+                let text = resp.text().await.unwrap_or_default();
+                return Err(ObsError::service_error(status, &text));
+            }
+            _ => {}
+        }
+
+        Ok(HeadBucketOutput::from_headers(resp.headers()))
+    }
+}
+
+/// Input for the HeadBucket operation.
+#[derive(Debug, Clone, Default)]
+pub struct HeadBucketInput {
+    bucket: String,
+    origin: Option<String>,
+    access_control_request_headers: Vec<String>,
+}
+
+/// Output for the HeadBucket operation.
+#[derive(Debug, Clone)]
+pub struct HeadBucketOutput {
+    location: Option<String>,
+    storage_class: Option<String>,
+    obs_version: Option<String>,
+    az_redundancy: Option<String>,
+    file_interface: Option<String>,
+    epid: Option<String>,
+    cors: Option<CorsHeaders>,
+}
+
+impl HeadBucketOutput {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        let header_string = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|s| s.to_string())
+        };
+
+        Self {
+            location: header_string("x-obs-bucket-location"),
+            storage_class: header_string("x-obs-storage-class"),
+            obs_version: header_string("x-obs-version"),
+            az_redundancy: header_string("x-obs-az-redundancy"),
+            file_interface: header_string("x-obs-fs-file-interface"),
+            epid: header_string("x-obs-epid"),
+            cors: CorsHeaders::from_headers(headers),
+        }
+    }
+
+    /// Get the region of the bucket.
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+
+    /// Get the default storage class of the bucket.
+    pub fn storage_class(&self) -> Option<&str> {
+        self.storage_class.as_deref()
+    }
+
+    /// Get the default storage class as a [`StorageClass`].
+    pub fn storage_class_kind(&self) -> Option<StorageClass> {
+        self.storage_class.as_deref().map(StorageClass::from)
+    }
+
+    /// Get the OBS version of the bucket (e.g. "3.0").
+    pub fn obs_version(&self) -> Option<&str> {
+        self.obs_version.as_deref()
+    }
+
+    /// Get the AZ redundancy of the bucket ("3az" for multi-AZ storage).
+    pub fn az_redundancy(&self) -> Option<&str> {
+        self.az_redundancy.as_deref()
+    }
+
+    /// Check if the bucket stores data in multiple AZs.
+    pub fn is_multi_az(&self) -> bool {
+        self.az_redundancy.as_deref() == Some("3az")
+    }
+
+    /// Check if the bucket is a parallel file system.
+    pub fn is_parallel_file_system(&self) -> bool {
+        self.file_interface.as_deref() == Some("Enabled")
+    }
+
+    /// Get the enterprise project ID of the bucket.
+    pub fn epid(&self) -> Option<&str> {
+        self.epid.as_deref()
+    }
+
+    /// Get the CORS settings that apply to the origin set on the request.
+    pub fn cors(&self) -> Option<&CorsHeaders> {
+        self.cors.as_ref()
+    }
+}
+
+/// CORS settings returned in `Access-Control-*` response headers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CorsHeaders {
+    allow_origin: String,
+    allow_methods: Vec<String>,
+    allow_headers: Vec<String>,
+    expose_headers: Vec<String>,
+    max_age: Option<u64>,
+}
+
+impl CorsHeaders {
+    /// Read the CORS headers of a response, if the origin is allowed.
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let list = |name: &str| -> Vec<String> {
+            headers
+                .get_all(name)
+                .iter()
+                .filter_map(|v| v.to_str().ok())
+                .flat_map(|v| v.split(','))
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+                .collect()
+        };
+
+        let allow_origin = headers
+            .get("Access-Control-Allow-Origin")?
+            .to_str()
+            .ok()?
+            .to_string();
+        Some(Self {
+            allow_origin,
+            allow_methods: list("Access-Control-Allow-Methods"),
+            allow_headers: list("Access-Control-Allow-Headers"),
+            expose_headers: list("Access-Control-Expose-Headers"),
+            max_age: headers
+                .get("Access-Control-Max-Age")
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse().ok()),
+        })
+    }
+
+    /// Get the allowed origin.
+    pub fn allow_origin(&self) -> &str {
+        &self.allow_origin
+    }
+
+    /// Get the allowed methods.
+    pub fn allow_methods(&self) -> &[String] {
+        &self.allow_methods
+    }
+
+    /// Get the allowed request headers.
+    pub fn allow_headers(&self) -> &[String] {
+        &self.allow_headers
+    }
+
+    /// Get the response headers the browser may expose.
+    pub fn expose_headers(&self) -> &[String] {
+        &self.expose_headers
+    }
+
+    /// Get how long the browser may cache the preflight result, in seconds.
+    pub fn max_age(&self) -> Option<u64> {
+        self.max_age
+    }
+}
+
 // ========================================
 // Get Bucket Location
 // ========================================
@@ -419,7 +816,9 @@ impl ListObjectsFluentBuilder {
         self
     }
 
-    /// Set the delimiter.
+    /// Set the delimiter (default: "/").
+    ///
+    /// Pass an empty string to list all objects under the prefix recursively.
     pub fn delimiter(mut self, delimiter: impl Into<String>) -> Self {
         self.inner.delimiter = Some(delimiter.into());
         self
@@ -459,10 +858,10 @@ impl ListObjectsFluentBuilder {
         }
 
         let mut params = HashMap::new();
-        params.insert(
-            "delimiter".to_string(),
-            self.inner.delimiter.as_deref().unwrap_or("/").to_string(),
-        );
+        let delimiter = self.inner.delimiter.as_deref().unwrap_or("/");
+        if !delimiter.is_empty() {
+            params.insert("delimiter".to_string(), delimiter.to_string());
+        }
 
         if let Some(ref marker) = self.inner.marker {
             params.insert("marker".to_string(), marker.clone());
@@ -597,7 +996,7 @@ pub struct ObjectInfo {
     #[serde(rename = "ETag")]
     etag: String,
     #[serde(rename = "Size")]
-    size: i64,
+    size: u64,
     #[serde(rename = "StorageClass")]
     storage_class: String,
     #[serde(rename = "Owner")]
@@ -615,13 +1014,18 @@ impl ObjectInfo {
         &self.last_modified
     }
 
+    /// Get the last modified time as a UTC timestamp (`None` if it cannot be parsed).
+    pub fn last_modified_time(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&self.last_modified)
+    }
+
     /// Get the ETag.
     pub fn etag(&self) -> &str {
         &self.etag
     }
 
     /// Get the size.
-    pub fn size(&self) -> i64 {
+    pub fn size(&self) -> u64 {
         self.size
     }
 
@@ -630,6 +1034,11 @@ impl ObjectInfo {
         &self.storage_class
     }
 
+    /// Get the storage class as a [`StorageClass`].
+    pub fn storage_class_kind(&self) -> StorageClass {
+        StorageClass::from(self.storage_class.as_str())
+    }
+
     /// Get the owner.
     pub fn owner(&self) -> Option<&ObjectOwner> {
         self.owner.as_ref()
@@ -872,3 +1281,3929 @@ impl From<ListBucketResult> for ListObjectsV2Output {
         }
     }
 }
+
+// ========================================
+// Put Bucket Object Lock Configuration
+// ========================================
+
+/// Fluent builder for the PutBucketObjectLockConfiguration operation.
+///
+/// Enables WORM on the bucket and optionally sets a default retention that
+/// is applied to every new object. Leaving the mode unset removes the
+/// default retention while keeping WORM enabled.
+#[derive(Debug, Clone)]
+pub struct PutBucketObjectLockConfigurationFluentBuilder {
+    client: Client,
+    inner: PutBucketObjectLockConfigurationInput,
+}
+
+impl PutBucketObjectLockConfigurationFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: PutBucketObjectLockConfigurationInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Set the default retention mode.
+    ///
+    /// Possible value: COMPLIANCE.
+    pub fn mode(mut self, mode: impl Into<String>) -> Self {
+        self.inner.mode = Some(mode.into());
+        self
+    }
+
+    /// Set the default retention period in days (1-36500).
+    pub fn days(mut self, days: u32) -> Self {
+        self.inner.days = Some(days);
+        self
+    }
+
+    /// Set the default retention period in years (1-100).
+    pub fn years(mut self, years: u32) -> Self {
+        self.inner.years = Some(years);
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<PutBucketObjectLockConfigurationOutput> {
+        let bucket = &self.inner.bucket;
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+
+        let rule = match self.inner.mode {
+            Some(ref mode) => {
+                match (self.inner.days, self.inner.years) {
+                    (Some(days), None) if (1..=36500).contains(&days) => {}
+                    (None, Some(years)) if (1..=100).contains(&years) => {}
+                    (Some(_), None) => {
+                        return Err(ObsError::InvalidInput(
+                            "retention days must be between 1 and 36500".to_string(),
+                        ))
+                    }
+                    (None, Some(_)) => {
+                        return Err(ObsError::InvalidInput(
+                            "retention years must be between 1 and 100".to_string(),
+                        ))
+                    }
+                    _ => {
+                        return Err(ObsError::InvalidInput(
+                            "exactly one of retention days or years is required".to_string(),
+                        ))
+                    }
+                }
+                Some(ObjectLockRule {
+                    default_retention: DefaultRetention {
+                        mode: mode.clone(),
+                        days: self.inner.days,
+                        years: self.inner.years,
+                    },
+                })
+            }
+            None if self.inner.days.is_some() || self.inner.years.is_some() => {
+                return Err(ObsError::InvalidInput(
+                    "retention mode is required when a period is set".to_string(),
+                ));
+            }
+            None => None,
+        };
+
+        let mut params = HashMap::new();
+        params.insert("object-lock".to_string(), String::new());
+
+        let configuration = ObjectLockConfiguration {
+            object_lock_enabled: Some("Enabled".to_string()),
+            rule,
+        };
+        let body = crate::xml_utils::to_xml(&configuration)?;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "Content-MD5",
+            crate::xml_utils::content_md5(body.as_bytes()),
+        );
+
+        let resp = self
+            .client
+            .do_request(
+                Method::PUT,
+                Some(bucket),
+                None,
+                Some(headers),
+                Some(params),
+                Some(body.into_bytes()),
+            )
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await?;
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        Ok(PutBucketObjectLockConfigurationOutput {})
+    }
+}
+
+/// Input for the PutBucketObjectLockConfiguration operation.
+#[derive(Debug, Clone, Default)]
+pub struct PutBucketObjectLockConfigurationInput {
+    bucket: String,
+    mode: Option<String>,
+    days: Option<u32>,
+    years: Option<u32>,
+}
+
+/// Output for the PutBucketObjectLockConfiguration operation.
+#[derive(Debug, Clone)]
+pub struct PutBucketObjectLockConfigurationOutput {}
+
+// ========================================
+// Get Bucket Object Lock Configuration
+// ========================================
+
+/// Fluent builder for the GetBucketObjectLockConfiguration operation.
+#[derive(Debug, Clone)]
+pub struct GetBucketObjectLockConfigurationFluentBuilder {
+    client: Client,
+    inner: GetBucketObjectLockConfigurationInput,
+}
+
+impl GetBucketObjectLockConfigurationFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: GetBucketObjectLockConfigurationInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<GetBucketObjectLockConfigurationOutput> {
+        let bucket = &self.inner.bucket;
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+
+        let mut params = HashMap::new();
+        params.insert("object-lock".to_string(), String::new());
+
+        let resp = self
+            .client
+            .do_request(Method::GET, Some(bucket), None, None, Some(params), None)
+            .await?;
+
+        let status = resp.status();
+        let text = resp.text().await?;
+
+        if !status.is_success() {
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        let result: ObjectLockConfiguration = crate::xml_utils::from_xml(&text)?;
+
+        Ok(GetBucketObjectLockConfigurationOutput {
+            object_lock_enabled: result.object_lock_enabled.as_deref() == Some("Enabled"),
+            default_retention: result.rule.map(|r| r.default_retention),
+        })
+    }
+}
+
+/// Input for the GetBucketObjectLockConfiguration operation.
+#[derive(Debug, Clone, Default)]
+pub struct GetBucketObjectLockConfigurationInput {
+    bucket: String,
+}
+
+/// Output for the GetBucketObjectLockConfiguration operation.
+#[derive(Debug, Clone)]
+pub struct GetBucketObjectLockConfigurationOutput {
+    object_lock_enabled: bool,
+    default_retention: Option<DefaultRetention>,
+}
+
+impl GetBucketObjectLockConfigurationOutput {
+    /// Check if WORM is enabled on the bucket.
+    pub fn object_lock_enabled(&self) -> bool {
+        self.object_lock_enabled
+    }
+
+    /// Get the default retention applied to new objects.
+    pub fn default_retention(&self) -> Option<&DefaultRetention> {
+        self.default_retention.as_ref()
+    }
+}
+
+/// Default WORM retention of a bucket.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DefaultRetention {
+    #[serde(rename = "Mode")]
+    mode: String,
+    #[serde(rename = "Days", default, skip_serializing_if = "Option::is_none")]
+    days: Option<u32>,
+    #[serde(rename = "Years", default, skip_serializing_if = "Option::is_none")]
+    years: Option<u32>,
+}
+
+impl DefaultRetention {
+    /// Get the retention mode.
+    pub fn mode(&self) -> &str {
+        &self.mode
+    }
+
+    /// Get the retention period in days.
+    pub fn days(&self) -> Option<u32> {
+        self.days
+    }
+
+    /// Get the retention period in years.
+    pub fn years(&self) -> Option<u32> {
+        self.years
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename = "ObjectLockConfiguration")]
+struct ObjectLockConfiguration {
+    #[serde(
+        rename = "ObjectLockEnabled",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    object_lock_enabled: Option<String>,
+    #[serde(rename = "Rule", default, skip_serializing_if = "Option::is_none")]
+    rule: Option<ObjectLockRule>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ObjectLockRule {
+    #[serde(rename = "DefaultRetention")]
+    default_retention: DefaultRetention,
+}
+
+// ========================================
+// Put Bucket Versioning
+// ========================================
+
+/// Fluent builder for the PutBucketVersioning operation.
+///
+/// Once enabled, versioning can only be suspended, not turned off.
+#[derive(Debug, Clone)]
+pub struct PutBucketVersioningFluentBuilder {
+    client: Client,
+    inner: PutBucketVersioningInput,
+}
+
+impl PutBucketVersioningFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: PutBucketVersioningInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Set the versioning status.
+    ///
+    /// Possible values: Enabled, Suspended.
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.inner.status = status.into();
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<PutBucketVersioningOutput> {
+        let bucket = &self.inner.bucket;
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+        if !matches!(self.inner.status.as_str(), "Enabled" | "Suspended") {
+            return Err(ObsError::InvalidInput(
+                "versioning status must be Enabled or Suspended".to_string(),
+            ));
+        }
+
+        let mut params = HashMap::new();
+        params.insert("versioning".to_string(), String::new());
+
+        let configuration = VersioningConfiguration {
+            status: Some(self.inner.status.clone()),
+        };
+        let body = crate::xml_utils::to_xml(&configuration)?;
+
+        let resp = self
+            .client
+            .do_request(
+                Method::PUT,
+                Some(bucket),
+                None,
+                None,
+                Some(params),
+                Some(body.into_bytes()),
+            )
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await?;
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        Ok(PutBucketVersioningOutput {})
+    }
+}
+
+/// Input for the PutBucketVersioning operation.
+#[derive(Debug, Clone, Default)]
+pub struct PutBucketVersioningInput {
+    bucket: String,
+    status: String,
+}
+
+/// Output for the PutBucketVersioning operation.
+#[derive(Debug, Clone)]
+pub struct PutBucketVersioningOutput {}
+
+// ========================================
+// Get Bucket Versioning
+// ========================================
+
+/// Fluent builder for the GetBucketVersioning operation.
+#[derive(Debug, Clone)]
+pub struct GetBucketVersioningFluentBuilder {
+    client: Client,
+    inner: GetBucketVersioningInput,
+}
+
+impl GetBucketVersioningFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: GetBucketVersioningInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<GetBucketVersioningOutput> {
+        let bucket = &self.inner.bucket;
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+
+        let mut params = HashMap::new();
+        params.insert("versioning".to_string(), String::new());
+
+        let resp = self
+            .client
+            .do_request(Method::GET, Some(bucket), None, None, Some(params), None)
+            .await?;
+
+        let status = resp.status();
+        let text = resp.text().await?;
+
+        if !status.is_success() {
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        let result: VersioningConfiguration = crate::xml_utils::from_xml(&text)?;
+
+        Ok(GetBucketVersioningOutput {
+            status: result.status.filter(|s| !s.is_empty()),
+        })
+    }
+}
+
+/// Input for the GetBucketVersioning operation.
+#[derive(Debug, Clone, Default)]
+pub struct GetBucketVersioningInput {
+    bucket: String,
+}
+
+/// Output for the GetBucketVersioning operation.
+#[derive(Debug, Clone)]
+pub struct GetBucketVersioningOutput {
+    status: Option<String>,
+}
+
+impl GetBucketVersioningOutput {
+    /// Get the versioning status (Enabled or Suspended).
+    ///
+    /// `None` if versioning was never enabled on the bucket.
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+
+    /// Check if versioning is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.status.as_deref() == Some("Enabled")
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename = "VersioningConfiguration")]
+struct VersioningConfiguration {
+    #[serde(rename = "Status", default, skip_serializing_if = "Option::is_none")]
+    status: Option<String>,
+}
+
+// ========================================
+// List Object Versions
+// ========================================
+
+/// Fluent builder for the ListObjectVersions operation.
+#[derive(Debug, Clone)]
+pub struct ListObjectVersionsFluentBuilder {
+    client: Client,
+    inner: ListObjectVersionsInput,
+}
+
+impl ListObjectVersionsFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: ListObjectVersionsInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Set the delimiter used to group keys into common prefixes.
+    pub fn delimiter(mut self, delimiter: impl Into<String>) -> Self {
+        self.inner.delimiter = Some(delimiter.into());
+        self
+    }
+
+    /// Set the key to start listing after.
+    pub fn key_marker(mut self, key_marker: impl Into<String>) -> Self {
+        self.inner.key_marker = Some(key_marker.into());
+        self
+    }
+
+    /// Set the version ID to start listing after, together with the key marker.
+    pub fn version_id_marker(mut self, version_id_marker: impl Into<String>) -> Self {
+        self.inner.version_id_marker = Some(version_id_marker.into());
+        self
+    }
+
+    /// Set the max keys (up to 1000).
+    pub fn max_keys(mut self, max_keys: i32) -> Self {
+        self.inner.max_keys = Some(max_keys);
+        self
+    }
+
+    /// Set the prefix.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.inner.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<ListObjectVersionsOutput> {
+        let bucket = &self.inner.bucket;
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+        if self.inner.version_id_marker.is_some() && self.inner.key_marker.is_none() {
+            return Err(ObsError::InvalidInput(
+                "version ID marker requires a key marker".to_string(),
+            ));
+        }
+
+        let mut params = HashMap::new();
+        params.insert("versions".to_string(), String::new());
+
+        if let Some(ref delimiter) = self.inner.delimiter {
+            params.insert("delimiter".to_string(), delimiter.clone());
+        }
+        if let Some(ref key_marker) = self.inner.key_marker {
+            params.insert("key-marker".to_string(), key_marker.clone());
+        }
+        if let Some(ref version_id_marker) = self.inner.version_id_marker {
+            params.insert("version-id-marker".to_string(), version_id_marker.clone());
+        }
+        if let Some(max_keys) = self.inner.max_keys {
+            params.insert("max-keys".to_string(), max_keys.to_string());
+        }
+        if let Some(ref prefix) = self.inner.prefix {
+            params.insert("prefix".to_string(), prefix.clone());
+        }
+
+        let resp = self
+            .client
+            .do_request(Method::GET, Some(bucket), None, None, Some(params), None)
+            .await?;
+
+        let status = resp.status();
+        let text = resp.text().await?;
+
+        if !status.is_success() {
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        let result: ListVersionsResult = crate::xml_utils::from_xml(&text)?;
+
+        Ok(ListObjectVersionsOutput::from(result))
+    }
+}
+
+/// Input for the ListObjectVersions operation.
+#[derive(Debug, Clone, Default)]
+pub struct ListObjectVersionsInput {
+    bucket: String,
+    delimiter: Option<String>,
+    key_marker: Option<String>,
+    version_id_marker: Option<String>,
+    max_keys: Option<i32>,
+    prefix: Option<String>,
+}
+
+/// Output for the ListObjectVersions operation.
+#[derive(Debug, Clone, Default)]
+pub struct ListObjectVersionsOutput {
+    name: String,
+    prefix: Option<String>,
+    delimiter: Option<String>,
+    key_marker: Option<String>,
+    version_id_marker: Option<String>,
+    next_key_marker: Option<String>,
+    next_version_id_marker: Option<String>,
+    max_keys: i32,
+    is_truncated: bool,
+    versions: Vec<ObjectVersion>,
+    delete_markers: Vec<DeleteMarkerEntry>,
+    common_prefixes: Vec<String>,
+}
+
+impl ListObjectVersionsOutput {
+    /// Get the bucket name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the prefix.
+    pub fn prefix(&self) -> Option<&str> {
+        self.prefix.as_deref()
+    }
+
+    /// Get the delimiter.
+    pub fn delimiter(&self) -> Option<&str> {
+        self.delimiter.as_deref()
+    }
+
+    /// Get the key marker.
+    pub fn key_marker(&self) -> Option<&str> {
+        self.key_marker.as_deref()
+    }
+
+    /// Get the version ID marker.
+    pub fn version_id_marker(&self) -> Option<&str> {
+        self.version_id_marker.as_deref()
+    }
+
+    /// Get the key marker for the next page.
+    pub fn next_key_marker(&self) -> Option<&str> {
+        self.next_key_marker.as_deref()
+    }
+
+    /// Get the version ID marker for the next page.
+    pub fn next_version_id_marker(&self) -> Option<&str> {
+        self.next_version_id_marker.as_deref()
+    }
+
+    /// Get the max keys.
+    pub fn max_keys(&self) -> i32 {
+        self.max_keys
+    }
+
+    /// Check if the result is truncated.
+    pub fn is_truncated(&self) -> bool {
+        self.is_truncated
+    }
+
+    /// Get the object versions.
+    pub fn versions(&self) -> &[ObjectVersion] {
+        &self.versions
+    }
+
+    /// Get the delete markers.
+    pub fn delete_markers(&self) -> &[DeleteMarkerEntry] {
+        &self.delete_markers
+    }
+
+    /// Get the common prefixes.
+    pub fn common_prefixes(&self) -> &[String] {
+        &self.common_prefixes
+    }
+}
+
+impl From<ListVersionsResult> for ListObjectVersionsOutput {
+    fn from(value: ListVersionsResult) -> Self {
+        fn non_empty(value: String) -> Option<String> {
+            (!value.is_empty()).then_some(value)
+        }
+
+        let mut output = Self {
+            max_keys: 1000,
+            ..Default::default()
+        };
+        for entry in value.entries {
+            match entry {
+                VersionsEntry::Name(name) => output.name = name,
+                VersionsEntry::Prefix(prefix) => output.prefix = non_empty(prefix),
+                VersionsEntry::Delimiter(delimiter) => output.delimiter = non_empty(delimiter),
+                VersionsEntry::KeyMarker(marker) => output.key_marker = non_empty(marker),
+                VersionsEntry::VersionIdMarker(marker) => {
+                    output.version_id_marker = non_empty(marker)
+                }
+                VersionsEntry::NextKeyMarker(marker) => output.next_key_marker = non_empty(marker),
+                VersionsEntry::NextVersionIdMarker(marker) => {
+                    output.next_version_id_marker = non_empty(marker)
+                }
+                VersionsEntry::MaxKeys(max_keys) => output.max_keys = max_keys,
+                VersionsEntry::IsTruncated(is_truncated) => output.is_truncated = is_truncated,
+                VersionsEntry::Version(version) => output.versions.push(version),
+                VersionsEntry::DeleteMarker(marker) => output.delete_markers.push(marker),
+                VersionsEntry::CommonPrefixes(prefix) => output.common_prefixes.push(prefix.prefix),
+                VersionsEntry::Other => {}
+            }
+        }
+        output
+    }
+}
+
+/// A version of an object.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ObjectVersion {
+    #[serde(rename = "Key")]
+    key: String,
+    #[serde(rename = "VersionId")]
+    version_id: String,
+    #[serde(rename = "IsLatest", default)]
+    is_latest: bool,
+    #[serde(rename = "LastModified", default)]
+    last_modified: String,
+    #[serde(rename = "ETag", default)]
+    etag: String,
+    #[serde(rename = "Size", default)]
+    size: u64,
+    #[serde(rename = "StorageClass", default)]
+    storage_class: String,
+    #[serde(rename = "Owner")]
+    owner: Option<ObjectOwner>,
+}
+
+impl ObjectVersion {
+    /// Get the key.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Get the version ID.
+    pub fn version_id(&self) -> &str {
+        &self.version_id
+    }
+
+    /// Check if this is the current version of the object.
+    pub fn is_latest(&self) -> bool {
+        self.is_latest
+    }
+
+    /// Get the last modified time.
+    pub fn last_modified(&self) -> &str {
+        &self.last_modified
+    }
+
+    /// Get the last modified time as a UTC timestamp (`None` if it cannot be parsed).
+    pub fn last_modified_time(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&self.last_modified)
+    }
+
+    /// Get the ETag.
+    pub fn etag(&self) -> &str {
+        &self.etag
+    }
+
+    /// Get the size.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Get the storage class.
+    pub fn storage_class(&self) -> &str {
+        &self.storage_class
+    }
+
+    /// Get the storage class as a [`StorageClass`].
+    pub fn storage_class_kind(&self) -> StorageClass {
+        StorageClass::from(self.storage_class.as_str())
+    }
+
+    /// Get the owner.
+    pub fn owner(&self) -> Option<&ObjectOwner> {
+        self.owner.as_ref()
+    }
+}
+
+/// A delete marker of an object.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteMarkerEntry {
+    #[serde(rename = "Key")]
+    key: String,
+    #[serde(rename = "VersionId")]
+    version_id: String,
+    #[serde(rename = "IsLatest", default)]
+    is_latest: bool,
+    #[serde(rename = "LastModified", default)]
+    last_modified: String,
+    #[serde(rename = "Owner")]
+    owner: Option<ObjectOwner>,
+}
+
+impl DeleteMarkerEntry {
+    /// Get the key.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Get the version ID.
+    pub fn version_id(&self) -> &str {
+        &self.version_id
+    }
+
+    /// Check if the delete marker is the current version of the object.
+    pub fn is_latest(&self) -> bool {
+        self.is_latest
+    }
+
+    /// Get the last modified time.
+    pub fn last_modified(&self) -> &str {
+        &self.last_modified
+    }
+
+    /// Get the last modified time as a UTC timestamp (`None` if it cannot be parsed).
+    pub fn last_modified_time(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&self.last_modified)
+    }
+
+    /// Get the owner.
+    pub fn owner(&self) -> Option<&ObjectOwner> {
+        self.owner.as_ref()
+    }
+}
+
+/// Response of a ListObjectVersions request.
+///
+/// Versions and delete markers are interleaved, so all children are read
+/// in document order.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "ListVersionsResult")]
+struct ListVersionsResult {
+    #[serde(rename = "$value", default)]
+    entries: Vec<VersionsEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+enum VersionsEntry {
+    Name(String),
+    Prefix(String),
+    Delimiter(String),
+    KeyMarker(String),
+    VersionIdMarker(String),
+    NextKeyMarker(String),
+    NextVersionIdMarker(String),
+    MaxKeys(i32),
+    IsTruncated(bool),
+    Version(ObjectVersion),
+    DeleteMarker(DeleteMarkerEntry),
+    CommonPrefixes(CommonPrefix),
+    #[serde(other)]
+    Other,
+}
+
+// ========================================
+// Bucket Policy
+// ========================================
+
+/// A bucket policy document.
+///
+/// Policies are JSON documents made of statements that allow or deny
+/// principals to perform actions on resources of the bucket.
+///
+/// ```rust
+/// use huaweicloud_sdk_rust_obs::{Policy, Principal, Statement};
+///
+/// let policy = Policy::new()
+///     .statement(Statement::public_read("my-bucket", "public/"))
+///     .statement(Statement::read_only_for_account("my-bucket", "domain-id"))
+///     .statement(
+///         Statement::deny()
+///             .principal(Principal::everyone())
+///             .action("DeleteObject")
+///             .resource("my-bucket/*"),
+///     );
+/// assert!(policy.validate("my-bucket").is_ok());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Policy {
+    /// Policy language version.
+    #[serde(rename = "Version", default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Statements of the policy.
+    #[serde(rename = "Statement")]
+    pub statements: Vec<Statement>,
+}
+
+impl Policy {
+    /// Create an empty policy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a statement.
+    pub fn statement(mut self, statement: Statement) -> Self {
+        self.statements.push(statement);
+        self
+    }
+
+    /// Check that the policy is well formed for a bucket.
+    ///
+    /// Every statement needs a principal, at least one action of the form
+    /// `Name`, `prefix:Name` or a `*` wildcard, and at least one resource
+    /// inside the bucket (`bucket` or `bucket/...`).
+    pub fn validate(&self, bucket: &str) -> Result<()> {
+        if self.statements.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket policy must contain at least one statement".to_string(),
+            ));
+        }
+
+        for statement in &self.statements {
+            if let Principal::Ids(ref ids) = statement.principal {
+                if ids.is_empty() || ids.iter().any(|id| id.is_empty()) {
+                    return Err(ObsError::InvalidInput(
+                        "policy statement principal must not be empty".to_string(),
+                    ));
+                }
+            }
+            if statement.actions.is_empty() {
+                return Err(ObsError::InvalidInput(
+                    "policy statement must contain at least one action".to_string(),
+                ));
+            }
+            if let Some(action) = statement.actions.iter().find(|a| !is_valid_action(a)) {
+                return Err(ObsError::InvalidInput(format!(
+                    "invalid policy action: {}",
+                    action
+                )));
+            }
+            if statement.resources.is_empty() {
+                return Err(ObsError::InvalidInput(
+                    "policy statement must contain at least one resource".to_string(),
+                ));
+            }
+            if let Some(resource) = statement
+                .resources
+                .iter()
+                .find(|r| r.as_str() != bucket && !r.starts_with(&format!("{}/", bucket)))
+            {
+                return Err(ObsError::InvalidInput(format!(
+                    "policy resource {} is not in bucket {}",
+                    resource, bucket
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Check an action name such as `GetObject`, `Get*` or `obs:GetObject`.
+fn is_valid_action(action: &str) -> bool {
+    let name = match action.split_once(':') {
+        Some((prefix, name)) if !prefix.is_empty() => {
+            if !prefix.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return false;
+            }
+            name
+        }
+        Some(_) => return false,
+        None => action,
+    };
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '*')
+}
+
+/// A statement of a bucket policy.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Statement {
+    /// Statement ID.
+    #[serde(rename = "Sid", default, skip_serializing_if = "Option::is_none")]
+    pub sid: Option<String>,
+    /// Whether the actions are allowed or denied.
+    #[serde(rename = "Effect")]
+    pub effect: Effect,
+    /// Who the statement applies to.
+    #[serde(rename = "Principal")]
+    pub principal: Principal,
+    /// Actions, e.g. "GetObject".
+    #[serde(rename = "Action", with = "one_or_many")]
+    pub actions: Vec<String>,
+    /// Resources, e.g. "my-bucket/logs/*".
+    #[serde(rename = "Resource", with = "one_or_many")]
+    pub resources: Vec<String>,
+    /// Conditions, keyed by operator and then by condition key.
+    #[serde(
+        rename = "Condition",
+        default,
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    pub conditions: BTreeMap<String, BTreeMap<String, serde_json::Value>>,
+}
+
+impl Statement {
+    fn new(effect: Effect) -> Self {
+        Self {
+            sid: None,
+            effect,
+            principal: Principal::Ids(Vec::new()),
+            actions: Vec::new(),
+            resources: Vec::new(),
+            conditions: BTreeMap::new(),
+        }
+    }
+
+    /// Create a statement that allows the actions.
+    pub fn allow() -> Self {
+        Self::new(Effect::Allow)
+    }
+
+    /// Create a statement that denies the actions.
+    pub fn deny() -> Self {
+        Self::new(Effect::Deny)
+    }
+
+    /// Allow an account read-only access to the whole bucket: listing the
+    /// objects and their versions and downloading them.
+    pub fn read_only_for_account(bucket: &str, domain_id: impl Into<String>) -> Self {
+        Self::allow()
+            .principal(Principal::account(domain_id))
+            .action("GetObject")
+            .action("GetObjectVersion")
+            .action("ListBucket")
+            .action("ListBucketVersions")
+            .resource(bucket)
+            .resource(format!("{}/*", bucket))
+    }
+
+    /// Allow anyone to download the objects under a prefix (an empty prefix
+    /// makes the whole bucket public).
+    pub fn public_read(bucket: &str, prefix: &str) -> Self {
+        Self::allow()
+            .principal(Principal::everyone())
+            .action("GetObject")
+            .resource(format!("{}/{}*", bucket, prefix))
+    }
+
+    /// Set the statement ID.
+    pub fn sid(mut self, sid: impl Into<String>) -> Self {
+        self.sid = Some(sid.into());
+        self
+    }
+
+    /// Set the principal the statement applies to.
+    pub fn principal(mut self, principal: Principal) -> Self {
+        self.principal = principal;
+        self
+    }
+
+    /// Add an action (e.g. "GetObject", "PutObject", "*").
+    pub fn action(mut self, action: impl Into<String>) -> Self {
+        self.actions.push(action.into());
+        self
+    }
+
+    /// Add a resource (e.g. "my-bucket", "my-bucket/logs/*").
+    pub fn resource(mut self, resource: impl Into<String>) -> Self {
+        self.resources.push(resource.into());
+        self
+    }
+
+    /// Add a condition (e.g. operator "IpAddress", key "SourceIp", value "192.168.0.0/16").
+    pub fn condition(
+        mut self,
+        operator: impl Into<String>,
+        key: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.conditions
+            .entry(operator.into())
+            .or_default()
+            .insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Effect of a policy statement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Effect {
+    /// Allow the actions.
+    Allow,
+    /// Deny the actions.
+    Deny,
+}
+
+/// Principal of a policy statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Principal {
+    /// Everyone, including anonymous users (`"*"`).
+    Everyone,
+    /// Accounts or users, in the form `domain/{domainId}` or
+    /// `domain/{domainId}:user/{userId}`.
+    Ids(Vec<String>),
+}
+
+impl Principal {
+    /// Everyone, including anonymous users.
+    pub fn everyone() -> Self {
+        Principal::Everyone
+    }
+
+    /// All users of an account.
+    pub fn account(domain_id: impl Into<String>) -> Self {
+        Principal::Ids(vec![format!("domain/{}", domain_id.into())])
+    }
+
+    /// A single user of an account.
+    pub fn user(domain_id: impl Into<String>, user_id: impl Into<String>) -> Self {
+        Principal::Ids(vec![format!(
+            "domain/{}:user/{}",
+            domain_id.into(),
+            user_id.into()
+        )])
+    }
+}
+
+impl Serialize for Principal {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        match self {
+            Principal::Everyone => serializer.serialize_str("*"),
+            Principal::Ids(ids) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("ID", ids)?;
+                map.end()
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Principal {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Any(String),
+            Ids {
+                #[serde(rename = "ID", deserialize_with = "one_or_many::deserialize")]
+                ids: Vec<String>,
+            },
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Any(value) if value == "*" => Ok(Principal::Everyone),
+            Repr::Any(value) => Err(serde::de::Error::custom(format!(
+                "invalid principal: {}",
+                value
+            ))),
+            Repr::Ids { ids } if ids == ["*"] => Ok(Principal::Everyone),
+            Repr::Ids { ids } => Ok(Principal::Ids(ids)),
+        }
+    }
+}
+
+/// Policy fields that may hold either a single string or a list of strings.
+mod one_or_many {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(values: &[String], serializer: S) -> Result<S::Ok, S::Error> {
+        values.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<String>, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum OneOrMany {
+            One(String),
+            Many(Vec<String>),
+        }
+
+        Ok(match OneOrMany::deserialize(deserializer)? {
+            OneOrMany::One(value) => vec![value],
+            OneOrMany::Many(values) => values,
+        })
+    }
+}
+
+// ========================================
+// Put Bucket Policy
+// ========================================
+
+/// Fluent builder for the PutBucketPolicy operation.
+///
+/// The policy is validated with [`Policy::validate`] before it is sent.
+#[derive(Debug, Clone)]
+pub struct PutBucketPolicyFluentBuilder {
+    client: Client,
+    inner: PutBucketPolicyInput,
+}
+
+impl PutBucketPolicyFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: PutBucketPolicyInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Set the policy, replacing any existing policy of the bucket.
+    pub fn policy(mut self, policy: Policy) -> Self {
+        self.inner.policy = policy;
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<PutBucketPolicyOutput> {
+        let bucket = &self.inner.bucket;
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+        self.inner.policy.validate(bucket)?;
+
+        let mut params = HashMap::new();
+        params.insert("policy".to_string(), String::new());
+
+        let body = serde_json::to_string(&self.inner.policy)
+            .map_err(|e| ObsError::Serialization(e.to_string()))?;
+
+        let resp = self
+            .client
+            .do_request(
+                Method::PUT,
+                Some(bucket),
+                None,
+                None,
+                Some(params),
+                Some(body.into_bytes()),
+            )
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await?;
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        Ok(PutBucketPolicyOutput {})
+    }
+}
+
+/// Input for the PutBucketPolicy operation.
+#[derive(Debug, Clone, Default)]
+pub struct PutBucketPolicyInput {
+    bucket: String,
+    policy: Policy,
+}
+
+/// Output for the PutBucketPolicy operation.
+#[derive(Debug, Clone)]
+pub struct PutBucketPolicyOutput {}
+
+// ========================================
+// Get Bucket Policy
+// ========================================
+
+/// Fluent builder for the GetBucketPolicy operation.
+#[derive(Debug, Clone)]
+pub struct GetBucketPolicyFluentBuilder {
+    client: Client,
+    inner: GetBucketPolicyInput,
+}
+
+impl GetBucketPolicyFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: GetBucketPolicyInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Send the request.
+    ///
+    /// A bucket without a policy returns a service error with the code
+    /// `NoSuchBucketPolicy`.
+    pub async fn send(&self) -> Result<GetBucketPolicyOutput> {
+        let bucket = &self.inner.bucket;
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+
+        let mut params = HashMap::new();
+        params.insert("policy".to_string(), String::new());
+
+        let resp = self
+            .client
+            .do_request(Method::GET, Some(bucket), None, None, Some(params), None)
+            .await?;
+
+        let status = resp.status();
+        let text = resp.text().await?;
+        if !status.is_success() {
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        let policy = serde_json::from_str(&text).map_err(|e| ObsError::JsonParse(e.to_string()))?;
+        Ok(GetBucketPolicyOutput { policy })
+    }
+}
+
+/// Input for the GetBucketPolicy operation.
+#[derive(Debug, Clone, Default)]
+pub struct GetBucketPolicyInput {
+    bucket: String,
+}
+
+/// Output for the GetBucketPolicy operation.
+#[derive(Debug, Clone)]
+pub struct GetBucketPolicyOutput {
+    policy: Policy,
+}
+
+impl GetBucketPolicyOutput {
+    /// Get the policy.
+    pub fn policy(&self) -> &Policy {
+        &self.policy
+    }
+
+    /// Take the policy, e.g. to modify it and put it back.
+    pub fn into_policy(self) -> Policy {
+        self.policy
+    }
+}
+
+// ========================================
+// Delete Bucket Policy
+// ========================================
+
+/// Fluent builder for the DeleteBucketPolicy operation.
+#[derive(Debug, Clone)]
+pub struct DeleteBucketPolicyFluentBuilder {
+    client: Client,
+    inner: DeleteBucketPolicyInput,
+}
+
+impl DeleteBucketPolicyFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: DeleteBucketPolicyInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<DeleteBucketPolicyOutput> {
+        let bucket = &self.inner.bucket;
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+
+        let mut params = HashMap::new();
+        params.insert("policy".to_string(), String::new());
+
+        let resp = self
+            .client
+            .do_request(Method::DELETE, Some(bucket), None, None, Some(params), None)
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await?;
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        Ok(DeleteBucketPolicyOutput {})
+    }
+}
+
+/// Input for the DeleteBucketPolicy operation.
+#[derive(Debug, Clone, Default)]
+pub struct DeleteBucketPolicyInput {
+    bucket: String,
+}
+
+/// Output for the DeleteBucketPolicy operation.
+#[derive(Debug, Clone)]
+pub struct DeleteBucketPolicyOutput {}
+
+// ========================================
+// Bucket Lifecycle
+// ========================================
+
+/// A lifecycle rule of a bucket.
+///
+/// A rule applies to the objects matching its prefix (and tags, if any) and
+/// combines one or more actions: expiring or transitioning current versions,
+/// expiring or transitioning noncurrent versions, and aborting incomplete
+/// multipart uploads.
+///
+/// ```rust
+/// use huaweicloud_sdk_rust_obs::{LifecycleRule, StorageClass};
+///
+/// let rule = LifecycleRule::new("archive-logs")
+///     .prefix("logs/")
+///     .transition_after_days(30, StorageClass::Warm)
+///     .transition_after_days(180, StorageClass::Cold)
+///     .expire_after_days(365)
+///     .abort_incomplete_multipart_upload_after_days(7);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LifecycleRule {
+    /// Rule ID.
+    pub id: Option<String>,
+    /// Key prefix of the objects the rule applies to (empty for the whole bucket).
+    pub prefix: String,
+    /// Tags the objects must have for the rule to apply.
+    pub tags: Vec<Tag>,
+    /// Whether the rule is enabled.
+    pub enabled: bool,
+    /// When current versions expire.
+    pub expiration: Option<LifecycleTime>,
+    /// When current versions move to another storage class.
+    pub transitions: Vec<LifecycleTransition>,
+    /// Days after which noncurrent versions expire.
+    pub noncurrent_version_expiration_days: Option<u32>,
+    /// When noncurrent versions move to another storage class.
+    pub noncurrent_version_transitions: Vec<NoncurrentVersionTransition>,
+    /// Days after which incomplete multipart uploads are aborted.
+    pub abort_incomplete_multipart_upload_days: Option<u32>,
+}
+
+impl LifecycleRule {
+    /// Create an enabled rule with an ID that applies to the whole bucket.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: Some(id.into()),
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    /// Only apply the rule to objects whose key starts with a prefix.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Only apply the rule to objects with a tag (all tags must match).
+    pub fn tag(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.tags.push(Tag::new(key, value));
+        self
+    }
+
+    /// Enable or disable the rule.
+    pub fn status(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Expire current versions a number of days after they were created.
+    pub fn expire_after_days(mut self, days: u32) -> Self {
+        self.expiration = Some(LifecycleTime::Days(days));
+        self
+    }
+
+    /// Expire current versions at a date (must be midnight UTC).
+    pub fn expire_at(mut self, date: DateTime<Utc>) -> Self {
+        self.expiration = Some(LifecycleTime::Date(date));
+        self
+    }
+
+    /// Move current versions to a storage class a number of days after they were created.
+    pub fn transition_after_days(mut self, days: u32, storage_class: StorageClass) -> Self {
+        self.transitions.push(LifecycleTransition {
+            time: LifecycleTime::Days(days),
+            storage_class,
+        });
+        self
+    }
+
+    /// Move current versions to a storage class at a date (must be midnight UTC).
+    pub fn transition_at(mut self, date: DateTime<Utc>, storage_class: StorageClass) -> Self {
+        self.transitions.push(LifecycleTransition {
+            time: LifecycleTime::Date(date),
+            storage_class,
+        });
+        self
+    }
+
+    /// Expire noncurrent versions a number of days after they became noncurrent.
+    pub fn noncurrent_expire_after_days(mut self, days: u32) -> Self {
+        self.noncurrent_version_expiration_days = Some(days);
+        self
+    }
+
+    /// Move noncurrent versions to a storage class a number of days after they
+    /// became noncurrent.
+    pub fn noncurrent_transition_after_days(
+        mut self,
+        days: u32,
+        storage_class: StorageClass,
+    ) -> Self {
+        self.noncurrent_version_transitions
+            .push(NoncurrentVersionTransition {
+                noncurrent_days: days,
+                storage_class,
+            });
+        self
+    }
+
+    /// Abort multipart uploads that are not completed a number of days after
+    /// they were initiated.
+    pub fn abort_incomplete_multipart_upload_after_days(mut self, days: u32) -> Self {
+        self.abort_incomplete_multipart_upload_days = Some(days);
+        self
+    }
+
+    /// Check that the rule has at least one action and valid values.
+    fn validate(&self) -> Result<()> {
+        let name = self.id.as_deref().unwrap_or(&self.prefix);
+        if self.expiration.is_none()
+            && self.transitions.is_empty()
+            && self.noncurrent_version_expiration_days.is_none()
+            && self.noncurrent_version_transitions.is_empty()
+            && self.abort_incomplete_multipart_upload_days.is_none()
+        {
+            return Err(ObsError::InvalidInput(format!(
+                "lifecycle rule {} has no action",
+                name
+            )));
+        }
+
+        let times = self
+            .expiration
+            .iter()
+            .chain(self.transitions.iter().map(|t| &t.time));
+        for time in times {
+            if let LifecycleTime::Date(date) = time {
+                if *date != date.date_naive().and_time(NaiveTime::MIN).and_utc() {
+                    return Err(ObsError::InvalidInput(format!(
+                        "lifecycle rule {}: dates must be midnight UTC",
+                        name
+                    )));
+                }
+            }
+        }
+
+        let classes = self.transitions.iter().map(|t| &t.storage_class).chain(
+            self.noncurrent_version_transitions
+                .iter()
+                .map(|t| &t.storage_class),
+        );
+        for class in classes {
+            if matches!(class, StorageClass::Standard | StorageClass::Unknown(_)) {
+                return Err(ObsError::InvalidInput(format!(
+                    "lifecycle rule {}: cannot transition to {}",
+                    name, class
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// When a lifecycle action takes effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LifecycleTime {
+    /// A number of days after the object was created.
+    Days(u32),
+    /// A date (midnight UTC).
+    Date(DateTime<Utc>),
+}
+
+/// A transition of current versions to another storage class.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LifecycleTransition {
+    /// When the transition takes effect.
+    pub time: LifecycleTime,
+    /// Target storage class.
+    pub storage_class: StorageClass,
+}
+
+/// A transition of noncurrent versions to another storage class.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoncurrentVersionTransition {
+    /// Days after the version became noncurrent.
+    pub noncurrent_days: u32,
+    /// Target storage class.
+    pub storage_class: StorageClass,
+}
+
+// ========================================
+// Put Bucket Lifecycle
+// ========================================
+
+/// Fluent builder for the PutBucketLifecycle operation.
+///
+/// The rules replace the whole lifecycle configuration of the bucket.
+#[derive(Debug, Clone)]
+pub struct PutBucketLifecycleFluentBuilder {
+    client: Client,
+    inner: PutBucketLifecycleInput,
+}
+
+impl PutBucketLifecycleFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: PutBucketLifecycleInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Add a rule.
+    pub fn rule(mut self, rule: LifecycleRule) -> Self {
+        self.inner.rules.push(rule);
+        self
+    }
+
+    /// Set all rules.
+    pub fn rules(mut self, rules: Vec<LifecycleRule>) -> Self {
+        self.inner.rules = rules;
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<PutBucketLifecycleOutput> {
+        let bucket = &self.inner.bucket;
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+        if self.inner.rules.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "at least one lifecycle rule is required".to_string(),
+            ));
+        }
+        for rule in &self.inner.rules {
+            rule.validate()?;
+        }
+
+        let mut params = HashMap::new();
+        params.insert("lifecycle".to_string(), String::new());
+
+        let configuration = LifecycleConfiguration::from(self.inner.rules.as_slice());
+        let body = crate::xml_utils::to_xml(&configuration)?;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "Content-MD5",
+            crate::xml_utils::content_md5(body.as_bytes()),
+        );
+
+        let resp = self
+            .client
+            .do_request(
+                Method::PUT,
+                Some(bucket),
+                None,
+                Some(headers),
+                Some(params),
+                Some(body.into_bytes()),
+            )
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await?;
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        Ok(PutBucketLifecycleOutput {})
+    }
+}
+
+/// Input for the PutBucketLifecycle operation.
+#[derive(Debug, Clone, Default)]
+pub struct PutBucketLifecycleInput {
+    bucket: String,
+    rules: Vec<LifecycleRule>,
+}
+
+/// Output for the PutBucketLifecycle operation.
+#[derive(Debug, Clone)]
+pub struct PutBucketLifecycleOutput {}
+
+// ========================================
+// Get Bucket Lifecycle
+// ========================================
+
+/// Fluent builder for the GetBucketLifecycle operation.
+#[derive(Debug, Clone)]
+pub struct GetBucketLifecycleFluentBuilder {
+    client: Client,
+    inner: GetBucketLifecycleInput,
+}
+
+impl GetBucketLifecycleFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: GetBucketLifecycleInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Send the request.
+    ///
+    /// A bucket without lifecycle rules returns a service error with the code
+    /// `NoSuchLifecycleConfiguration`.
+    pub async fn send(&self) -> Result<GetBucketLifecycleOutput> {
+        let bucket = &self.inner.bucket;
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+
+        let mut params = HashMap::new();
+        params.insert("lifecycle".to_string(), String::new());
+
+        let resp = self
+            .client
+            .do_request(Method::GET, Some(bucket), None, None, Some(params), None)
+            .await?;
+
+        let status = resp.status();
+        let text = resp.text().await?;
+        if !status.is_success() {
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        let configuration: LifecycleConfiguration = crate::xml_utils::from_xml(&text)?;
+        Ok(GetBucketLifecycleOutput {
+            rules: configuration.into_rules()?,
+        })
+    }
+}
+
+/// Input for the GetBucketLifecycle operation.
+#[derive(Debug, Clone, Default)]
+pub struct GetBucketLifecycleInput {
+    bucket: String,
+}
+
+/// Output for the GetBucketLifecycle operation.
+#[derive(Debug, Clone)]
+pub struct GetBucketLifecycleOutput {
+    rules: Vec<LifecycleRule>,
+}
+
+impl GetBucketLifecycleOutput {
+    /// Get the rules.
+    pub fn rules(&self) -> &[LifecycleRule] {
+        &self.rules
+    }
+
+    /// Take the rules, e.g. to modify them and put them back.
+    pub fn into_rules(self) -> Vec<LifecycleRule> {
+        self.rules
+    }
+}
+
+// ========================================
+// Delete Bucket Lifecycle
+// ========================================
+
+/// Fluent builder for the DeleteBucketLifecycle operation.
+#[derive(Debug, Clone)]
+pub struct DeleteBucketLifecycleFluentBuilder {
+    client: Client,
+    inner: DeleteBucketLifecycleInput,
+}
+
+impl DeleteBucketLifecycleFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: DeleteBucketLifecycleInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<DeleteBucketLifecycleOutput> {
+        let bucket = &self.inner.bucket;
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+
+        let mut params = HashMap::new();
+        params.insert("lifecycle".to_string(), String::new());
+
+        let resp = self
+            .client
+            .do_request(Method::DELETE, Some(bucket), None, None, Some(params), None)
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await?;
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        Ok(DeleteBucketLifecycleOutput {})
+    }
+}
+
+/// Input for the DeleteBucketLifecycle operation.
+#[derive(Debug, Clone, Default)]
+pub struct DeleteBucketLifecycleInput {
+    bucket: String,
+}
+
+/// Output for the DeleteBucketLifecycle operation.
+#[derive(Debug, Clone)]
+pub struct DeleteBucketLifecycleOutput {}
+
+// ========================================
+// Internal lifecycle XML types
+// ========================================
+
+/// Lifecycle configuration document.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename = "LifecycleConfiguration")]
+struct LifecycleConfiguration {
+    #[serde(rename = "Rule", default)]
+    rules: Vec<LifecycleRuleXml>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LifecycleRuleXml {
+    #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(rename = "Prefix", skip_serializing_if = "Option::is_none")]
+    prefix: Option<String>,
+    #[serde(rename = "Filter", skip_serializing_if = "Option::is_none")]
+    filter: Option<LifecycleFilterXml>,
+    #[serde(rename = "Status")]
+    status: String,
+    #[serde(rename = "Expiration", skip_serializing_if = "Option::is_none")]
+    expiration: Option<LifecycleTimeXml>,
+    #[serde(rename = "Transition", default)]
+    transitions: Vec<LifecycleTimeXml>,
+    #[serde(
+        rename = "NoncurrentVersionExpiration",
+        skip_serializing_if = "Option::is_none"
+    )]
+    noncurrent_version_expiration: Option<NoncurrentXml>,
+    #[serde(rename = "NoncurrentVersionTransition", default)]
+    noncurrent_version_transitions: Vec<NoncurrentXml>,
+    #[serde(
+        rename = "AbortIncompleteMultipartUpload",
+        skip_serializing_if = "Option::is_none"
+    )]
+    abort_incomplete_multipart_upload: Option<AbortIncompleteMultipartUploadXml>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct LifecycleFilterXml {
+    #[serde(rename = "Prefix", skip_serializing_if = "Option::is_none")]
+    prefix: Option<String>,
+    #[serde(rename = "Tag", default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<Tag>,
+    #[serde(rename = "And", skip_serializing_if = "Option::is_none")]
+    and: Option<Box<LifecycleFilterXml>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LifecycleTimeXml {
+    #[serde(rename = "Date", skip_serializing_if = "Option::is_none")]
+    date: Option<String>,
+    #[serde(rename = "Days", skip_serializing_if = "Option::is_none")]
+    days: Option<u32>,
+    #[serde(rename = "StorageClass", skip_serializing_if = "Option::is_none")]
+    storage_class: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct NoncurrentXml {
+    #[serde(rename = "NoncurrentDays")]
+    noncurrent_days: u32,
+    #[serde(rename = "StorageClass", skip_serializing_if = "Option::is_none")]
+    storage_class: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AbortIncompleteMultipartUploadXml {
+    #[serde(rename = "DaysAfterInitiation")]
+    days_after_initiation: u32,
+}
+
+impl LifecycleTimeXml {
+    fn new(time: &LifecycleTime, storage_class: Option<&StorageClass>) -> Self {
+        let (date, days) = match time {
+            LifecycleTime::Days(days) => (None, Some(*days)),
+            LifecycleTime::Date(date) => (
+                Some(date.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()),
+                None,
+            ),
+        };
+        Self {
+            date,
+            days,
+            storage_class: storage_class.map(|c| c.to_string()),
+        }
+    }
+
+    fn time(&self) -> Result<LifecycleTime> {
+        match (self.days, self.date.as_deref()) {
+            (Some(days), _) => Ok(LifecycleTime::Days(days)),
+            (None, Some(date)) => parse_timestamp(date)
+                .map(LifecycleTime::Date)
+                .ok_or_else(|| ObsError::XmlParse(format!("invalid lifecycle date: {}", date))),
+            (None, None) => Err(ObsError::XmlParse(
+                "lifecycle action without Days or Date".to_string(),
+            )),
+        }
+    }
+}
+
+impl From<&[LifecycleRule]> for LifecycleConfiguration {
+    fn from(rules: &[LifecycleRule]) -> Self {
+        let rules = rules
+            .iter()
+            .map(|rule| {
+                // Rules without tags use the plain Prefix element understood by
+                // every OBS version; tags need a Filter.
+                let (prefix, filter) = if rule.tags.is_empty() {
+                    (Some(rule.prefix.clone()), None)
+                } else {
+                    let and = LifecycleFilterXml {
+                        prefix: Some(rule.prefix.clone()).filter(|p| !p.is_empty()),
+                        tags: rule.tags.clone(),
+                        and: None,
+                    };
+                    let filter = LifecycleFilterXml {
+                        and: Some(Box::new(and)),
+                        ..Default::default()
+                    };
+                    (None, Some(filter))
+                };
+
+                LifecycleRuleXml {
+                    id: rule.id.clone(),
+                    prefix,
+                    filter,
+                    status: if rule.enabled { "Enabled" } else { "Disabled" }.to_string(),
+                    expiration: rule
+                        .expiration
+                        .as_ref()
+                        .map(|time| LifecycleTimeXml::new(time, None)),
+                    transitions: rule
+                        .transitions
+                        .iter()
+                        .map(|t| LifecycleTimeXml::new(&t.time, Some(&t.storage_class)))
+                        .collect(),
+                    noncurrent_version_expiration: rule.noncurrent_version_expiration_days.map(
+                        |days| NoncurrentXml {
+                            noncurrent_days: days,
+                            storage_class: None,
+                        },
+                    ),
+                    noncurrent_version_transitions: rule
+                        .noncurrent_version_transitions
+                        .iter()
+                        .map(|t| NoncurrentXml {
+                            noncurrent_days: t.noncurrent_days,
+                            storage_class: Some(t.storage_class.to_string()),
+                        })
+                        .collect(),
+                    abort_incomplete_multipart_upload: rule
+                        .abort_incomplete_multipart_upload_days
+                        .map(|days| AbortIncompleteMultipartUploadXml {
+                            days_after_initiation: days,
+                        }),
+                }
+            })
+            .collect();
+        Self { rules }
+    }
+}
+
+impl LifecycleConfiguration {
+    fn into_rules(self) -> Result<Vec<LifecycleRule>> {
+        self.rules
+            .into_iter()
+            .map(|rule| {
+                let (prefix, tags) = match rule.filter {
+                    Some(filter) => match filter.and {
+                        Some(and) => (and.prefix, and.tags),
+                        None => (filter.prefix, filter.tags),
+                    },
+                    None => (rule.prefix, Vec::new()),
+                };
+
+                let storage_class = |value: Option<String>| {
+                    StorageClass::from(value.as_deref().unwrap_or_default())
+                };
+
+                Ok(LifecycleRule {
+                    id: rule.id,
+                    prefix: prefix.unwrap_or_default(),
+                    tags,
+                    enabled: rule.status == "Enabled",
+                    expiration: rule.expiration.map(|e| e.time()).transpose()?,
+                    transitions: rule
+                        .transitions
+                        .into_iter()
+                        .map(|t| {
+                            Ok(LifecycleTransition {
+                                time: t.time()?,
+                                storage_class: storage_class(t.storage_class),
+                            })
+                        })
+                        .collect::<Result<_>>()?,
+                    noncurrent_version_expiration_days: rule
+                        .noncurrent_version_expiration
+                        .map(|e| e.noncurrent_days),
+                    noncurrent_version_transitions: rule
+                        .noncurrent_version_transitions
+                        .into_iter()
+                        .map(|t| NoncurrentVersionTransition {
+                            noncurrent_days: t.noncurrent_days,
+                            storage_class: storage_class(t.storage_class),
+                        })
+                        .collect(),
+                    abort_incomplete_multipart_upload_days: rule
+                        .abort_incomplete_multipart_upload
+                        .map(|a| a.days_after_initiation),
+                })
+            })
+            .collect()
+    }
+}
+
+// ========================================
+// Bucket CORS
+// ========================================
+
+/// A CORS rule of a bucket.
+///
+/// A cross-origin request is allowed when its origin, method and request
+/// headers all match one rule.
+///
+/// ```rust
+/// use huaweicloud_sdk_rust_obs::{CorsMethod, CorsRule};
+///
+/// let rule = CorsRule::new()
+///     .allow_origin("https://app.example.com")
+///     .allow_method(CorsMethod::Get)
+///     .allow_method(CorsMethod::Put)
+///     .allow_header("*")
+///     .expose_header("ETag")
+///     .max_age(3600);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CorsRule {
+    /// Rule ID.
+    pub id: Option<String>,
+    /// Allowed origins, each with at most one `*` wildcard.
+    pub allowed_origins: Vec<String>,
+    /// Allowed methods.
+    pub allowed_methods: Vec<CorsMethod>,
+    /// Allowed request headers, each with at most one `*` wildcard.
+    pub allowed_headers: Vec<String>,
+    /// Response headers the browser may expose to the page.
+    pub expose_headers: Vec<String>,
+    /// How long the browser may cache the preflight result, in seconds.
+    pub max_age_seconds: Option<u64>,
+}
+
+impl CorsRule {
+    /// Create an empty rule.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the rule ID.
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Allow an origin.
+    pub fn allow_origin(mut self, origin: impl Into<String>) -> Self {
+        self.allowed_origins.push(origin.into());
+        self
+    }
+
+    /// Allow a method.
+    pub fn allow_method(mut self, method: CorsMethod) -> Self {
+        self.allowed_methods.push(method);
+        self
+    }
+
+    /// Allow a request header.
+    pub fn allow_header(mut self, header: impl Into<String>) -> Self {
+        self.allowed_headers.push(header.into());
+        self
+    }
+
+    /// Let the browser expose a response header.
+    pub fn expose_header(mut self, header: impl Into<String>) -> Self {
+        self.expose_headers.push(header.into());
+        self
+    }
+
+    /// Set how long the browser may cache the preflight result, in seconds.
+    pub fn max_age(mut self, seconds: u64) -> Self {
+        self.max_age_seconds = Some(seconds);
+        self
+    }
+
+    /// Check that the rule has origins and methods, and valid wildcards.
+    fn validate(&self) -> Result<()> {
+        if self.allowed_origins.is_empty() || self.allowed_methods.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "a CORS rule needs at least one allowed origin and method".to_string(),
+            ));
+        }
+        let mut values = self.allowed_origins.iter().chain(&self.allowed_headers);
+        if let Some(value) = values.find(|v| v.matches('*').count() > 1) {
+            return Err(ObsError::InvalidInput(format!(
+                "CORS value {} has more than one wildcard",
+                value
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// HTTP method of a cross-origin request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CorsMethod {
+    /// GET
+    Get,
+    /// PUT
+    Put,
+    /// HEAD
+    Head,
+    /// POST
+    Post,
+    /// DELETE
+    Delete,
+}
+
+impl CorsMethod {
+    /// Get the method name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CorsMethod::Get => "GET",
+            CorsMethod::Put => "PUT",
+            CorsMethod::Head => "HEAD",
+            CorsMethod::Post => "POST",
+            CorsMethod::Delete => "DELETE",
+        }
+    }
+}
+
+impl fmt::Display for CorsMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CorsMethod {
+    type Err = ObsError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "GET" => Ok(CorsMethod::Get),
+            "PUT" => Ok(CorsMethod::Put),
+            "HEAD" => Ok(CorsMethod::Head),
+            "POST" => Ok(CorsMethod::Post),
+            "DELETE" => Ok(CorsMethod::Delete),
+            _ => Err(ObsError::InvalidInput(format!(
+                "unknown CORS method: {}",
+                s
+            ))),
+        }
+    }
+}
+
+// ========================================
+// Put Bucket CORS
+// ========================================
+
+/// Fluent builder for the PutBucketCors operation.
+///
+/// The rules replace the whole CORS configuration of the bucket.
+#[derive(Debug, Clone)]
+pub struct PutBucketCorsFluentBuilder {
+    client: Client,
+    inner: PutBucketCorsInput,
+}
+
+impl PutBucketCorsFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: PutBucketCorsInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Add a rule.
+    pub fn rule(mut self, rule: CorsRule) -> Self {
+        self.inner.rules.push(rule);
+        self
+    }
+
+    /// Set all rules.
+    pub fn rules(mut self, rules: Vec<CorsRule>) -> Self {
+        self.inner.rules = rules;
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<PutBucketCorsOutput> {
+        let bucket = &self.inner.bucket;
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+        if self.inner.rules.is_empty() || self.inner.rules.len() > 100 {
+            return Err(ObsError::InvalidInput(
+                "between 1 and 100 CORS rules are required".to_string(),
+            ));
+        }
+        for rule in &self.inner.rules {
+            rule.validate()?;
+        }
+
+        let mut params = HashMap::new();
+        params.insert("cors".to_string(), String::new());
+
+        let configuration = CorsConfiguration::from(self.inner.rules.as_slice());
+        let body = crate::xml_utils::to_xml(&configuration)?;
+
+        // OBS rejects CORS configurations without Content-MD5
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "Content-MD5",
+            crate::xml_utils::content_md5(body.as_bytes()),
+        );
+
+        let resp = self
+            .client
+            .do_request(
+                Method::PUT,
+                Some(bucket),
+                None,
+                Some(headers),
+                Some(params),
+                Some(body.into_bytes()),
+            )
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await?;
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        Ok(PutBucketCorsOutput {})
+    }
+}
+
+/// Input for the PutBucketCors operation.
+#[derive(Debug, Clone, Default)]
+pub struct PutBucketCorsInput {
+    bucket: String,
+    rules: Vec<CorsRule>,
+}
+
+/// Output for the PutBucketCors operation.
+#[derive(Debug, Clone)]
+pub struct PutBucketCorsOutput {}
+
+// ========================================
+// Get Bucket CORS
+// ========================================
+
+/// Fluent builder for the GetBucketCors operation.
+#[derive(Debug, Clone)]
+pub struct GetBucketCorsFluentBuilder {
+    client: Client,
+    inner: GetBucketCorsInput,
+}
+
+impl GetBucketCorsFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: GetBucketCorsInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Send the request.
+    ///
+    /// A bucket without CORS rules returns a service error with the code
+    /// `NoSuchCORSConfiguration`.
+    pub async fn send(&self) -> Result<GetBucketCorsOutput> {
+        let bucket = &self.inner.bucket;
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+
+        let mut params = HashMap::new();
+        params.insert("cors".to_string(), String::new());
+
+        let resp = self
+            .client
+            .do_request(Method::GET, Some(bucket), None, None, Some(params), None)
+            .await?;
+
+        let status = resp.status();
+        let text = resp.text().await?;
+        if !status.is_success() {
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        let configuration: CorsConfiguration = crate::xml_utils::from_xml(&text)?;
+        Ok(GetBucketCorsOutput {
+            rules: configuration.into_rules()?,
+        })
+    }
+}
+
+/// Input for the GetBucketCors operation.
+#[derive(Debug, Clone, Default)]
+pub struct GetBucketCorsInput {
+    bucket: String,
+}
+
+/// Output for the GetBucketCors operation.
+#[derive(Debug, Clone)]
+pub struct GetBucketCorsOutput {
+    rules: Vec<CorsRule>,
+}
+
+impl GetBucketCorsOutput {
+    /// Get the rules.
+    pub fn rules(&self) -> &[CorsRule] {
+        &self.rules
+    }
+
+    /// Take the rules, e.g. to modify them and put them back.
+    pub fn into_rules(self) -> Vec<CorsRule> {
+        self.rules
+    }
+}
+
+// ========================================
+// Delete Bucket CORS
+// ========================================
+
+/// Fluent builder for the DeleteBucketCors operation.
+#[derive(Debug, Clone)]
+pub struct DeleteBucketCorsFluentBuilder {
+    client: Client,
+    inner: DeleteBucketCorsInput,
+}
+
+impl DeleteBucketCorsFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: DeleteBucketCorsInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<DeleteBucketCorsOutput> {
+        let bucket = &self.inner.bucket;
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+
+        let mut params = HashMap::new();
+        params.insert("cors".to_string(), String::new());
+
+        let resp = self
+            .client
+            .do_request(Method::DELETE, Some(bucket), None, None, Some(params), None)
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await?;
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        Ok(DeleteBucketCorsOutput {})
+    }
+}
+
+/// Input for the DeleteBucketCors operation.
+#[derive(Debug, Clone, Default)]
+pub struct DeleteBucketCorsInput {
+    bucket: String,
+}
+
+/// Output for the DeleteBucketCors operation.
+#[derive(Debug, Clone)]
+pub struct DeleteBucketCorsOutput {}
+
+// ========================================
+// Options Bucket
+// ========================================
+
+/// Fluent builder for the OptionsBucket operation.
+///
+/// Sends the preflight request a browser would send before a cross-origin
+/// request to the bucket. OBS answers with an error if no CORS rule allows it.
+#[derive(Debug, Clone)]
+pub struct OptionsBucketFluentBuilder {
+    client: Client,
+    inner: OptionsBucketInput,
+}
+
+impl OptionsBucketFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: OptionsBucketInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Set the origin of the cross-origin request.
+    pub fn origin(mut self, origin: impl Into<String>) -> Self {
+        self.inner.preflight.origin = origin.into();
+        self
+    }
+
+    /// Set the method of the cross-origin request.
+    pub fn request_method(mut self, method: CorsMethod) -> Self {
+        self.inner.preflight.method = Some(method);
+        self
+    }
+
+    /// Add a header that the cross-origin request would send.
+    pub fn request_header(mut self, header: impl Into<String>) -> Self {
+        self.inner.preflight.headers.push(header.into());
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<OptionsBucketOutput> {
+        let bucket = &self.inner.bucket;
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+
+        let cors = self
+            .inner
+            .preflight
+            .send(&self.client, bucket, None)
+            .await?;
+        Ok(OptionsBucketOutput { cors })
+    }
+}
+
+/// Input for the OptionsBucket operation.
+#[derive(Debug, Clone, Default)]
+pub struct OptionsBucketInput {
+    bucket: String,
+    preflight: Preflight,
+}
+
+/// Output for the OptionsBucket operation.
+#[derive(Debug, Clone)]
+pub struct OptionsBucketOutput {
+    cors: CorsHeaders,
+}
+
+impl OptionsBucketOutput {
+    /// Get the CORS settings that allow the request.
+    pub fn cors(&self) -> &CorsHeaders {
+        &self.cors
+    }
+}
+
+/// A CORS preflight request, shared by OptionsBucket and OptionsObject.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Preflight {
+    pub(crate) origin: String,
+    pub(crate) method: Option<CorsMethod>,
+    pub(crate) headers: Vec<String>,
+}
+
+impl Preflight {
+    /// Send the preflight request for a bucket or an object.
+    pub(crate) async fn send(
+        &self,
+        client: &Client,
+        bucket: &str,
+        key: Option<&str>,
+    ) -> Result<CorsHeaders> {
+        if self.origin.is_empty() {
+            return Err(ObsError::InvalidInput("origin is required".to_string()));
+        }
+        let Some(method) = self.method else {
+            return Err(ObsError::InvalidInput(
+                "request method is required".to_string(),
+            ));
+        };
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "Origin",
+            HeaderValue::from_str(&self.origin)
+                .map_err(|e| ObsError::InvalidInput(format!("Invalid origin: {}", e)))?,
+        );
+        headers.insert(
+            "Access-Control-Request-Method",
+            HeaderValue::from_static(method.as_str()),
+        );
+        if !self.headers.is_empty() {
+            headers.insert(
+                "Access-Control-Request-Headers",
+                HeaderValue::from_str(&self.headers.join(",")).map_err(|e| {
+                    ObsError::InvalidInput(format!("Invalid request headers: {}", e))
+                })?,
+            );
+        }
+
+        let resp = client
+            .do_request(
+                Method::OPTIONS,
+                Some(bucket),
+                key,
+                Some(headers),
+                None,
+                None,
+            )
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await?;
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        Ok(CorsHeaders::from_headers(resp.headers()).unwrap_or_default())
+    }
+}
+
+// ========================================
+// Internal CORS XML types
+// ========================================
+
+/// CORS configuration document.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename = "CORSConfiguration")]
+struct CorsConfiguration {
+    #[serde(rename = "CORSRule", default)]
+    rules: Vec<CorsRuleXml>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CorsRuleXml {
+    #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(rename = "AllowedMethod", default)]
+    allowed_methods: Vec<String>,
+    #[serde(rename = "AllowedOrigin", default)]
+    allowed_origins: Vec<String>,
+    #[serde(rename = "AllowedHeader", default)]
+    allowed_headers: Vec<String>,
+    #[serde(rename = "MaxAgeSeconds", skip_serializing_if = "Option::is_none")]
+    max_age_seconds: Option<u64>,
+    #[serde(rename = "ExposeHeader", default)]
+    expose_headers: Vec<String>,
+}
+
+impl From<&[CorsRule]> for CorsConfiguration {
+    fn from(rules: &[CorsRule]) -> Self {
+        let rules = rules
+            .iter()
+            .map(|rule| CorsRuleXml {
+                id: rule.id.clone(),
+                allowed_methods: rule.allowed_methods.iter().map(|m| m.to_string()).collect(),
+                allowed_origins: rule.allowed_origins.clone(),
+                allowed_headers: rule.allowed_headers.clone(),
+                max_age_seconds: rule.max_age_seconds,
+                expose_headers: rule.expose_headers.clone(),
+            })
+            .collect();
+        Self { rules }
+    }
+}
+
+impl CorsConfiguration {
+    fn into_rules(self) -> Result<Vec<CorsRule>> {
+        self.rules
+            .into_iter()
+            .map(|rule| {
+                let allowed_methods = rule
+                    .allowed_methods
+                    .iter()
+                    .map(|m| {
+                        m.parse()
+                            .map_err(|_| ObsError::XmlParse(format!("invalid CORS method: {}", m)))
+                    })
+                    .collect::<Result<_>>()?;
+                Ok(CorsRule {
+                    id: rule.id,
+                    allowed_origins: rule.allowed_origins,
+                    allowed_methods,
+                    allowed_headers: rule.allowed_headers,
+                    expose_headers: rule.expose_headers,
+                    max_age_seconds: rule.max_age_seconds,
+                })
+            })
+            .collect()
+    }
+}
+
+// ========================================
+// Bucket Website
+// ========================================
+
+/// Static website hosting configuration of a bucket.
+///
+/// A bucket either hosts a website, with an index document, an optional
+/// error document and routing rules, or redirects all requests to another
+/// host.
+///
+/// ```rust
+/// use huaweicloud_sdk_rust_obs::{RoutingRule, WebsiteConfiguration};
+///
+/// let website = WebsiteConfiguration::index("index.html")
+///     .error_page("404.html")
+///     .routing_rule(
+///         RoutingRule::new()
+///             .when_key_prefix("docs/v1/")
+///             .replace_key_prefix("docs/v2/"),
+///     );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WebsiteConfiguration {
+    /// Suffix appended to requests for a directory (e.g. "index.html").
+    pub index_document: Option<String>,
+    /// Key of the object returned when a 4XX error occurs.
+    pub error_document: Option<String>,
+    /// Host all requests are redirected to.
+    pub redirect_all_requests_to: Option<RedirectAllRequestsTo>,
+    /// Rules redirecting some requests.
+    pub routing_rules: Vec<RoutingRule>,
+}
+
+impl WebsiteConfiguration {
+    /// Create a configuration that hosts a website with an index document.
+    pub fn index(suffix: impl Into<String>) -> Self {
+        Self {
+            index_document: Some(suffix.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a configuration that redirects all requests to another host.
+    pub fn redirect_all(host_name: impl Into<String>, protocol: Option<WebsiteProtocol>) -> Self {
+        Self {
+            redirect_all_requests_to: Some(RedirectAllRequestsTo {
+                host_name: host_name.into(),
+                protocol,
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Set the key of the object returned when a 4XX error occurs.
+    pub fn error_page(mut self, key: impl Into<String>) -> Self {
+        self.error_document = Some(key.into());
+        self
+    }
+
+    /// Add a routing rule.
+    pub fn routing_rule(mut self, rule: RoutingRule) -> Self {
+        self.routing_rules.push(rule);
+        self
+    }
+
+    /// Check that the configuration is accepted by OBS.
+    fn validate(&self) -> Result<()> {
+        match (&self.index_document, &self.redirect_all_requests_to) {
+            (Some(_), Some(_)) => {
+                return Err(ObsError::InvalidInput(
+                    "a website cannot have both an index document and a redirect for all requests"
+                        .to_string(),
+                ))
+            }
+            (None, None) => {
+                return Err(ObsError::InvalidInput(
+                    "a website needs an index document or a redirect for all requests".to_string(),
+                ))
+            }
+            (Some(suffix), None) => {
+                if suffix.is_empty() || suffix.contains('/') {
+                    return Err(ObsError::InvalidInput(format!(
+                        "invalid index document suffix: {}",
+                        suffix
+                    )));
+                }
+            }
+            (None, Some(redirect)) => {
+                if redirect.host_name.is_empty() {
+                    return Err(ObsError::InvalidInput(
+                        "redirect host name is required".to_string(),
+                    ));
+                }
+                if self.error_document.is_some() || !self.routing_rules.is_empty() {
+                    return Err(ObsError::InvalidInput(
+                        "a website that redirects all requests cannot have an error document or routing rules"
+                            .to_string(),
+                    ));
+                }
+            }
+        }
+
+        for rule in &self.routing_rules {
+            if rule.host_name.is_none()
+                && rule.protocol.is_none()
+                && rule.replace_key.is_none()
+                && rule.http_redirect_code.is_none()
+            {
+                return Err(ObsError::InvalidInput(
+                    "a routing rule needs a redirect target".to_string(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Redirect of all requests to another host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedirectAllRequestsTo {
+    /// Target host name.
+    pub host_name: String,
+    /// Target protocol (the protocol of the request if not set).
+    pub protocol: Option<WebsiteProtocol>,
+}
+
+/// Protocol of a website redirect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WebsiteProtocol {
+    /// http
+    Http,
+    /// https
+    Https,
+}
+
+impl WebsiteProtocol {
+    /// Get the protocol name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebsiteProtocol::Http => "http",
+            WebsiteProtocol::Https => "https",
+        }
+    }
+}
+
+impl fmt::Display for WebsiteProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for WebsiteProtocol {
+    type Err = ObsError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "http" => Ok(WebsiteProtocol::Http),
+            "https" => Ok(WebsiteProtocol::Https),
+            _ => Err(ObsError::InvalidInput(format!(
+                "unknown website protocol: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// A website routing rule: requests matching the condition are redirected.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RoutingRule {
+    /// Only redirect requests for keys with this prefix.
+    pub key_prefix_equals: Option<String>,
+    /// Only redirect requests that fail with this HTTP error code.
+    pub http_error_code_returned_equals: Option<u16>,
+    /// Target host name.
+    pub host_name: Option<String>,
+    /// Target protocol.
+    pub protocol: Option<WebsiteProtocol>,
+    /// How the key of the request is replaced.
+    pub replace_key: Option<ReplaceKey>,
+    /// HTTP status code of the redirect.
+    pub http_redirect_code: Option<u16>,
+}
+
+impl RoutingRule {
+    /// Create a rule that matches every request.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only redirect requests for keys with a prefix.
+    pub fn when_key_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.key_prefix_equals = Some(prefix.into());
+        self
+    }
+
+    /// Only redirect requests that fail with an HTTP error code.
+    pub fn when_http_error_code(mut self, code: u16) -> Self {
+        self.http_error_code_returned_equals = Some(code);
+        self
+    }
+
+    /// Redirect to another host.
+    pub fn redirect_host(mut self, host_name: impl Into<String>) -> Self {
+        self.host_name = Some(host_name.into());
+        self
+    }
+
+    /// Redirect with a protocol.
+    pub fn redirect_protocol(mut self, protocol: WebsiteProtocol) -> Self {
+        self.protocol = Some(protocol);
+        self
+    }
+
+    /// Replace the matched key prefix.
+    pub fn replace_key_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.replace_key = Some(ReplaceKey::Prefix(prefix.into()));
+        self
+    }
+
+    /// Replace the whole key.
+    pub fn replace_key(mut self, key: impl Into<String>) -> Self {
+        self.replace_key = Some(ReplaceKey::Key(key.into()));
+        self
+    }
+
+    /// Set the HTTP status code of the redirect.
+    pub fn redirect_code(mut self, code: u16) -> Self {
+        self.http_redirect_code = Some(code);
+        self
+    }
+}
+
+/// How a routing rule replaces the key of a request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplaceKey {
+    /// Replace the prefix matched by the condition.
+    Prefix(String),
+    /// Replace the whole key.
+    Key(String),
+}
+
+// ========================================
+// Put Bucket Website
+// ========================================
+
+/// Fluent builder for the PutBucketWebsite operation.
+#[derive(Debug, Clone)]
+pub struct PutBucketWebsiteFluentBuilder {
+    client: Client,
+    inner: PutBucketWebsiteInput,
+}
+
+impl PutBucketWebsiteFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: PutBucketWebsiteInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Set the website configuration.
+    pub fn configuration(mut self, configuration: WebsiteConfiguration) -> Self {
+        self.inner.configuration = configuration;
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<PutBucketWebsiteOutput> {
+        let bucket = &self.inner.bucket;
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+        self.inner.configuration.validate()?;
+
+        let mut params = HashMap::new();
+        params.insert("website".to_string(), String::new());
+
+        let configuration = WebsiteConfigurationXml::from(&self.inner.configuration);
+        let body = crate::xml_utils::to_xml(&configuration)?;
+
+        let resp = self
+            .client
+            .do_request(
+                Method::PUT,
+                Some(bucket),
+                None,
+                None,
+                Some(params),
+                Some(body.into_bytes()),
+            )
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await?;
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        Ok(PutBucketWebsiteOutput {})
+    }
+}
+
+/// Input for the PutBucketWebsite operation.
+#[derive(Debug, Clone, Default)]
+pub struct PutBucketWebsiteInput {
+    bucket: String,
+    configuration: WebsiteConfiguration,
+}
+
+/// Output for the PutBucketWebsite operation.
+#[derive(Debug, Clone)]
+pub struct PutBucketWebsiteOutput {}
+
+// ========================================
+// Get Bucket Website
+// ========================================
+
+/// Fluent builder for the GetBucketWebsite operation.
+#[derive(Debug, Clone)]
+pub struct GetBucketWebsiteFluentBuilder {
+    client: Client,
+    inner: GetBucketWebsiteInput,
+}
+
+impl GetBucketWebsiteFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: GetBucketWebsiteInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Send the request.
+    ///
+    /// A bucket without a website configuration returns a service error with
+    /// the code `NoSuchWebsiteConfiguration`.
+    pub async fn send(&self) -> Result<GetBucketWebsiteOutput> {
+        let bucket = &self.inner.bucket;
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+
+        let mut params = HashMap::new();
+        params.insert("website".to_string(), String::new());
+
+        let resp = self
+            .client
+            .do_request(Method::GET, Some(bucket), None, None, Some(params), None)
+            .await?;
+
+        let status = resp.status();
+        let text = resp.text().await?;
+        if !status.is_success() {
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        let configuration: WebsiteConfigurationXml = crate::xml_utils::from_xml(&text)?;
+        Ok(GetBucketWebsiteOutput {
+            configuration: configuration.try_into()?,
+        })
+    }
+}
+
+/// Input for the GetBucketWebsite operation.
+#[derive(Debug, Clone, Default)]
+pub struct GetBucketWebsiteInput {
+    bucket: String,
+}
+
+/// Output for the GetBucketWebsite operation.
+#[derive(Debug, Clone)]
+pub struct GetBucketWebsiteOutput {
+    configuration: WebsiteConfiguration,
+}
+
+impl GetBucketWebsiteOutput {
+    /// Get the website configuration.
+    pub fn configuration(&self) -> &WebsiteConfiguration {
+        &self.configuration
+    }
+
+    /// Take the website configuration, e.g. to modify it and put it back.
+    pub fn into_configuration(self) -> WebsiteConfiguration {
+        self.configuration
+    }
+}
+
+// ========================================
+// Delete Bucket Website
+// ========================================
+
+/// Fluent builder for the DeleteBucketWebsite operation.
+#[derive(Debug, Clone)]
+pub struct DeleteBucketWebsiteFluentBuilder {
+    client: Client,
+    inner: DeleteBucketWebsiteInput,
+}
+
+impl DeleteBucketWebsiteFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: DeleteBucketWebsiteInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<DeleteBucketWebsiteOutput> {
+        let bucket = &self.inner.bucket;
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+
+        let mut params = HashMap::new();
+        params.insert("website".to_string(), String::new());
+
+        let resp = self
+            .client
+            .do_request(Method::DELETE, Some(bucket), None, None, Some(params), None)
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await?;
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        Ok(DeleteBucketWebsiteOutput {})
+    }
+}
+
+/// Input for the DeleteBucketWebsite operation.
+#[derive(Debug, Clone, Default)]
+pub struct DeleteBucketWebsiteInput {
+    bucket: String,
+}
+
+/// Output for the DeleteBucketWebsite operation.
+#[derive(Debug, Clone)]
+pub struct DeleteBucketWebsiteOutput {}
+
+// ========================================
+// Internal website XML types
+// ========================================
+
+/// Website configuration document.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename = "WebsiteConfiguration")]
+struct WebsiteConfigurationXml {
+    #[serde(
+        rename = "RedirectAllRequestsTo",
+        skip_serializing_if = "Option::is_none"
+    )]
+    redirect_all_requests_to: Option<RedirectAllRequestsToXml>,
+    #[serde(rename = "IndexDocument", skip_serializing_if = "Option::is_none")]
+    index_document: Option<IndexDocumentXml>,
+    #[serde(rename = "ErrorDocument", skip_serializing_if = "Option::is_none")]
+    error_document: Option<ErrorDocumentXml>,
+    #[serde(rename = "RoutingRules", skip_serializing_if = "Option::is_none")]
+    routing_rules: Option<RoutingRulesXml>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RedirectAllRequestsToXml {
+    #[serde(rename = "HostName")]
+    host_name: String,
+    #[serde(rename = "Protocol", skip_serializing_if = "Option::is_none")]
+    protocol: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IndexDocumentXml {
+    #[serde(rename = "Suffix")]
+    suffix: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ErrorDocumentXml {
+    #[serde(rename = "Key")]
+    key: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RoutingRulesXml {
+    #[serde(rename = "RoutingRule", default)]
+    rules: Vec<RoutingRuleXml>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RoutingRuleXml {
+    #[serde(rename = "Condition", skip_serializing_if = "Option::is_none")]
+    condition: Option<RoutingConditionXml>,
+    #[serde(rename = "Redirect")]
+    redirect: RoutingRedirectXml,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RoutingConditionXml {
+    #[serde(rename = "KeyPrefixEquals", skip_serializing_if = "Option::is_none")]
+    key_prefix_equals: Option<String>,
+    #[serde(
+        rename = "HttpErrorCodeReturnedEquals",
+        skip_serializing_if = "Option::is_none"
+    )]
+    http_error_code_returned_equals: Option<u16>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RoutingRedirectXml {
+    #[serde(rename = "Protocol", skip_serializing_if = "Option::is_none")]
+    protocol: Option<String>,
+    #[serde(rename = "HostName", skip_serializing_if = "Option::is_none")]
+    host_name: Option<String>,
+    #[serde(
+        rename = "ReplaceKeyPrefixWith",
+        skip_serializing_if = "Option::is_none"
+    )]
+    replace_key_prefix_with: Option<String>,
+    #[serde(rename = "ReplaceKeyWith", skip_serializing_if = "Option::is_none")]
+    replace_key_with: Option<String>,
+    #[serde(rename = "HttpRedirectCode", skip_serializing_if = "Option::is_none")]
+    http_redirect_code: Option<u16>,
+}
+
+impl From<&WebsiteConfiguration> for WebsiteConfigurationXml {
+    fn from(configuration: &WebsiteConfiguration) -> Self {
+        let rules: Vec<_> = configuration
+            .routing_rules
+            .iter()
+            .map(|rule| {
+                let condition = (rule.key_prefix_equals.is_some()
+                    || rule.http_error_code_returned_equals.is_some())
+                .then(|| RoutingConditionXml {
+                    key_prefix_equals: rule.key_prefix_equals.clone(),
+                    http_error_code_returned_equals: rule.http_error_code_returned_equals,
+                });
+                let (replace_key_prefix_with, replace_key_with) = match &rule.replace_key {
+                    Some(ReplaceKey::Prefix(prefix)) => (Some(prefix.clone()), None),
+                    Some(ReplaceKey::Key(key)) => (None, Some(key.clone())),
+                    None => (None, None),
+                };
+                RoutingRuleXml {
+                    condition,
+                    redirect: RoutingRedirectXml {
+                        protocol: rule.protocol.map(|p| p.to_string()),
+                        host_name: rule.host_name.clone(),
+                        replace_key_prefix_with,
+                        replace_key_with,
+                        http_redirect_code: rule.http_redirect_code,
+                    },
+                }
+            })
+            .collect();
+
+        Self {
+            redirect_all_requests_to: configuration.redirect_all_requests_to.as_ref().map(
+                |redirect| RedirectAllRequestsToXml {
+                    host_name: redirect.host_name.clone(),
+                    protocol: redirect.protocol.map(|p| p.to_string()),
+                },
+            ),
+            index_document: configuration
+                .index_document
+                .clone()
+                .map(|suffix| IndexDocumentXml { suffix }),
+            error_document: configuration
+                .error_document
+                .clone()
+                .map(|key| ErrorDocumentXml { key }),
+            routing_rules: (!rules.is_empty()).then_some(RoutingRulesXml { rules }),
+        }
+    }
+}
+
+impl TryFrom<WebsiteConfigurationXml> for WebsiteConfiguration {
+    type Error = ObsError;
+
+    fn try_from(configuration: WebsiteConfigurationXml) -> Result<Self> {
+        let protocol = |value: Option<String>| {
+            value
+                .map(|p| {
+                    p.parse::<WebsiteProtocol>()
+                        .map_err(|_| ObsError::XmlParse(format!("invalid website protocol: {}", p)))
+                })
+                .transpose()
+        };
+
+        let redirect_all_requests_to = configuration
+            .redirect_all_requests_to
+            .map(|redirect| {
+                Ok::<_, ObsError>(RedirectAllRequestsTo {
+                    host_name: redirect.host_name,
+                    protocol: protocol(redirect.protocol)?,
+                })
+            })
+            .transpose()?;
+
+        let routing_rules = configuration
+            .routing_rules
+            .map(|rules| rules.rules)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|rule| {
+                let (key_prefix_equals, http_error_code_returned_equals) = match rule.condition {
+                    Some(condition) => (
+                        condition.key_prefix_equals,
+                        condition.http_error_code_returned_equals,
+                    ),
+                    None => (None, None),
+                };
+                let redirect = rule.redirect;
+                let replace_key =
+                    match (redirect.replace_key_prefix_with, redirect.replace_key_with) {
+                        (Some(prefix), _) => Some(ReplaceKey::Prefix(prefix)),
+                        (None, Some(key)) => Some(ReplaceKey::Key(key)),
+                        (None, None) => None,
+                    };
+                Ok(RoutingRule {
+                    key_prefix_equals,
+                    http_error_code_returned_equals,
+                    host_name: redirect.host_name,
+                    protocol: protocol(redirect.protocol)?,
+                    replace_key,
+                    http_redirect_code: redirect.http_redirect_code,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            index_document: configuration.index_document.map(|d| d.suffix),
+            error_document: configuration.error_document.map(|d| d.key),
+            redirect_all_requests_to,
+            routing_rules,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[tokio::test]
+    async fn test_create_bucket_kms_key_requires_kms() {
+        let config = crate::Config::builder()
+            .access_key("ak", "sk")
+            .endpoint("obs.cn-north-4.myhuaweicloud.com")
+            .build()
+            .unwrap();
+        let client = Client::from_config(config).unwrap();
+
+        let result = client
+            .create_bucket()
+            .bucket("bucket")
+            .server_side_encryption("AES256")
+            .ssekms_key_id("key")
+            .send()
+            .await;
+        assert!(matches!(result, Err(ObsError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_head_bucket_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-obs-bucket-location", "cn-north-4".parse().unwrap());
+        headers.insert("x-obs-storage-class", "WARM".parse().unwrap());
+        headers.insert("x-obs-version", "3.0".parse().unwrap());
+        headers.insert("x-obs-az-redundancy", "3az".parse().unwrap());
+        headers.insert("x-obs-fs-file-interface", "Enabled".parse().unwrap());
+        headers.insert("x-obs-epid", "0".parse().unwrap());
+
+        let output = HeadBucketOutput::from_headers(&headers);
+        assert_eq!(output.location(), Some("cn-north-4"));
+        assert_eq!(output.storage_class_kind(), Some(StorageClass::Warm));
+        assert_eq!(output.obs_version(), Some("3.0"));
+        assert!(output.is_multi_az());
+        assert!(output.is_parallel_file_system());
+        assert_eq!(output.epid(), Some("0"));
+        assert!(output.cors().is_none());
+
+        headers.insert(
+            "Access-Control-Allow-Origin",
+            "https://example.com".parse().unwrap(),
+        );
+        headers.insert("Access-Control-Allow-Methods", "GET, PUT".parse().unwrap());
+        headers.insert("Access-Control-Max-Age", "100".parse().unwrap());
+        let output = HeadBucketOutput::from_headers(&headers);
+        let cors = output.cors().unwrap();
+        assert_eq!(cors.allow_origin(), "https://example.com");
+        assert_eq!(cors.allow_methods(), ["GET", "PUT"]);
+        assert!(cors.allow_headers().is_empty());
+        assert_eq!(cors.max_age(), Some(100));
+    }
+
+    #[test]
+    fn test_policy_json() {
+        let policy = Policy::new()
+            .statement(Statement::public_read("bucket", "public/").sid("public"))
+            .statement(
+                Statement::deny()
+                    .principal(Principal::user("d1", "u1"))
+                    .action("DeleteObject")
+                    .resource("bucket/*")
+                    .condition("IpAddress", "SourceIp", "10.0.0.0/8"),
+            );
+        let json = serde_json::to_value(&policy).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "Statement": [
+                    {
+                        "Sid": "public",
+                        "Effect": "Allow",
+                        "Principal": "*",
+                        "Action": ["GetObject"],
+                        "Resource": ["bucket/public/*"]
+                    },
+                    {
+                        "Effect": "Deny",
+                        "Principal": {"ID": ["domain/d1:user/u1"]},
+                        "Action": ["DeleteObject"],
+                        "Resource": ["bucket/*"],
+                        "Condition": {"IpAddress": {"SourceIp": "10.0.0.0/8"}}
+                    }
+                ]
+            })
+        );
+        let parsed: Policy = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, policy);
+
+        // Single values and a wildcard ID are accepted in responses
+        let parsed: Policy = serde_json::from_str(
+            r#"{"Statement":[{"Effect":"Allow","Principal":{"ID":"*"},
+                "Action":"GetObject","Resource":"bucket/*"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(parsed.statements[0].principal, Principal::Everyone);
+        assert_eq!(parsed.statements[0].actions, ["GetObject"]);
+    }
+
+    #[test]
+    fn test_policy_validate() {
+        let valid = Policy::new().statement(Statement::read_only_for_account("bucket", "d1"));
+        assert!(valid.validate("bucket").is_ok());
+        assert!(Policy::new().validate("bucket").is_err());
+
+        let invalid = [
+            Statement::allow().action("GetObject").resource("bucket/*"),
+            Statement::public_read("other", ""),
+            Statement::public_read("bucket", "").action("Get Object"),
+            Statement::public_read("bucket", "").action(":GetObject"),
+            Statement::allow()
+                .principal(Principal::everyone())
+                .resource("bucket"),
+        ];
+        for statement in invalid {
+            let policy = Policy::new().statement(statement);
+            assert!(policy.validate("bucket").is_err(), "{:?}", policy);
+        }
+
+        let wildcard = Policy::new().statement(
+            Statement::allow()
+                .principal(Principal::account("d1"))
+                .action("*")
+                .action("obs:Get*")
+                .resource("bucket"),
+        );
+        assert!(wildcard.validate("bucket").is_ok());
+    }
+
+    #[test]
+    fn test_object_lock_configuration_xml() {
+        let configuration = ObjectLockConfiguration {
+            object_lock_enabled: Some("Enabled".to_string()),
+            rule: Some(ObjectLockRule {
+                default_retention: DefaultRetention {
+                    mode: "COMPLIANCE".to_string(),
+                    days: Some(365),
+                    years: None,
+                },
+            }),
+        };
+        let xml = crate::xml_utils::to_xml(&configuration).unwrap();
+        assert_eq!(
+            xml,
+            "<ObjectLockConfiguration><ObjectLockEnabled>Enabled</ObjectLockEnabled>\
+             <Rule><DefaultRetention><Mode>COMPLIANCE</Mode><Days>365</Days></DefaultRetention>\
+             </Rule></ObjectLockConfiguration>"
+        );
+
+        let parsed: ObjectLockConfiguration = crate::xml_utils::from_xml(&xml).unwrap();
+        assert_eq!(
+            parsed.rule.unwrap().default_retention,
+            configuration.rule.unwrap().default_retention
+        );
+
+        let enabled_only: ObjectLockConfiguration = crate::xml_utils::from_xml(
+            "<ObjectLockConfiguration><ObjectLockEnabled>Enabled</ObjectLockEnabled></ObjectLockConfiguration>",
+        )
+        .unwrap();
+        assert!(enabled_only.rule.is_none());
+    }
+
+    #[test]
+    fn test_versioning_configuration_xml() {
+        let configuration = VersioningConfiguration {
+            status: Some("Enabled".to_string()),
+        };
+        assert_eq!(
+            crate::xml_utils::to_xml(&configuration).unwrap(),
+            "<VersioningConfiguration><Status>Enabled</Status></VersioningConfiguration>"
+        );
+
+        let never_enabled: VersioningConfiguration =
+            crate::xml_utils::from_xml("<VersioningConfiguration/>").unwrap();
+        assert!(never_enabled.status.is_none());
+    }
+
+    #[test]
+    fn test_list_versions_result_xml() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListVersionsResult xmlns="http://obs.myhwclouds.com/doc/2015-06-30/">
+  <Name>bucket</Name>
+  <Prefix>logs/</Prefix>
+  <KeyMarker></KeyMarker>
+  <VersionIdMarker></VersionIdMarker>
+  <NextKeyMarker>logs/b.txt</NextKeyMarker>
+  <NextVersionIdMarker>v3</NextVersionIdMarker>
+  <MaxKeys>3</MaxKeys>
+  <IsTruncated>true</IsTruncated>
+  <Version>
+    <Key>logs/a.txt</Key>
+    <VersionId>v1</VersionId>
+    <IsLatest>true</IsLatest>
+    <LastModified>2024-01-01T00:00:00.000Z</LastModified>
+    <ETag>"abc"</ETag>
+    <Size>10</Size>
+    <Owner><ID>owner</ID></Owner>
+    <StorageClass>STANDARD</StorageClass>
+  </Version>
+  <DeleteMarker>
+    <Key>logs/b.txt</Key>
+    <VersionId>v2</VersionId>
+    <IsLatest>true</IsLatest>
+    <LastModified>2024-01-02T00:00:00.000Z</LastModified>
+  </DeleteMarker>
+  <Version>
+    <Key>logs/b.txt</Key>
+    <VersionId>v3</VersionId>
+    <IsLatest>false</IsLatest>
+    <Size>20</Size>
+  </Version>
+  <CommonPrefixes><Prefix>logs/old/</Prefix></CommonPrefixes>
+</ListVersionsResult>"#;
+
+        let result: ListVersionsResult = crate::xml_utils::from_xml(xml).unwrap();
+        let output = ListObjectVersionsOutput::from(result);
+        assert_eq!(output.name(), "bucket");
+        assert_eq!(output.prefix(), Some("logs/"));
+        assert_eq!(output.key_marker(), None);
+        assert_eq!(output.next_key_marker(), Some("logs/b.txt"));
+        assert_eq!(output.next_version_id_marker(), Some("v3"));
+        assert_eq!(output.max_keys(), 3);
+        assert!(output.is_truncated());
+
+        let versions: Vec<_> = output
+            .versions()
+            .iter()
+            .map(|v| (v.key(), v.version_id(), v.is_latest(), v.size()))
+            .collect();
+        assert_eq!(
+            versions,
+            vec![
+                ("logs/a.txt", "v1", true, 10),
+                ("logs/b.txt", "v3", false, 20)
+            ]
+        );
+        assert_eq!(output.versions()[0].owner().map(|o| o.id()), Some("owner"));
+        assert_eq!(
+            output.versions()[0].storage_class_kind(),
+            StorageClass::Standard
+        );
+        assert_eq!(
+            output.delete_markers()[0].last_modified_time(),
+            Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).single()
+        );
+        assert_eq!(output.delete_markers().len(), 1);
+        assert_eq!(output.delete_markers()[0].version_id(), "v2");
+        assert!(output.delete_markers()[0].is_latest());
+        assert_eq!(output.common_prefixes(), ["logs/old/".to_string()]);
+    }
+
+    #[test]
+    fn test_lifecycle_configuration_xml() {
+        // Request example from the OBS PutBucketLifecycle documentation.
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<LifecycleConfiguration>
+    <Rule>
+        <ID>delete-2-days</ID>
+        <Prefix>test/</Prefix>
+        <Status>Enabled</Status>
+        <Expiration>
+            <Days>70</Days>
+        </Expiration>
+        <NoncurrentVersionExpiration>
+            <NoncurrentDays>70</NoncurrentDays>
+        </NoncurrentVersionExpiration>
+        <Transition>
+            <Days>30</Days>
+            <StorageClass>WARM</StorageClass>
+        </Transition>
+        <Transition>
+            <Days>60</Days>
+            <StorageClass>COLD</StorageClass>
+        </Transition>
+        <NoncurrentVersionTransition>
+            <NoncurrentDays>30</NoncurrentDays>
+            <StorageClass>WARM</StorageClass>
+        </NoncurrentVersionTransition>
+        <NoncurrentVersionTransition>
+            <NoncurrentDays>60</NoncurrentDays>
+            <StorageClass>COLD</StorageClass>
+        </NoncurrentVersionTransition>
+    </Rule>
+</LifecycleConfiguration>"#;
+
+        let configuration: LifecycleConfiguration = crate::xml_utils::from_xml(xml).unwrap();
+        let rules = configuration.into_rules().unwrap();
+        let expected = LifecycleRule::new("delete-2-days")
+            .prefix("test/")
+            .expire_after_days(70)
+            .noncurrent_expire_after_days(70)
+            .transition_after_days(30, StorageClass::Warm)
+            .transition_after_days(60, StorageClass::Cold)
+            .noncurrent_transition_after_days(30, StorageClass::Warm)
+            .noncurrent_transition_after_days(60, StorageClass::Cold);
+        assert_eq!(rules, vec![expected]);
+
+        let xml =
+            crate::xml_utils::to_xml(&LifecycleConfiguration::from(rules.as_slice())).unwrap();
+        assert_eq!(
+            xml,
+            "<LifecycleConfiguration><Rule><ID>delete-2-days</ID><Prefix>test/</Prefix>\
+             <Status>Enabled</Status><Expiration><Days>70</Days></Expiration>\
+             <Transition><Days>30</Days><StorageClass>WARM</StorageClass></Transition>\
+             <Transition><Days>60</Days><StorageClass>COLD</StorageClass></Transition>\
+             <NoncurrentVersionExpiration><NoncurrentDays>70</NoncurrentDays></NoncurrentVersionExpiration>\
+             <NoncurrentVersionTransition><NoncurrentDays>30</NoncurrentDays><StorageClass>WARM</StorageClass></NoncurrentVersionTransition>\
+             <NoncurrentVersionTransition><NoncurrentDays>60</NoncurrentDays><StorageClass>COLD</StorageClass></NoncurrentVersionTransition>\
+             </Rule></LifecycleConfiguration>"
+        );
+        let parsed: LifecycleConfiguration = crate::xml_utils::from_xml(&xml).unwrap();
+        assert_eq!(parsed.into_rules().unwrap(), rules);
+    }
+
+    #[test]
+    fn test_lifecycle_dates_and_tags_xml() {
+        let date = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let rules = vec![
+            LifecycleRule::new("tagged")
+                .prefix("logs/")
+                .tag("type", "log")
+                .status(false)
+                .expire_at(date)
+                .transition_at(date, StorageClass::DeepArchive),
+            LifecycleRule::new("uploads").abort_incomplete_multipart_upload_after_days(7),
+        ];
+
+        let xml =
+            crate::xml_utils::to_xml(&LifecycleConfiguration::from(rules.as_slice())).unwrap();
+        assert_eq!(
+            xml,
+            "<LifecycleConfiguration><Rule><ID>tagged</ID><Filter><And><Prefix>logs/</Prefix>\
+             <Tag><Key>type</Key><Value>log</Value></Tag></And></Filter><Status>Disabled</Status>\
+             <Expiration><Date>2025-01-01T00:00:00.000Z</Date></Expiration>\
+             <Transition><Date>2025-01-01T00:00:00.000Z</Date><StorageClass>DEEP_ARCHIVE</StorageClass></Transition>\
+             </Rule><Rule><ID>uploads</ID><Prefix/><Status>Enabled</Status>\
+             <AbortIncompleteMultipartUpload><DaysAfterInitiation>7</DaysAfterInitiation></AbortIncompleteMultipartUpload>\
+             </Rule></LifecycleConfiguration>"
+        );
+        let parsed: LifecycleConfiguration = crate::xml_utils::from_xml(&xml).unwrap();
+        assert_eq!(parsed.into_rules().unwrap(), rules);
+    }
+
+    #[test]
+    fn test_lifecycle_rule_validate() {
+        assert!(LifecycleRule::new("empty").validate().is_err());
+        assert!(LifecycleRule::new("standard")
+            .transition_after_days(30, StorageClass::Standard)
+            .validate()
+            .is_err());
+        assert!(LifecycleRule::new("noon")
+            .expire_at(Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap())
+            .validate()
+            .is_err());
+        assert!(LifecycleRule::new("ok")
+            .noncurrent_transition_after_days(30, StorageClass::Cold)
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_cors_configuration_xml() {
+        // Request example from the OBS PutBucketCors documentation.
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<CORSConfiguration>
+    <CORSRule>
+        <ID>test</ID>
+        <AllowedMethod>PUT</AllowedMethod>
+        <AllowedMethod>GET</AllowedMethod>
+        <AllowedOrigin>www.example.com</AllowedOrigin>
+        <AllowedHeader>header-1</AllowedHeader>
+        <AllowedHeader>header-2</AllowedHeader>
+        <MaxAgeSeconds>100</MaxAgeSeconds>
+        <ExposeHeader>header-1</ExposeHeader>
+        <ExposeHeader>header-2</ExposeHeader>
+    </CORSRule>
+</CORSConfiguration>"#;
+
+        let configuration: CorsConfiguration = crate::xml_utils::from_xml(xml).unwrap();
+        let rules = configuration.into_rules().unwrap();
+        let expected = CorsRule::new()
+            .id("test")
+            .allow_method(CorsMethod::Put)
+            .allow_method(CorsMethod::Get)
+            .allow_origin("www.example.com")
+            .allow_header("header-1")
+            .allow_header("header-2")
+            .max_age(100)
+            .expose_header("header-1")
+            .expose_header("header-2");
+        assert_eq!(rules, vec![expected]);
+
+        let xml = crate::xml_utils::to_xml(&CorsConfiguration::from(rules.as_slice())).unwrap();
+        assert_eq!(
+            xml,
+            "<CORSConfiguration><CORSRule><ID>test</ID>\
+             <AllowedMethod>PUT</AllowedMethod><AllowedMethod>GET</AllowedMethod>\
+             <AllowedOrigin>www.example.com</AllowedOrigin>\
+             <AllowedHeader>header-1</AllowedHeader><AllowedHeader>header-2</AllowedHeader>\
+             <MaxAgeSeconds>100</MaxAgeSeconds>\
+             <ExposeHeader>header-1</ExposeHeader><ExposeHeader>header-2</ExposeHeader>\
+             </CORSRule></CORSConfiguration>"
+        );
+        let parsed: CorsConfiguration = crate::xml_utils::from_xml(&xml).unwrap();
+        assert_eq!(parsed.into_rules().unwrap(), rules);
+
+        let invalid: CorsConfiguration = crate::xml_utils::from_xml(
+            "<CORSConfiguration><CORSRule><AllowedMethod>PATCH</AllowedMethod></CORSRule></CORSConfiguration>",
+        )
+        .unwrap();
+        assert!(invalid.into_rules().is_err());
+    }
+
+    #[test]
+    fn test_cors_rule_validate() {
+        assert!(CorsRule::new().allow_origin("*").validate().is_err());
+        assert!(CorsRule::new()
+            .allow_origin("https://*.example.*")
+            .allow_method(CorsMethod::Get)
+            .validate()
+            .is_err());
+        assert!(CorsRule::new()
+            .allow_origin("https://*.example.com")
+            .allow_method(CorsMethod::Get)
+            .allow_header("x-obs-*")
+            .validate()
+            .is_ok());
+        assert_eq!("DELETE".parse::<CorsMethod>().unwrap(), CorsMethod::Delete);
+        assert!("get".parse::<CorsMethod>().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_options_bucket_requires_origin_and_method() {
+        let config = crate::Config::builder()
+            .access_key("ak", "sk")
+            .endpoint("obs.cn-north-4.myhuaweicloud.com")
+            .build()
+            .unwrap();
+        let client = Client::from_config(config).unwrap();
+
+        let result = client
+            .options_bucket()
+            .bucket("bucket")
+            .request_method(CorsMethod::Get)
+            .send()
+            .await;
+        assert!(matches!(result, Err(ObsError::InvalidInput(_))));
+
+        let result = client
+            .options_object()
+            .bucket("bucket")
+            .key("key")
+            .origin("https://app.example.com")
+            .send()
+            .await;
+        assert!(matches!(result, Err(ObsError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_website_configuration_xml() {
+        // Request example from the OBS PutBucketWebsite documentation.
+        let xml = r#"<WebsiteConfiguration xmlns="http://obs.cn-north-4.myhuaweicloud.com/doc/2015-06-30/">
+  <IndexDocument>
+    <Suffix>index.html</Suffix>
+  </IndexDocument>
+  <ErrorDocument>
+    <Key>SomeErrorDocument.html</Key>
+  </ErrorDocument>
+  <RoutingRules>
+    <RoutingRule>
+      <Condition>
+        <KeyPrefixEquals>folder0/</KeyPrefixEquals>
+      </Condition>
+      <Redirect>
+        <ReplaceKeyPrefixWith>folder/</ReplaceKeyPrefixWith>
+      </Redirect>
+    </RoutingRule>
+    <RoutingRule>
+      <Condition>
+        <HttpErrorCodeReturnedEquals>404</HttpErrorCodeReturnedEquals>
+      </Condition>
+      <Redirect>
+        <Protocol>https</Protocol>
+        <HostName>www.example.com</HostName>
+        <ReplaceKeyWith>404.html</ReplaceKeyWith>
+        <HttpRedirectCode>302</HttpRedirectCode>
+      </Redirect>
+    </RoutingRule>
+  </RoutingRules>
+</WebsiteConfiguration>"#;
+
+        let parsed: WebsiteConfigurationXml = crate::xml_utils::from_xml(xml).unwrap();
+        let website = WebsiteConfiguration::try_from(parsed).unwrap();
+        let expected = WebsiteConfiguration::index("index.html")
+            .error_page("SomeErrorDocument.html")
+            .routing_rule(
+                RoutingRule::new()
+                    .when_key_prefix("folder0/")
+                    .replace_key_prefix("folder/"),
+            )
+            .routing_rule(
+                RoutingRule::new()
+                    .when_http_error_code(404)
+                    .redirect_protocol(WebsiteProtocol::Https)
+                    .redirect_host("www.example.com")
+                    .replace_key("404.html")
+                    .redirect_code(302),
+            );
+        assert_eq!(website, expected);
+        assert!(website.validate().is_ok());
+
+        let xml = crate::xml_utils::to_xml(&WebsiteConfigurationXml::from(&website)).unwrap();
+        assert_eq!(
+            xml,
+            "<WebsiteConfiguration><IndexDocument><Suffix>index.html</Suffix></IndexDocument>\
+             <ErrorDocument><Key>SomeErrorDocument.html</Key></ErrorDocument><RoutingRules>\
+             <RoutingRule><Condition><KeyPrefixEquals>folder0/</KeyPrefixEquals></Condition>\
+             <Redirect><ReplaceKeyPrefixWith>folder/</ReplaceKeyPrefixWith></Redirect></RoutingRule>\
+             <RoutingRule><Condition><HttpErrorCodeReturnedEquals>404</HttpErrorCodeReturnedEquals></Condition>\
+             <Redirect><Protocol>https</Protocol><HostName>www.example.com</HostName>\
+             <ReplaceKeyWith>404.html</ReplaceKeyWith><HttpRedirectCode>302</HttpRedirectCode></Redirect>\
+             </RoutingRule></RoutingRules></WebsiteConfiguration>"
+        );
+        let parsed: WebsiteConfigurationXml = crate::xml_utils::from_xml(&xml).unwrap();
+        assert_eq!(WebsiteConfiguration::try_from(parsed).unwrap(), website);
+    }
+
+    #[test]
+    fn test_website_redirect_all_xml() {
+        let xml = r#"<WebsiteConfiguration xmlns="http://obs.cn-north-4.myhuaweicloud.com/doc/2015-06-30/">
+    <RedirectAllRequestsTo>
+        <HostName>www.example.com</HostName>
+    </RedirectAllRequestsTo>
+</WebsiteConfiguration>"#;
+        let parsed: WebsiteConfigurationXml = crate::xml_utils::from_xml(xml).unwrap();
+        let website = WebsiteConfiguration::try_from(parsed).unwrap();
+        assert_eq!(
+            website,
+            WebsiteConfiguration::redirect_all("www.example.com", None)
+        );
+
+        let website =
+            WebsiteConfiguration::redirect_all("www.example.com", Some(WebsiteProtocol::Https));
+        assert_eq!(
+            crate::xml_utils::to_xml(&WebsiteConfigurationXml::from(&website)).unwrap(),
+            "<WebsiteConfiguration><RedirectAllRequestsTo><HostName>www.example.com</HostName>\
+             <Protocol>https</Protocol></RedirectAllRequestsTo></WebsiteConfiguration>"
+        );
+    }
+
+    #[test]
+    fn test_website_configuration_validate() {
+        assert!(WebsiteConfiguration::default().validate().is_err());
+        assert!(WebsiteConfiguration::index("docs/index.html")
+            .validate()
+            .is_err());
+        assert!(WebsiteConfiguration::index("index.html")
+            .routing_rule(RoutingRule::new().when_key_prefix("old/"))
+            .validate()
+            .is_err());
+        assert!(WebsiteConfiguration::redirect_all("www.example.com", None)
+            .error_page("404.html")
+            .validate()
+            .is_err());
+        let mut both = WebsiteConfiguration::index("index.html");
+        both.redirect_all_requests_to =
+            WebsiteConfiguration::redirect_all("www.example.com", None).redirect_all_requests_to;
+        assert!(both.validate().is_err());
+    }
+}
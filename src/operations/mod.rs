@@ -10,10 +10,14 @@
 mod bucket;
 mod multipart;
 mod object;
+mod transfer;
+mod types;
 
 pub use bucket::*;
 pub use multipart::*;
 pub use object::*;
+pub use transfer::*;
+pub use types::*;
 
 use crate::client::Client;
 use crate::error::Result;
@@ -99,3 +99,55 @@ pub struct AbortMultipartUploadInput {
 /// Output for the AbortMultipartUpload operation.
 #[derive(Debug, Clone)]
 pub struct AbortMultipartUploadOutput {}
+
+/// Aborts a multipart upload when dropped before it is disarmed.
+///
+/// The abort request is sent in the background, so the guard must be
+/// dropped within a Tokio runtime for the abort to happen.
+pub(crate) struct AbortGuard {
+    client: Client,
+    bucket: String,
+    key: String,
+    upload_id: Option<String>,
+}
+
+impl AbortGuard {
+    /// Create a guard for an upload.
+    pub(crate) fn new(client: Client, bucket: &str, key: &str, upload_id: &str) -> Self {
+        Self {
+            client,
+            bucket: bucket.to_string(),
+            key: key.to_string(),
+            upload_id: Some(upload_id.to_string()),
+        }
+    }
+
+    /// Get the upload ID.
+    pub(crate) fn upload_id(&self) -> &str {
+        self.upload_id.as_deref().unwrap_or_default()
+    }
+
+    /// Keep the upload, e.g. after it was completed.
+    pub(crate) fn disarm(mut self) {
+        self.upload_id = None;
+    }
+}
+
+impl Drop for AbortGuard {
+    fn drop(&mut self) {
+        let Some(upload_id) = self.upload_id.take() else {
+            return;
+        };
+        let request = self
+            .client
+            .abort_multipart_upload()
+            .bucket(&self.bucket)
+            .key(&self.key)
+            .upload_id(upload_id);
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            runtime.spawn(async move {
+                let _ = request.send().await;
+            });
+        }
+    }
+}
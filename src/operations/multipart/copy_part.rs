@@ -2,11 +2,13 @@
 
 use std::collections::HashMap;
 
+use chrono::{DateTime, Utc};
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::client::Client;
 use crate::error::{ObsError, Result};
+use crate::operations::parse_timestamp;
 
 /// Fluent builder for the CopyPart operation.
 ///
@@ -320,6 +322,11 @@ impl CopyPartOutput {
         &self.last_modified
     }
 
+    /// Get the last modified time as a UTC timestamp (`None` if it cannot be parsed).
+    pub fn last_modified_time(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&self.last_modified)
+    }
+
     /// Get the part number.
     pub fn part_number(&self) -> i32 {
         self.part_number
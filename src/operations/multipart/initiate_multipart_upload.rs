@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::client::Client;
 use crate::error::{ObsError, Result};
+use crate::operations::{CannedAcl, GrantHeaders, TagSet};
 
 /// Fluent builder for the InitiateMultipartUpload operation.
 ///
@@ -41,11 +42,18 @@ impl InitiateMultipartUploadFluentBuilder {
     /// Set the ACL (Access Control List).
     ///
     /// Possible values: private, public-read, public-read-write.
+    #[deprecated(note = "use `canned_acl` with a `CannedAcl` instead")]
     pub fn acl(mut self, acl: impl Into<String>) -> Self {
         self.inner.acl = Some(acl.into());
         self
     }
 
+    /// Set a canned ACL (predefined ACL).
+    pub fn canned_acl(mut self, acl: CannedAcl) -> Self {
+        self.inner.acl = Some(acl.to_string());
+        self
+    }
+
     /// Set the storage class.
     ///
     /// Possible values: STANDARD, WARM, COLD, DEEP_ARCHIVE.
@@ -57,32 +65,50 @@ impl InitiateMultipartUploadFluentBuilder {
     /// Set the grant-read ACL header.
     ///
     /// Grants read permission to the specified domain IDs.
+    #[deprecated(note = "use `grants` with `GrantHeaders::read` instead")]
     pub fn grant_read(mut self, grant_read: impl Into<String>) -> Self {
-        self.inner.grant_read = Some(grant_read.into());
+        self.inner
+            .grants
+            .set_header_value("x-obs-grant-read", &grant_read.into());
         self
     }
 
     /// Set the grant-read-acp ACL header.
     ///
     /// Grants read ACL permission to the specified domain IDs.
+    #[deprecated(note = "use `grants` with `GrantHeaders::read_acp` instead")]
     pub fn grant_read_acp(mut self, grant_read_acp: impl Into<String>) -> Self {
-        self.inner.grant_read_acp = Some(grant_read_acp.into());
+        self.inner
+            .grants
+            .set_header_value("x-obs-grant-read-acp", &grant_read_acp.into());
         self
     }
 
     /// Set the grant-write-acp ACL header.
     ///
     /// Grants write ACL permission to the specified domain IDs.
+    #[deprecated(note = "use `grants` with `GrantHeaders::write_acp` instead")]
     pub fn grant_write_acp(mut self, grant_write_acp: impl Into<String>) -> Self {
-        self.inner.grant_write_acp = Some(grant_write_acp.into());
+        self.inner
+            .grants
+            .set_header_value("x-obs-grant-write-acp", &grant_write_acp.into());
         self
     }
 
     /// Set the grant-full-control ACL header.
     ///
     /// Grants full control permission to the specified domain IDs.
+    #[deprecated(note = "use `grants` with `GrantHeaders::full_control` instead")]
     pub fn grant_full_control(mut self, grant_full_control: impl Into<String>) -> Self {
-        self.inner.grant_full_control = Some(grant_full_control.into());
+        self.inner
+            .grants
+            .set_header_value("x-obs-grant-full-control", &grant_full_control.into());
+        self
+    }
+
+    /// Set the grants of the object, sent as `x-obs-grant-*` headers.
+    pub fn grants(mut self, grants: GrantHeaders) -> Self {
+        self.inner.grants = grants;
         self
     }
 
@@ -152,6 +178,12 @@ impl InitiateMultipartUploadFluentBuilder {
         self
     }
 
+    /// Set the object tags from a tag set.
+    pub fn tag_set(mut self, tag_set: TagSet) -> Self {
+        self.inner.tagging = Some(tag_set.to_header_value());
+        self
+    }
+
     /// Set the object lock mode.
     ///
     /// Possible value: COMPLIANCE.
@@ -180,6 +212,32 @@ impl InitiateMultipartUploadFluentBuilder {
         self
     }
 
+    /// Set the content disposition.
+    pub fn content_disposition(mut self, content_disposition: impl Into<String>) -> Self {
+        self.inner.content_disposition = Some(content_disposition.into());
+        self
+    }
+
+    /// Set the content language.
+    pub fn content_language(mut self, content_language: impl Into<String>) -> Self {
+        self.inner.content_language = Some(content_language.into());
+        self
+    }
+
+    /// Set the cache control.
+    pub fn cache_control(mut self, cache_control: impl Into<String>) -> Self {
+        self.inner.cache_control = Some(cache_control.into());
+        self
+    }
+
+    /// Set the HTTP `Expires` header returned when the object is downloaded.
+    ///
+    /// Unlike [`expires`](Self::expires), this does not delete the object.
+    pub fn http_expires(mut self, expires: impl Into<String>) -> Self {
+        self.inner.http_expires = Some(expires.into());
+        self
+    }
+
     /// Add a custom metadata header.
     ///
     /// The key should not include the "x-obs-meta-" prefix.
@@ -219,34 +277,13 @@ impl InitiateMultipartUploadFluentBuilder {
 
         // ACL headers
         if let Some(ref acl) = self.inner.acl {
-            if let Ok(value) = reqwest::header::HeaderValue::from_str(acl) {
-                headers.insert("x-obs-acl", value);
-            }
-        }
-
-        if let Some(ref grant_read) = self.inner.grant_read {
-            if let Ok(value) = reqwest::header::HeaderValue::from_str(grant_read) {
-                headers.insert("x-obs-grant-read", value);
-            }
-        }
-
-        if let Some(ref grant_read_acp) = self.inner.grant_read_acp {
-            if let Ok(value) = reqwest::header::HeaderValue::from_str(grant_read_acp) {
-                headers.insert("x-obs-grant-read-acp", value);
-            }
-        }
-
-        if let Some(ref grant_write_acp) = self.inner.grant_write_acp {
-            if let Ok(value) = reqwest::header::HeaderValue::from_str(grant_write_acp) {
-                headers.insert("x-obs-grant-write-acp", value);
-            }
-        }
-
-        if let Some(ref grant_full_control) = self.inner.grant_full_control {
-            if let Ok(value) = reqwest::header::HeaderValue::from_str(grant_full_control) {
-                headers.insert("x-obs-grant-full-control", value);
-            }
+            let acl: CannedAcl = acl.parse()?;
+            headers.insert(
+                "x-obs-acl",
+                reqwest::header::HeaderValue::from_static(acl.as_str()),
+            );
         }
+        self.inner.grants.apply(&mut headers)?;
 
         // Storage class
         if let Some(ref storage_class) = self.inner.storage_class {
@@ -348,6 +385,20 @@ impl InitiateMultipartUploadFluentBuilder {
             }
         }
 
+        let content_headers = [
+            ("Content-Disposition", &self.inner.content_disposition),
+            ("Content-Language", &self.inner.content_language),
+            ("Cache-Control", &self.inner.cache_control),
+            ("Expires", &self.inner.http_expires),
+        ];
+        for (name, value) in content_headers {
+            if let Some(value) = value {
+                if let Ok(value) = reqwest::header::HeaderValue::from_str(value) {
+                    headers.insert(name, value);
+                }
+            }
+        }
+
         // Custom metadata
         for (k, v) in &self.inner.metadata {
             let header_name = format!("x-obs-meta-{}", k);
@@ -390,10 +441,7 @@ pub struct InitiateMultipartUploadInput {
     key: String,
     acl: Option<String>,
     storage_class: Option<String>,
-    grant_read: Option<String>,
-    grant_read_acp: Option<String>,
-    grant_write_acp: Option<String>,
-    grant_full_control: Option<String>,
+    grants: GrantHeaders,
     website_redirect_location: Option<String>,
     server_side_encryption: Option<String>,
     server_side_data_encryption: Option<String>,
@@ -408,6 +456,10 @@ pub struct InitiateMultipartUploadInput {
     object_lock_retain_until_date: Option<String>,
     content_type: Option<String>,
     content_encoding: Option<String>,
+    content_disposition: Option<String>,
+    content_language: Option<String>,
+    cache_control: Option<String>,
+    http_expires: Option<String>,
     metadata: std::collections::HashMap<String, String>,
     encoding_type: Option<String>,
 }
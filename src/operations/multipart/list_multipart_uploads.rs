@@ -2,11 +2,13 @@
 
 use std::collections::HashMap;
 
+use chrono::{DateTime, Utc};
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::client::Client;
 use crate::error::{ObsError, Result};
+use crate::operations::{parse_timestamp, StorageClass};
 
 /// Fluent builder for the ListMultipartUploads operation.
 ///
@@ -298,10 +300,20 @@ impl MultipartUpload {
         &self.storage_class
     }
 
+    /// Get the storage class as a [`StorageClass`].
+    pub fn storage_class_kind(&self) -> StorageClass {
+        StorageClass::from(self.storage_class.as_str())
+    }
+
     /// Get the initiated timestamp.
     pub fn initiated(&self) -> &str {
         &self.initiated
     }
+
+    /// Get the initiated time as a UTC timestamp (`None` if it cannot be parsed).
+    pub fn initiated_time(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&self.initiated)
+    }
 }
 
 /// Initiator information for multipart upload.
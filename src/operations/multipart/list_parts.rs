@@ -2,11 +2,13 @@
 
 use std::collections::HashMap;
 
+use chrono::{DateTime, Utc};
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::client::Client;
 use crate::error::{ObsError, Result};
+use crate::operations::{parse_timestamp, StorageClass};
 
 /// Fluent builder for the ListParts operation.
 ///
@@ -183,6 +185,11 @@ impl ListPartsOutput {
         &self.storage_class
     }
 
+    /// Get the storage class as a [`StorageClass`].
+    pub fn storage_class_kind(&self) -> StorageClass {
+        StorageClass::from(self.storage_class.as_str())
+    }
+
     /// Get the part number marker.
     pub fn part_number_marker(&self) -> i32 {
         self.part_number_marker
@@ -277,7 +284,7 @@ pub struct PartInfo {
     etag: String,
     /// Size in bytes.
     #[serde(rename = "Size")]
-    size: i64,
+    size: u64,
 }
 
 impl PartInfo {
@@ -291,13 +298,18 @@ impl PartInfo {
         &self.last_modified
     }
 
+    /// Get the last modified time as a UTC timestamp (`None` if it cannot be parsed).
+    pub fn last_modified_time(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&self.last_modified)
+    }
+
     /// Get the ETag.
     pub fn etag(&self) -> &str {
         &self.etag
     }
 
     /// Get the size in bytes.
-    pub fn size(&self) -> i64 {
+    pub fn size(&self) -> u64 {
         self.size
     }
 }
@@ -6,6 +6,7 @@ use reqwest::Method;
 
 use crate::client::Client;
 use crate::error::{ObsError, Result};
+use crate::progress::{ProgressListener, ProgressTracker};
 
 /// Fluent builder for the UploadPart operation.
 ///
@@ -84,6 +85,18 @@ impl UploadPartFluentBuilder {
         self
     }
 
+    /// Set a listener that is notified as the part is uploaded.
+    pub fn progress_listener(mut self, listener: impl ProgressListener + 'static) -> Self {
+        self.inner.progress = Some(ProgressTracker::new(listener));
+        self
+    }
+
+    /// Report upload progress to an existing tracker.
+    pub(crate) fn progress_tracker(mut self, tracker: ProgressTracker) -> Self {
+        self.inner.progress = Some(tracker);
+        self
+    }
+
     /// Send the request.
     pub async fn send(self) -> Result<UploadPartOutput> {
         let bucket = &self.inner.bucket;
@@ -145,6 +158,22 @@ impl UploadPartFluentBuilder {
             .body
             .ok_or_else(|| ObsError::InvalidInput("body is required".to_string()))?;
 
+        // Send tracked bodies as counted streams
+        let body = match (body, self.inner.progress) {
+            (UploadPartBody::Bytes(bytes), Some(tracker)) => {
+                tracker.set_total(bytes.len() as u64);
+                headers.insert(
+                    reqwest::header::CONTENT_LENGTH,
+                    reqwest::header::HeaderValue::from(bytes.len()),
+                );
+                UploadPartBody::Stream(tracker.bytes_body(bytes))
+            }
+            (UploadPartBody::Stream(stream), Some(tracker)) => {
+                UploadPartBody::Stream(tracker.stream_body(stream))
+            }
+            (body, None) => body,
+        };
+
         match body {
             UploadPartBody::Bytes(bytes) => {
                 let resp = self
@@ -252,6 +281,7 @@ pub struct UploadPartInput {
     ssec_customer_key: Option<String>,
     ssec_customer_key_md5: Option<String>,
     checksum_crc64ecma: Option<String>,
+    progress: Option<ProgressTracker>,
 }
 
 /// Output for the UploadPart operation.
@@ -9,6 +9,7 @@ use reqwest::{
 
 use crate::client::Client;
 use crate::error::{ObsError, Result};
+use crate::progress::{ProgressListener, ProgressTracker};
 
 /// Fluent builder for the AppendObject operation.
 #[derive(Debug, Clone)]
@@ -56,7 +57,16 @@ impl AppendObjectFluentBuilder {
         self
     }
 
+    /// Set a listener that is notified as the body is uploaded.
+    pub fn progress_listener(mut self, listener: impl ProgressListener + 'static) -> Self {
+        self.inner.progress = Some(ProgressTracker::new(listener));
+        self
+    }
+
     /// Send the request.
+    ///
+    /// Returns [`ObsError::AppendPositionConflict`] if the position does not
+    /// match the current length of the object.
     pub async fn send(&self) -> Result<AppendObjectOutput> {
         let bucket = &self.inner.bucket;
         let key = &self.inner.key;
@@ -86,31 +96,56 @@ impl AppendObjectFluentBuilder {
             headers.insert("Content-Type", HeaderValue::from_str(content_type).unwrap());
         }
 
-        let resp = self
-            .client
-            .do_request(
-                Method::POST,
-                Some(bucket),
-                Some(key),
-                Some(headers),
-                Some(params),
-                Some(body),
-            )
-            .await?;
+        let resp = match self.inner.progress {
+            Some(ref tracker) => {
+                tracker.set_total(body.len() as u64);
+                self.client
+                    .do_request_streaming(
+                        Method::POST,
+                        Some(bucket),
+                        Some(key),
+                        Some(headers),
+                        Some(params),
+                        Some(tracker.bytes_body(body)),
+                    )
+                    .await?
+            }
+            None => {
+                self.client
+                    .do_request(
+                        Method::POST,
+                        Some(bucket),
+                        Some(key),
+                        Some(headers),
+                        Some(params),
+                        Some(body),
+                    )
+                    .await?
+            }
+        };
 
         let status = resp.status();
         let response_headers = resp.headers().clone();
 
-        if !status.is_success() {
-            let text = resp.text().await?;
-            return Err(ObsError::service_error(status, &text));
-        }
-
         let next_position = response_headers
             .get("x-obs-next-append-position")
             .and_then(|v| v.to_str().ok())
             .and_then(|s| s.parse::<u64>().ok());
 
+        if !status.is_success() {
+            let text = resp.text().await?;
+            return Err(match ObsError::service_error(status, &text) {
+                ObsError::ServiceError {
+                    code: Some(ref code),
+                    ..
+                } if code == "PositionNotEqualToLength" => ObsError::AppendPositionConflict {
+                    position: self.inner.position,
+                    next_position,
+                },
+                err => err,
+            });
+        }
+
         let etag = response_headers
             .get("ETag")
             .and_then(|v| v.to_str().ok())
@@ -131,6 +166,7 @@ pub struct AppendObjectInput {
     position: u64,
     body: Option<Vec<u8>>,
     content_type: Option<String>,
+    progress: Option<ProgressTracker>,
 }
 
 /// Output for the AppendObject operation.
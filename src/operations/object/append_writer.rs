@@ -0,0 +1,277 @@
+//! AppendWriter - stream data into an appendable object in OBS.
+
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+use reqwest::StatusCode;
+use tokio::io::AsyncWrite;
+
+use super::AppendObjectOutput;
+use crate::client::Client;
+use crate::error::{ObsError, Result};
+
+/// Default number of bytes buffered before an append request is sent (1 MiB).
+const DEFAULT_FLUSH_SIZE: usize = 1024 * 1024;
+
+/// Builder for an [`AppendWriter`].
+#[derive(Debug, Clone)]
+pub struct AppendWriterBuilder {
+    client: Client,
+    bucket: String,
+    key: String,
+    content_type: Option<String>,
+    flush_size: usize,
+    position: Option<u64>,
+}
+
+impl AppendWriterBuilder {
+    /// Create a new builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            bucket: String::new(),
+            key: String::new(),
+            content_type: None,
+            flush_size: DEFAULT_FLUSH_SIZE,
+            position: None,
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.bucket = bucket.into();
+        self
+    }
+
+    /// Set the object key.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key = key.into();
+        self
+    }
+
+    /// Set the content type (only applied when the object is created).
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+
+    /// Set how many bytes are buffered before an append request is sent (default: 1 MiB).
+    pub fn flush_size(mut self, flush_size: usize) -> Self {
+        self.flush_size = flush_size;
+        self
+    }
+
+    /// Set the position to start appending at, skipping the HeadObject lookup.
+    pub fn position(mut self, position: u64) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// Create the writer.
+    ///
+    /// Unless a position is set, the writer resumes from the next append
+    /// position of an existing object, or starts a new object at 0.
+    pub async fn build(self) -> Result<AppendWriter> {
+        if self.bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+        if self.key.is_empty() {
+            return Err(ObsError::InvalidInput("object key is required".to_string()));
+        }
+        if self.flush_size == 0 {
+            return Err(ObsError::InvalidInput(
+                "flush size must be greater than zero".to_string(),
+            ));
+        }
+
+        let position = match self.position {
+            Some(position) => position,
+            None => {
+                let head = self
+                    .client
+                    .head_object()
+                    .bucket(&self.bucket)
+                    .key(&self.key)
+                    .send()
+                    .await;
+                match head {
+                    Ok(head) => head.next_append_position().ok_or_else(|| {
+                        ObsError::InvalidInput(format!(
+                            "object {} exists and is not appendable",
+                            self.key
+                        ))
+                    })?,
+                    Err(e) if e.status_code() == Some(StatusCode::NOT_FOUND) => 0,
+                    Err(e) => return Err(e),
+                }
+            }
+        };
+
+        Ok(AppendWriter {
+            client: self.client,
+            bucket: self.bucket,
+            key: self.key,
+            content_type: self.content_type,
+            flush_size: self.flush_size,
+            position,
+            etag: None,
+            buffer: Vec::with_capacity(self.flush_size),
+            in_flight: None,
+        })
+    }
+}
+
+type AppendFuture = Pin<Box<dyn Future<Output = Result<AppendObjectOutput>> + Send>>;
+
+/// An [`AsyncWrite`] that appends to an object in OBS.
+///
+/// Written data is buffered and sent with AppendObject once the flush size
+/// is reached, on `flush()` and on `shutdown()`. The append position is
+/// tracked automatically. If an append fails, the buffered data is kept so
+/// the write can be retried.
+///
+/// I/O errors wrap an [`ObsError`]; a concurrent writer to the same object
+/// shows up as [`ObsError::AppendPositionConflict`]:
+///
+/// ```rust,no_run
+/// use tokio::io::AsyncWriteExt;
+///
+/// let mut writer = client.append_writer()
+///     .bucket("my-bucket")
+///     .key("logs/app.log")
+///     .build()
+///     .await?;
+///
+/// writer.write_all(b"started\n").await?;
+/// if let Err(e) = writer.shutdown().await {
+///     if let Some(ObsError::AppendPositionConflict { next_position, .. }) =
+///         e.get_ref().and_then(|e| e.downcast_ref::<ObsError>())
+///     {
+///         println!("object was appended elsewhere, now at {:?}", next_position);
+///     }
+/// }
+/// ```
+pub struct AppendWriter {
+    client: Client,
+    bucket: String,
+    key: String,
+    content_type: Option<String>,
+    flush_size: usize,
+    position: u64,
+    etag: Option<String>,
+    buffer: Vec<u8>,
+    in_flight: Option<AppendFuture>,
+}
+
+impl AppendWriter {
+    /// Get the bucket name.
+    pub fn bucket(&self) -> &str {
+        &self.bucket
+    }
+
+    /// Get the object key.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Get the object length that has been committed to OBS.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Get the number of bytes buffered but not yet appended.
+    pub fn buffered(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Get the ETag returned by the last append.
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    /// Start appending the buffered data.
+    fn start_append(&mut self) {
+        let mut request = self
+            .client
+            .append_object()
+            .bucket(&self.bucket)
+            .key(&self.key)
+            .position(self.position)
+            .body(self.buffer.clone());
+        if self.position == 0 {
+            if let Some(ref content_type) = self.content_type {
+                request = request.content_type(content_type);
+            }
+        }
+        self.in_flight = Some(Box::pin(async move { request.send().await }));
+    }
+
+    /// Drive the in-flight append, if any, to completion.
+    fn poll_in_flight(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        if let Some(in_flight) = self.in_flight.as_mut() {
+            let result = ready!(in_flight.as_mut().poll(cx));
+            self.in_flight = None;
+
+            let output = result.map_err(io::Error::other)?;
+            let appended = self.buffer.len() as u64;
+            self.position = output.next_position().unwrap_or(self.position + appended);
+            self.etag = output.etag().map(|s| s.to_string());
+            self.buffer.clear();
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    /// Append everything that is buffered.
+    fn poll_append_buffer(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(self.poll_in_flight(cx))?;
+        if !self.buffer.is_empty() {
+            self.start_append();
+            ready!(self.poll_in_flight(cx))?;
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncWrite for AppendWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+
+        ready!(this.poll_in_flight(cx))?;
+        if this.buffer.len() >= this.flush_size {
+            ready!(this.poll_append_buffer(cx))?;
+        }
+
+        let n = buf.len().min(this.flush_size - this.buffer.len());
+        this.buffer.extend_from_slice(&buf[..n]);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.get_mut().poll_append_buffer(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.get_mut().poll_append_buffer(cx)
+    }
+}
+
+impl std::fmt::Debug for AppendWriter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AppendWriter")
+            .field("bucket", &self.bucket)
+            .field("key", &self.key)
+            .field("flush_size", &self.flush_size)
+            .field("position", &self.position)
+            .field("buffered", &self.buffer.len())
+            .field("in_flight", &self.in_flight.is_some())
+            .finish()
+    }
+}
@@ -1,13 +1,18 @@
 //! CopyObject operation - copy an object within OBS.
 
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
 use reqwest::{
-    header::{HeaderMap, HeaderValue},
+    header::{HeaderMap, HeaderName, HeaderValue},
     Method,
 };
 use serde::{Deserialize, Serialize};
 
+use super::{CannedAcl, GrantHeaders, TagSet};
 use crate::client::Client;
 use crate::error::{ObsError, Result};
+use crate::operations::parse_timestamp;
 
 /// Fluent builder for the CopyObject operation.
 #[derive(Debug, Clone)]
@@ -38,6 +43,8 @@ impl CopyObjectFluentBuilder {
     }
 
     /// Set the copy source (format: "source-bucket/source-key").
+    ///
+    /// The object key is URL-encoded when the request is sent.
     pub fn copy_source(mut self, copy_source: impl Into<String>) -> Self {
         self.inner.copy_source = copy_source.into();
         self
@@ -55,6 +62,168 @@ impl CopyObjectFluentBuilder {
         self
     }
 
+    /// Set the version ID of the source object to copy.
+    pub fn copy_source_version_id(mut self, version_id: impl Into<String>) -> Self {
+        self.inner.copy_source_version_id = Some(version_id.into());
+        self
+    }
+
+    /// Set the metadata directive.
+    ///
+    /// Possible values: COPY (default, keep the source metadata),
+    /// REPLACE (use the metadata supplied with this request).
+    pub fn metadata_directive(mut self, directive: impl Into<String>) -> Self {
+        self.inner.metadata_directive = Some(directive.into());
+        self
+    }
+
+    /// Add a custom metadata header.
+    ///
+    /// The key should not include the "x-obs-meta-" prefix.
+    /// Only takes effect when the metadata directive is REPLACE.
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.inner.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set the content encoding.
+    pub fn content_encoding(mut self, content_encoding: impl Into<String>) -> Self {
+        self.inner.content_encoding = Some(content_encoding.into());
+        self
+    }
+
+    /// Set the content disposition.
+    pub fn content_disposition(mut self, content_disposition: impl Into<String>) -> Self {
+        self.inner.content_disposition = Some(content_disposition.into());
+        self
+    }
+
+    /// Set the content language.
+    pub fn content_language(mut self, content_language: impl Into<String>) -> Self {
+        self.inner.content_language = Some(content_language.into());
+        self
+    }
+
+    /// Set the cache control.
+    pub fn cache_control(mut self, cache_control: impl Into<String>) -> Self {
+        self.inner.cache_control = Some(cache_control.into());
+        self
+    }
+
+    /// Set the ACL (Access Control List) of the destination object.
+    ///
+    /// Possible values: private, public-read, public-read-write.
+    #[deprecated(note = "use `canned_acl` with a `CannedAcl` instead")]
+    pub fn acl(mut self, acl: impl Into<String>) -> Self {
+        self.inner.acl = Some(acl.into());
+        self
+    }
+
+    /// Set a canned ACL (predefined ACL) for the destination object.
+    pub fn canned_acl(mut self, acl: CannedAcl) -> Self {
+        self.inner.acl = Some(acl.to_string());
+        self
+    }
+
+    /// Set the grants of the destination object, sent as `x-obs-grant-*` headers.
+    pub fn grants(mut self, grants: GrantHeaders) -> Self {
+        self.inner.grants = grants;
+        self
+    }
+
+    /// Set the tagging directive.
+    ///
+    /// Possible values: COPY (default, keep the source tags),
+    /// REPLACE (use the tags supplied with this request).
+    pub fn tagging_directive(mut self, directive: impl Into<String>) -> Self {
+        self.inner.tagging_directive = Some(directive.into());
+        self
+    }
+
+    /// Set the object tagging of the destination object.
+    ///
+    /// Format: TagA=A&TagB&TagC
+    pub fn tagging(mut self, tagging: impl Into<String>) -> Self {
+        self.inner.tagging = Some(tagging.into());
+        self
+    }
+
+    /// Set the tags of the destination object from a tag set.
+    ///
+    /// Only takes effect when the tagging directive is REPLACE.
+    pub fn tag_set(mut self, tag_set: TagSet) -> Self {
+        self.inner.tagging = Some(tag_set.to_header_value());
+        self
+    }
+
+    /// Set the copy source if-match condition.
+    ///
+    /// Only copy if the source object's ETag matches this value.
+    pub fn copy_source_if_match(mut self, etag: impl Into<String>) -> Self {
+        self.inner.copy_source_if_match = Some(etag.into());
+        self
+    }
+
+    /// Set the copy source if-none-match condition.
+    ///
+    /// Only copy if the source object's ETag does not match this value.
+    pub fn copy_source_if_none_match(mut self, etag: impl Into<String>) -> Self {
+        self.inner.copy_source_if_none_match = Some(etag.into());
+        self
+    }
+
+    /// Set the copy source if-unmodified-since condition.
+    ///
+    /// Only copy if the source object has not been modified since this time.
+    pub fn copy_source_if_unmodified_since(mut self, date: impl Into<String>) -> Self {
+        self.inner.copy_source_if_unmodified_since = Some(date.into());
+        self
+    }
+
+    /// Set the copy source if-modified-since condition.
+    ///
+    /// Only copy if the source object has been modified since this time.
+    pub fn copy_source_if_modified_since(mut self, date: impl Into<String>) -> Self {
+        self.inner.copy_source_if_modified_since = Some(date.into());
+        self
+    }
+
+    /// Set the customer algorithm for SSE-C (destination).
+    pub fn ssec_customer_algorithm(mut self, algorithm: impl Into<String>) -> Self {
+        self.inner.ssec_customer_algorithm = Some(algorithm.into());
+        self
+    }
+
+    /// Set the customer key for SSE-C (destination).
+    pub fn ssec_customer_key(mut self, key: impl Into<String>) -> Self {
+        self.inner.ssec_customer_key = Some(key.into());
+        self
+    }
+
+    /// Set the customer key MD5 for SSE-C (destination).
+    pub fn ssec_customer_key_md5(mut self, md5: impl Into<String>) -> Self {
+        self.inner.ssec_customer_key_md5 = Some(md5.into());
+        self
+    }
+
+    /// Set the customer algorithm for SSE-C (source).
+    pub fn copy_source_ssec_customer_algorithm(mut self, algorithm: impl Into<String>) -> Self {
+        self.inner.copy_source_ssec_customer_algorithm = Some(algorithm.into());
+        self
+    }
+
+    /// Set the customer key for SSE-C (source).
+    pub fn copy_source_ssec_customer_key(mut self, key: impl Into<String>) -> Self {
+        self.inner.copy_source_ssec_customer_key = Some(key.into());
+        self
+    }
+
+    /// Set the customer key MD5 for SSE-C (source).
+    pub fn copy_source_ssec_customer_key_md5(mut self, md5: impl Into<String>) -> Self {
+        self.inner.copy_source_ssec_customer_key_md5 = Some(md5.into());
+        self
+    }
+
     /// Send the request.
     pub async fn send(&self) -> Result<CopyObjectOutput> {
         let bucket = &self.inner.bucket;
@@ -76,9 +245,13 @@ impl CopyObjectFluentBuilder {
 
         let mut headers = HeaderMap::new();
 
-        // x-obs-copy-source 格式: /bucket/key
-        // copy_source 格式为 "bucket/key"
-        let copy_source = format!("/{}", self.inner.copy_source);
+        // x-obs-copy-source 格式: /bucket/key[?versionId=xxx]
+        // copy_source 格式为 "bucket/key"，对象名需要 URL 编码
+        let mut copy_source = encode_copy_source(&self.inner.copy_source);
+        if let Some(ref version_id) = self.inner.copy_source_version_id {
+            copy_source.push_str("?versionId=");
+            copy_source.push_str(version_id);
+        }
 
         headers.insert(
             "x-obs-copy-source",
@@ -86,15 +259,83 @@ impl CopyObjectFluentBuilder {
                 .map_err(|e| ObsError::InvalidInput(format!("Invalid copy source: {}", e)))?,
         );
 
-        if let Some(ref content_type) = self.inner.content_type {
-            headers.insert("Content-Type", HeaderValue::from_str(content_type).unwrap());
+        let optional_headers = [
+            ("Content-Type", &self.inner.content_type),
+            ("Content-Encoding", &self.inner.content_encoding),
+            ("Content-Disposition", &self.inner.content_disposition),
+            ("Content-Language", &self.inner.content_language),
+            ("Cache-Control", &self.inner.cache_control),
+            ("x-obs-storage-class", &self.inner.storage_class),
+            ("x-obs-metadata-directive", &self.inner.metadata_directive),
+            ("x-obs-tagging-directive", &self.inner.tagging_directive),
+            ("x-obs-tagging", &self.inner.tagging),
+            (
+                "x-obs-copy-source-if-match",
+                &self.inner.copy_source_if_match,
+            ),
+            (
+                "x-obs-copy-source-if-none-match",
+                &self.inner.copy_source_if_none_match,
+            ),
+            (
+                "x-obs-copy-source-if-unmodified-since",
+                &self.inner.copy_source_if_unmodified_since,
+            ),
+            (
+                "x-obs-copy-source-if-modified-since",
+                &self.inner.copy_source_if_modified_since,
+            ),
+            (
+                "x-obs-server-side-encryption-customer-algorithm",
+                &self.inner.ssec_customer_algorithm,
+            ),
+            (
+                "x-obs-server-side-encryption-customer-key",
+                &self.inner.ssec_customer_key,
+            ),
+            (
+                "x-obs-server-side-encryption-customer-key-MD5",
+                &self.inner.ssec_customer_key_md5,
+            ),
+            (
+                "x-obs-copy-source-server-side-encryption-customer-algorithm",
+                &self.inner.copy_source_ssec_customer_algorithm,
+            ),
+            (
+                "x-obs-copy-source-server-side-encryption-customer-key",
+                &self.inner.copy_source_ssec_customer_key,
+            ),
+            (
+                "x-obs-copy-source-server-side-encryption-customer-key-MD5",
+                &self.inner.copy_source_ssec_customer_key_md5,
+            ),
+        ];
+
+        for (name, value) in optional_headers {
+            if let Some(value) = value {
+                headers.insert(
+                    name,
+                    HeaderValue::from_str(value).map_err(|e| {
+                        ObsError::InvalidInput(format!("Invalid {} header: {}", name, e))
+                    })?,
+                );
+            }
         }
 
-        if let Some(ref storage_class) = self.inner.storage_class {
-            headers.insert(
-                "x-obs-storage-class",
-                HeaderValue::from_str(storage_class).unwrap(),
-            );
+        if let Some(ref acl) = self.inner.acl {
+            let acl: CannedAcl = acl.parse()?;
+            headers.insert("x-obs-acl", HeaderValue::from_static(acl.as_str()));
+        }
+        self.inner.grants.apply(&mut headers)?;
+
+        // Custom metadata
+        for (k, v) in &self.inner.metadata {
+            let name: HeaderName = format!("x-obs-meta-{}", k)
+                .parse()
+                .map_err(|e| ObsError::InvalidInput(format!("Invalid metadata key: {}", e)))?;
+            let value = HeaderValue::from_str(v)
+                .map_err(|e| ObsError::InvalidInput(format!("Invalid metadata value: {}", e)))?;
+            headers.insert(name, value);
         }
 
         let resp = self
@@ -110,6 +351,7 @@ impl CopyObjectFluentBuilder {
             .await?;
 
         let status = resp.status();
+        let response_headers = resp.headers().clone();
         let text = resp.text().await?;
 
         if !status.is_success() {
@@ -118,13 +360,38 @@ impl CopyObjectFluentBuilder {
 
         let result: CopyObjectResult = crate::xml_utils::from_xml(&text)?;
 
+        let version_id = response_headers
+            .get("x-obs-version-id")
+            .and_then(|v| v.to_str().ok())
+            .map(|s| s.to_string());
+
+        let copy_source_version_id = response_headers
+            .get("x-obs-copy-source-version-id")
+            .and_then(|v| v.to_str().ok())
+            .map(|s| s.to_string());
+
         Ok(CopyObjectOutput {
             etag: result.etag,
             last_modified: result.last_modified,
+            version_id,
+            copy_source_version_id,
         })
     }
 }
 
+/// URL-encode the object key part of a copy source ("bucket/key"),
+/// keeping the path separators intact, and prepend the leading slash.
+pub(crate) fn encode_copy_source(copy_source: &str) -> String {
+    let copy_source = copy_source.trim_start_matches('/');
+    let (bucket, key) = copy_source.split_once('/').unwrap_or((copy_source, ""));
+    let key = key
+        .split('/')
+        .map(|segment| urlencoding::encode(segment).into_owned())
+        .collect::<Vec<_>>()
+        .join("/");
+    format!("/{}/{}", bucket, key)
+}
+
 /// Input for the CopyObject operation.
 #[derive(Debug, Clone, Default)]
 pub struct CopyObjectInput {
@@ -133,6 +400,27 @@ pub struct CopyObjectInput {
     copy_source: String,
     content_type: Option<String>,
     storage_class: Option<String>,
+    copy_source_version_id: Option<String>,
+    metadata_directive: Option<String>,
+    metadata: HashMap<String, String>,
+    content_encoding: Option<String>,
+    content_disposition: Option<String>,
+    content_language: Option<String>,
+    cache_control: Option<String>,
+    acl: Option<String>,
+    grants: GrantHeaders,
+    tagging_directive: Option<String>,
+    tagging: Option<String>,
+    copy_source_if_match: Option<String>,
+    copy_source_if_none_match: Option<String>,
+    copy_source_if_unmodified_since: Option<String>,
+    copy_source_if_modified_since: Option<String>,
+    ssec_customer_algorithm: Option<String>,
+    ssec_customer_key: Option<String>,
+    ssec_customer_key_md5: Option<String>,
+    copy_source_ssec_customer_algorithm: Option<String>,
+    copy_source_ssec_customer_key: Option<String>,
+    copy_source_ssec_customer_key_md5: Option<String>,
 }
 
 /// Output for the CopyObject operation.
@@ -140,6 +428,8 @@ pub struct CopyObjectInput {
 pub struct CopyObjectOutput {
     etag: String,
     last_modified: String,
+    version_id: Option<String>,
+    copy_source_version_id: Option<String>,
 }
 
 impl CopyObjectOutput {
@@ -152,6 +442,21 @@ impl CopyObjectOutput {
     pub fn last_modified(&self) -> &str {
         &self.last_modified
     }
+
+    /// Get the last modified time as a UTC timestamp (`None` if it cannot be parsed).
+    pub fn last_modified_time(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&self.last_modified)
+    }
+
+    /// Get the version ID of the destination object.
+    pub fn version_id(&self) -> Option<&str> {
+        self.version_id.as_deref()
+    }
+
+    /// Get the version ID of the source object that was copied.
+    pub fn copy_source_version_id(&self) -> Option<&str> {
+        self.copy_source_version_id.as_deref()
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -161,3 +466,18 @@ struct CopyObjectResult {
     #[serde(rename = "LastModified")]
     last_modified: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_copy_source() {
+        assert_eq!(encode_copy_source("bucket/key.txt"), "/bucket/key.txt");
+        assert_eq!(encode_copy_source("/bucket/a/b.txt"), "/bucket/a/b.txt");
+        assert_eq!(
+            encode_copy_source("bucket/dir/测试 file+1.txt"),
+            "/bucket/dir/%E6%B5%8B%E8%AF%95%20file%2B1.txt"
+        );
+    }
+}
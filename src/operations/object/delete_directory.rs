@@ -0,0 +1,137 @@
+//! DeleteDirectory helper - recursively delete a directory in a parallel file system bucket.
+
+use std::collections::BTreeMap;
+
+use super::rename_object::directory_key;
+use super::DeleteError;
+use crate::client::Client;
+use crate::error::{ObsError, Result};
+
+/// Fluent builder for recursively deleting a directory.
+///
+/// Parallel file system buckets refuse to delete non-empty directories, so
+/// objects are deleted level by level, deepest first, ending with the
+/// directory itself. On object storage buckets this deletes every object
+/// under the prefix.
+#[derive(Debug, Clone)]
+pub struct DeleteDirectoryFluentBuilder {
+    client: Client,
+    bucket: String,
+    directory: String,
+}
+
+impl DeleteDirectoryFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            bucket: String::new(),
+            directory: String::new(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.bucket = bucket.into();
+        self
+    }
+
+    /// Set the directory to delete (e.g. "logs/2024").
+    pub fn directory(mut self, directory: impl Into<String>) -> Self {
+        self.directory = directory.into();
+        self
+    }
+
+    /// Send the requests.
+    pub async fn send(&self) -> Result<DeleteDirectoryOutput> {
+        if self.bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+        if self.directory.trim_end_matches('/').is_empty() {
+            return Err(ObsError::InvalidInput("directory is required".to_string()));
+        }
+
+        let prefix = directory_key(&self.directory);
+
+        // Group keys by depth so children are always deleted before parents.
+        let mut levels: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+        let mut marker: Option<String> = None;
+        loop {
+            let mut request = self
+                .client
+                .list_objects()
+                .bucket(&self.bucket)
+                .prefix(&prefix)
+                .delimiter("")
+                .max_keys(1000);
+            if let Some(ref marker) = marker {
+                request = request.marker(marker);
+            }
+            let page = request.send().await?;
+
+            for object in page.contents() {
+                let depth = object.key().trim_end_matches('/').matches('/').count();
+                levels
+                    .entry(depth)
+                    .or_default()
+                    .push(object.key().to_string());
+            }
+
+            if !page.is_truncated() {
+                break;
+            }
+            marker = page
+                .next_marker()
+                .map(|m| m.to_string())
+                .or_else(|| page.contents().last().map(|o| o.key().to_string()));
+            if marker.is_none() {
+                break;
+            }
+        }
+
+        let mut deleted = 0;
+        let mut errors = Vec::new();
+
+        for keys in levels.into_values().rev() {
+            let count = keys.len();
+            let output = self
+                .client
+                .delete_objects()
+                .bucket(&self.bucket)
+                .keys(keys)
+                .quiet(true)
+                .send()
+                .await?;
+            deleted += count - output.errors().len();
+            errors.extend_from_slice(output.errors());
+        }
+
+        Ok(DeleteDirectoryOutput { deleted, errors })
+    }
+}
+
+/// Output for the DeleteDirectory helper.
+#[derive(Debug, Clone)]
+pub struct DeleteDirectoryOutput {
+    deleted: usize,
+    errors: Vec<DeleteError>,
+}
+
+impl DeleteDirectoryOutput {
+    /// Get the number of deleted objects and directories.
+    pub fn deleted_count(&self) -> usize {
+        self.deleted
+    }
+
+    /// Get the deletion errors.
+    pub fn errors(&self) -> &[DeleteError] {
+        &self.errors
+    }
+
+    /// Check if everything was deleted successfully.
+    pub fn is_all_success(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
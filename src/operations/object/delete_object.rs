@@ -78,7 +78,20 @@ impl DeleteObjectFluentBuilder {
             return Err(ObsError::service_error(status, &text));
         }
 
-        Ok(DeleteObjectOutput {})
+        let headers = resp.headers();
+        let version_id = headers
+            .get("x-obs-version-id")
+            .and_then(|v| v.to_str().ok())
+            .map(|s| s.to_string());
+        let delete_marker = headers
+            .get("x-obs-delete-marker")
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|s| s.eq_ignore_ascii_case("true"));
+
+        Ok(DeleteObjectOutput {
+            version_id,
+            delete_marker,
+        })
     }
 }
 
@@ -92,4 +105,22 @@ pub struct DeleteObjectInput {
 
 /// Output for the DeleteObject operation.
 #[derive(Debug, Clone)]
-pub struct DeleteObjectOutput {}
+pub struct DeleteObjectOutput {
+    version_id: Option<String>,
+    delete_marker: bool,
+}
+
+impl DeleteObjectOutput {
+    /// Get the version ID.
+    ///
+    /// In a versioned bucket this is the version of the delete marker that
+    /// was created, or the version that was deleted.
+    pub fn version_id(&self) -> Option<&str> {
+        self.version_id.as_deref()
+    }
+
+    /// Check if a delete marker was created or deleted.
+    pub fn delete_marker(&self) -> bool {
+        self.delete_marker
+    }
+}
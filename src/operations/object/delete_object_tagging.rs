@@ -0,0 +1,112 @@
+//! DeleteObjectTagging operation - remove all tags from an object in OBS.
+
+use std::collections::HashMap;
+
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::{ObsError, Result};
+
+/// Fluent builder for the DeleteObjectTagging operation.
+#[derive(Debug, Clone)]
+pub struct DeleteObjectTaggingFluentBuilder {
+    client: Client,
+    inner: DeleteObjectTaggingInput,
+}
+
+impl DeleteObjectTaggingFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: DeleteObjectTaggingInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Set the object key.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.inner.key = key.into();
+        self
+    }
+
+    /// Set the version ID.
+    pub fn version_id(mut self, version_id: impl Into<String>) -> Self {
+        self.inner.version_id = Some(version_id.into());
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<DeleteObjectTaggingOutput> {
+        let bucket = &self.inner.bucket;
+        let key = &self.inner.key;
+
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+        if key.is_empty() {
+            return Err(ObsError::InvalidInput("object key is required".to_string()));
+        }
+
+        let mut params = HashMap::new();
+        params.insert("tagging".to_string(), String::new());
+
+        if let Some(ref version_id) = self.inner.version_id {
+            params.insert("versionId".to_string(), version_id.clone());
+        }
+
+        let resp = self
+            .client
+            .do_request(
+                Method::DELETE,
+                Some(bucket),
+                Some(key),
+                None,
+                Some(params),
+                None,
+            )
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await?;
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        let version_id = resp
+            .headers()
+            .get("x-obs-version-id")
+            .and_then(|v| v.to_str().ok())
+            .map(|s| s.to_string());
+
+        Ok(DeleteObjectTaggingOutput { version_id })
+    }
+}
+
+/// Input for the DeleteObjectTagging operation.
+#[derive(Debug, Clone, Default)]
+pub struct DeleteObjectTaggingInput {
+    bucket: String,
+    key: String,
+    version_id: Option<String>,
+}
+
+/// Output for the DeleteObjectTagging operation.
+#[derive(Debug, Clone)]
+pub struct DeleteObjectTaggingOutput {
+    version_id: Option<String>,
+}
+
+impl DeleteObjectTaggingOutput {
+    /// Get the version ID.
+    pub fn version_id(&self) -> Option<&str> {
+        self.version_id.as_deref()
+    }
+}
@@ -2,8 +2,7 @@
 
 use std::collections::HashMap;
 
-use base64::{engine::general_purpose, Engine};
-use md5::{Digest, Md5};
+use futures::stream::{self, StreamExt};
 use reqwest::{
     header::{HeaderMap, HeaderValue},
     Method,
@@ -13,7 +12,16 @@ use serde::{Deserialize, Serialize};
 use crate::client::Client;
 use crate::error::{ObsError, Result};
 
+/// Maximum number of objects in a single DeleteObjects request.
+pub(crate) const DELETE_BATCH_SIZE: usize = 1000;
+
+/// Default number of batches deleted at the same time.
+const DEFAULT_CONCURRENCY: usize = 4;
+
 /// Fluent builder for the DeleteObjects operation.
+///
+/// More than 1000 objects are split into batches of 1000 that are sent
+/// concurrently; their results are merged into one output.
 #[derive(Debug, Clone)]
 pub struct DeleteObjectsFluentBuilder {
     client: Client,
@@ -25,7 +33,10 @@ impl DeleteObjectsFluentBuilder {
     pub(crate) fn new(client: Client) -> Self {
         Self {
             client,
-            inner: DeleteObjectsInput::default(),
+            inner: DeleteObjectsInput {
+                concurrency: DEFAULT_CONCURRENCY,
+                ..Default::default()
+            },
         }
     }
 
@@ -82,11 +93,19 @@ impl DeleteObjectsFluentBuilder {
         self
     }
 
-    /// Send the request.
-    pub async fn send(&self) -> Result<DeleteObjectsOutput> {
-        let bucket = &self.inner.bucket;
+    /// Set how many batches are deleted at the same time (default: 4).
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.inner.concurrency = concurrency;
+        self
+    }
 
-        if bucket.is_empty() {
+    /// Send the requests.
+    ///
+    /// A batch whose request fails is reported as a [`DeleteError`] for each
+    /// of its objects. If no batch succeeds, the error of the first batch is
+    /// returned instead.
+    pub async fn send(&self) -> Result<DeleteObjectsOutput> {
+        if self.inner.bucket.is_empty() {
             return Err(ObsError::InvalidInput(
                 "bucket name is required".to_string(),
             ));
@@ -96,30 +115,68 @@ impl DeleteObjectsFluentBuilder {
                 "at least one object key is required".to_string(),
             ));
         }
-        if self.inner.objects.len() > 1000 {
+        if self.inner.concurrency == 0 {
             return Err(ObsError::InvalidInput(
-                "maximum 1000 objects can be deleted in one request".to_string(),
+                "concurrency must be greater than zero".to_string(),
             ));
         }
 
+        let results: Vec<_> = stream::iter(self.inner.objects.chunks(DELETE_BATCH_SIZE))
+            .map(|batch| async move { (batch, self.send_batch(batch).await) })
+            .buffered(self.inner.concurrency)
+            .collect()
+            .await;
+
+        let mut output = DeleteObjectsOutput {
+            deleted: Vec::new(),
+            errors: Vec::new(),
+            encoding_type: None,
+        };
+        let mut first_error = None;
+        let mut any_success = false;
+        for (batch, result) in results {
+            match result {
+                Ok(result) => {
+                    any_success = true;
+                    output.deleted.extend(result.deleted);
+                    output.errors.extend(result.errors);
+                    output.encoding_type = output.encoding_type.or(result.encoding_type);
+                }
+                Err(e) => {
+                    output
+                        .errors
+                        .extend(batch.iter().map(|object| DeleteError::failed(object, &e)));
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        match first_error {
+            Some(e) if !any_success => Err(e),
+            _ => Ok(output),
+        }
+    }
+
+    /// Delete up to 1000 objects with a single request.
+    async fn send_batch(&self, objects: &[DeleteObjectRequest]) -> Result<DeleteResult> {
+        let bucket = &self.inner.bucket;
+
         let mut params = HashMap::new();
         params.insert("delete".to_string(), String::new());
 
         let delete = DeleteRequest {
             quiet: self.inner.quiet,
             encoding_type: self.inner.encoding_type.clone(),
-            object: self.inner.objects.clone(),
+            object: objects.to_vec(),
         };
 
         let body = crate::xml_utils::to_xml(&delete)?;
 
-        let mut hasher = Md5::new();
-        hasher.update(body.as_bytes());
-        let result = hasher.finalize();
-        let md5_value = general_purpose::STANDARD.encode(result);
-
         let mut headers = HeaderMap::new();
-        headers.insert("Content-MD5", HeaderValue::from_str(&md5_value).unwrap());
+        headers.insert(
+            "Content-MD5",
+            crate::xml_utils::content_md5(body.as_bytes()),
+        );
         headers.insert(
             "Content-Length",
             HeaderValue::from_str(&body.len().to_string()).unwrap(),
@@ -145,13 +202,7 @@ impl DeleteObjectsFluentBuilder {
 
         // Parse the response
         let text = resp.text().await?;
-        let result: DeleteResult = crate::xml_utils::from_xml(&text)?;
-
-        Ok(DeleteObjectsOutput {
-            deleted: result.deleted,
-            errors: result.errors,
-            encoding_type: result.encoding_type,
-        })
+        crate::xml_utils::from_xml(&text)
     }
 }
 
@@ -162,6 +213,7 @@ pub struct DeleteObjectsInput {
     objects: Vec<DeleteObjectRequest>,
     quiet: bool,
     encoding_type: Option<String>,
+    concurrency: usize,
 }
 
 /// Object to delete in the request.
@@ -248,6 +300,16 @@ pub struct DeletedObject {
 }
 
 impl DeletedObject {
+    /// Create a result for an object that was (or would be) deleted.
+    pub(crate) fn new(key: impl Into<String>, version_id: Option<String>) -> Self {
+        Self {
+            key: key.into(),
+            version_id,
+            delete_marker: None,
+            delete_marker_version_id: None,
+        }
+    }
+
     /// Get the object key.
     pub fn key(&self) -> &str {
         &self.key
@@ -283,6 +345,22 @@ pub struct DeleteError {
 }
 
 impl DeleteError {
+    /// Create an error for an object whose batch request failed.
+    pub(crate) fn failed(object: &DeleteObjectRequest, error: &ObsError) -> Self {
+        let code = match error {
+            ObsError::ServiceError {
+                code: Some(code), ..
+            } => code.clone(),
+            _ => "RequestFailed".to_string(),
+        };
+        Self {
+            key: object.key.clone(),
+            code,
+            message: error.to_string(),
+            version_id: object.version_id.clone(),
+        }
+    }
+
     /// Get the object key.
     pub fn key(&self) -> &str {
         &self.key
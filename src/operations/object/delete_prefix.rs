@@ -0,0 +1,272 @@
+//! DeletePrefix helper - delete every object under a prefix.
+
+use futures::future;
+use futures::stream::{self, TryStreamExt};
+
+use super::delete_objects::DELETE_BATCH_SIZE;
+use super::{DeleteError, DeleteObjectRequest, DeletedObject};
+use crate::client::Client;
+use crate::error::{ObsError, Result};
+
+/// Default number of batches deleted at the same time.
+const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Fluent builder for deleting every object under a prefix.
+///
+/// Keys are listed page by page and each page is deleted with one
+/// DeleteObjects request while the listing continues. With
+/// [`all_versions`](Self::all_versions) every version and delete marker is
+/// removed, which permanently deletes the objects in a versioned bucket.
+#[derive(Debug, Clone)]
+pub struct DeletePrefixFluentBuilder {
+    client: Client,
+    bucket: String,
+    prefix: String,
+    all_versions: bool,
+    dry_run: bool,
+    concurrency: usize,
+}
+
+impl DeletePrefixFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            bucket: String::new(),
+            prefix: String::new(),
+            all_versions: false,
+            dry_run: false,
+            concurrency: DEFAULT_CONCURRENCY,
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.bucket = bucket.into();
+        self
+    }
+
+    /// Set the prefix of the objects to delete (e.g. "tmp/2024-").
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Delete all versions and delete markers instead of the current objects.
+    pub fn all_versions(mut self, all_versions: bool) -> Self {
+        self.all_versions = all_versions;
+        self
+    }
+
+    /// Only list what would be deleted, without deleting anything.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Set how many batches are deleted at the same time (default: 4).
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Send the requests.
+    ///
+    /// Listing and request errors stop the deletion and are returned; objects
+    /// that could not be deleted are reported in the output.
+    pub async fn send(&self) -> Result<DeletePrefixOutput> {
+        if self.bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+        if self.prefix.is_empty() {
+            return Err(ObsError::InvalidInput("prefix is required".to_string()));
+        }
+        if self.concurrency == 0 {
+            return Err(ObsError::InvalidInput(
+                "concurrency must be greater than zero".to_string(),
+            ));
+        }
+
+        let mut output = DeletePrefixOutput {
+            deleted: Vec::new(),
+            errors: Vec::new(),
+            dry_run: self.dry_run,
+        };
+        let pages = stream::try_unfold(Some(ListMarker::default()), |marker| async move {
+            let Some(marker) = marker else {
+                return Ok(None);
+            };
+            let (objects, next) = if self.all_versions {
+                self.list_versions(&marker).await?
+            } else {
+                self.list_objects(&marker).await?
+            };
+            Ok(Some((objects, next)))
+        });
+
+        if self.dry_run {
+            let objects: Vec<_> = pages.try_concat().await?;
+            output.deleted.extend(
+                objects
+                    .into_iter()
+                    .map(|o| DeletedObject::new(o.key(), o.version_id().map(String::from))),
+            );
+            return Ok(output);
+        }
+
+        // The next page is listed while earlier pages are being deleted.
+        let mut batches = Box::pin(
+            pages
+                .try_filter(|objects| future::ready(!objects.is_empty()))
+                .map_ok(|objects| self.delete_batch(objects))
+                .try_buffer_unordered(self.concurrency),
+        );
+        while let Some(result) = batches.try_next().await? {
+            output.merge(result);
+        }
+
+        Ok(output)
+    }
+
+    /// Delete one page of objects.
+    async fn delete_batch(&self, objects: Vec<DeleteObjectRequest>) -> Result<DeleteResult> {
+        let mut request = self.client.delete_objects().bucket(&self.bucket);
+        for object in objects {
+            request = match object.version_id() {
+                Some(version_id) => request.key_with_version(object.key(), version_id),
+                None => request.key(object.key()),
+            };
+        }
+        let output = request.send().await?;
+        Ok(DeleteResult {
+            deleted: output.deleted().to_vec(),
+            errors: output.errors().to_vec(),
+        })
+    }
+
+    /// List one page of current objects.
+    async fn list_objects(
+        &self,
+        marker: &ListMarker,
+    ) -> Result<(Vec<DeleteObjectRequest>, Option<ListMarker>)> {
+        let mut request = self
+            .client
+            .list_objects()
+            .bucket(&self.bucket)
+            .prefix(&self.prefix)
+            .delimiter("")
+            .max_keys(DELETE_BATCH_SIZE as i32);
+        if let Some(ref key) = marker.key {
+            request = request.marker(key);
+        }
+        let page = request.send().await?;
+
+        let objects: Vec<_> = page
+            .contents()
+            .iter()
+            .map(|o| DeleteObjectRequest::new(o.key()))
+            .collect();
+
+        let next = if page.is_truncated() {
+            page.next_marker()
+                .map(|m| m.to_string())
+                .or_else(|| objects.last().map(|o| o.key().to_string()))
+                .map(|key| ListMarker {
+                    key: Some(key),
+                    version_id: None,
+                })
+        } else {
+            None
+        };
+        Ok((objects, next))
+    }
+
+    /// List one page of object versions and delete markers.
+    async fn list_versions(
+        &self,
+        marker: &ListMarker,
+    ) -> Result<(Vec<DeleteObjectRequest>, Option<ListMarker>)> {
+        let mut request = self
+            .client
+            .list_object_versions()
+            .bucket(&self.bucket)
+            .prefix(&self.prefix)
+            .max_keys(DELETE_BATCH_SIZE as i32);
+        if let Some(ref key) = marker.key {
+            request = request.key_marker(key);
+        }
+        if let Some(ref version_id) = marker.version_id {
+            request = request.version_id_marker(version_id);
+        }
+        let page = request.send().await?;
+
+        let versions = page
+            .versions()
+            .iter()
+            .map(|v| DeleteObjectRequest::with_version(v.key(), v.version_id()));
+        let markers = page
+            .delete_markers()
+            .iter()
+            .map(|m| DeleteObjectRequest::with_version(m.key(), m.version_id()));
+        let objects = versions.chain(markers).collect();
+
+        let next = match page.next_key_marker() {
+            Some(key) if page.is_truncated() => Some(ListMarker {
+                key: Some(key.to_string()),
+                version_id: page.next_version_id_marker().map(|s| s.to_string()),
+            }),
+            _ => None,
+        };
+        Ok((objects, next))
+    }
+}
+
+/// Position to continue listing from.
+#[derive(Debug, Default)]
+struct ListMarker {
+    key: Option<String>,
+    version_id: Option<String>,
+}
+
+/// Results of one DeleteObjects batch.
+struct DeleteResult {
+    deleted: Vec<DeletedObject>,
+    errors: Vec<DeleteError>,
+}
+
+/// Output for the DeletePrefix helper.
+#[derive(Debug, Clone)]
+pub struct DeletePrefixOutput {
+    deleted: Vec<DeletedObject>,
+    errors: Vec<DeleteError>,
+    dry_run: bool,
+}
+
+impl DeletePrefixOutput {
+    /// Get the deleted objects, or the objects that would be deleted in a dry run.
+    pub fn deleted(&self) -> &[DeletedObject] {
+        &self.deleted
+    }
+
+    /// Get the deletion errors.
+    pub fn errors(&self) -> &[DeleteError] {
+        &self.errors
+    }
+
+    /// Check if this was a dry run.
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Check if everything was deleted successfully.
+    pub fn is_all_success(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    fn merge(&mut self, result: DeleteResult) {
+        self.deleted.extend(result.deleted);
+        self.errors.extend(result.errors);
+    }
+}
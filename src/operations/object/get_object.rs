@@ -1,14 +1,19 @@
 //! GetObject operation - download an object from OBS.
 
 use std::collections::HashMap;
+use std::time::Duration;
 
+use chrono::{DateTime, Utc};
 use reqwest::{
     header::{HeaderMap, HeaderValue},
     Method,
 };
 
+use super::ImageProcess;
 use crate::client::Client;
 use crate::error::{ObsError, Result};
+use crate::operations::parse_timestamp;
+use crate::progress::{ProgressListener, ProgressTracker};
 
 /// Fluent builder for the GetObject operation.
 #[derive(Debug, Clone)]
@@ -50,28 +55,123 @@ impl GetObjectFluentBuilder {
         self
     }
 
-    /// Send the request.
-    pub async fn send(&self) -> Result<GetObjectOutput> {
-        let bucket = &self.inner.bucket;
-        let key = &self.inner.key;
+    /// Only return the object if its ETag matches (otherwise 412 Precondition Failed).
+    pub fn if_match(mut self, etag: impl Into<String>) -> Self {
+        self.inner.if_match = Some(etag.into());
+        self
+    }
+
+    /// Set a listener that is notified as the body is downloaded.
+    pub fn progress_listener(mut self, listener: impl ProgressListener + 'static) -> Self {
+        self.inner.progress = Some(ProgressTracker::new(listener));
+        self
+    }
+
+    /// Report download progress to an existing tracker.
+    pub(crate) fn progress_tracker(mut self, tracker: ProgressTracker) -> Self {
+        self.inner.progress = Some(tracker);
+        self
+    }
+
+    /// Set the image processing applied to the downloaded image.
+    pub fn image_process(mut self, image_process: ImageProcess) -> Self {
+        self.inner.image_process = Some(image_process);
+        self
+    }
 
-        if bucket.is_empty() {
+    /// Save the processed image to another bucket (default: the source bucket).
+    ///
+    /// Only used together with [`image_save_object`](Self::image_save_object).
+    pub fn image_save_bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.image_save_bucket = Some(bucket.into());
+        self
+    }
+
+    /// Save the processed image as a new object with this key.
+    pub fn image_save_object(mut self, key: impl Into<String>) -> Self {
+        self.inner.image_save_object = Some(key.into());
+        self
+    }
+
+    /// Build a presigned URL for downloading the object.
+    ///
+    /// The version ID and image processing parameters are part of the URL;
+    /// the range is not.
+    pub fn presigned_url(&self, expires_in: Duration) -> Result<String> {
+        let params = self.params()?;
+        self.client.presign_url(
+            Method::GET,
+            &self.inner.bucket,
+            &self.inner.key,
+            Some(params),
+            expires_in,
+        )
+    }
+
+    /// Validate the input and build the query parameters.
+    fn params(&self) -> Result<HashMap<String, String>> {
+        if self.inner.bucket.is_empty() {
             return Err(ObsError::InvalidInput(
                 "bucket name is required".to_string(),
             ));
         }
-        if key.is_empty() {
+        if self.inner.key.is_empty() {
             return Err(ObsError::InvalidInput("object key is required".to_string()));
         }
 
+        let mut params = HashMap::new();
+        if let Some(ref version_id) = self.inner.version_id {
+            params.insert("versionId".to_string(), version_id.clone());
+        }
+
+        match self.inner.image_process {
+            Some(ref image_process) => {
+                image_process.validate()?;
+                params.insert("x-image-process".to_string(), image_process.to_string());
+            }
+            None if self.inner.image_save_object.is_some() => {
+                return Err(ObsError::InvalidInput(
+                    "image process is required to save a processed image".to_string(),
+                ));
+            }
+            None => {}
+        }
+
+        match (&self.inner.image_save_object, &self.inner.image_save_bucket) {
+            (Some(object), bucket) => {
+                params.insert("x-image-save-object".to_string(), object.clone());
+                if let Some(bucket) = bucket {
+                    params.insert("x-image-save-bucket".to_string(), bucket.clone());
+                }
+            }
+            (None, Some(_)) => {
+                return Err(ObsError::InvalidInput(
+                    "image save object is required when a save bucket is set".to_string(),
+                ));
+            }
+            (None, None) => {}
+        }
+
+        Ok(params)
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<GetObjectOutput> {
+        let params = self.params()?;
+        let bucket = &self.inner.bucket;
+        let key = &self.inner.key;
+
         let mut headers = HeaderMap::new();
         if let Some(ref range) = self.inner.range {
             headers.insert("Range", HeaderValue::from_str(range).unwrap());
         }
-
-        let mut params = HashMap::new();
-        if let Some(ref version_id) = self.inner.version_id {
-            params.insert("versionId".to_string(), version_id.clone());
+        if let Some(ref if_match) = self.inner.if_match {
+            headers.insert(
+                "If-Match",
+                HeaderValue::from_str(if_match).map_err(|e| {
+                    ObsError::InvalidInput(format!("Invalid If-Match header: {}", e))
+                })?,
+            );
         }
 
         let resp = self
@@ -94,7 +194,15 @@ impl GetObjectFluentBuilder {
             return Err(ObsError::service_error(status, &text));
         }
 
-        let body = resp.bytes().await?;
+        let body = match self.inner.progress {
+            Some(ref tracker) => {
+                if let Some(len) = resp.content_length() {
+                    tracker.set_total(len);
+                }
+                tracker.read_body(resp).await?
+            }
+            None => resp.bytes().await?,
+        };
 
         let content_type = response_headers
             .get("Content-Type")
@@ -133,6 +241,11 @@ pub struct GetObjectInput {
     key: String,
     range: Option<String>,
     version_id: Option<String>,
+    if_match: Option<String>,
+    image_process: Option<ImageProcess>,
+    image_save_bucket: Option<String>,
+    image_save_object: Option<String>,
+    progress: Option<ProgressTracker>,
 }
 
 /// Output for the GetObject operation.
@@ -180,4 +293,48 @@ impl GetObjectOutput {
     pub fn last_modified(&self) -> Option<&str> {
         self.last_modified.as_deref()
     }
+
+    /// Get the last modified time as a UTC timestamp (`None` if it cannot be parsed).
+    pub fn last_modified_time(&self) -> Option<DateTime<Utc>> {
+        self.last_modified.as_deref().and_then(parse_timestamp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::Resize;
+    use crate::Config;
+
+    #[test]
+    fn test_presigned_url_with_image_process() {
+        let config = Config::builder()
+            .access_key("ak", "sk")
+            .region_name("cn-north-4")
+            .build()
+            .unwrap();
+        let client = Client::from_config(config).unwrap();
+
+        let url = client
+            .get_object()
+            .bucket("my-bucket")
+            .key("photo.jpg")
+            .image_process(ImageProcess::new().resize(Resize::new().width(200)))
+            .presigned_url(Duration::from_secs(3600))
+            .unwrap();
+
+        assert!(url.starts_with(
+            "https://my-bucket.obs.cn-north-4.myhuaweicloud.com/photo.jpg\
+             ?x-image-process=image%2Fresize%2Cw_200&AccessKeyId=ak&Expires="
+        ));
+        assert!(url.contains("&Signature="));
+
+        let missing_process = client
+            .get_object()
+            .bucket("my-bucket")
+            .key("photo.jpg")
+            .image_save_object("thumb.jpg")
+            .presigned_url(Duration::from_secs(60));
+        assert!(missing_process.is_err());
+    }
 }
@@ -3,8 +3,10 @@
 use std::collections::HashMap;
 
 use reqwest::Method;
-use serde::Deserialize;
 
+use super::{
+    AccessControlList, AccessControlPolicy, Grantee, Permission, SetObjectAclFluentBuilder,
+};
 use crate::client::Client;
 use crate::error::{ObsError, Result};
 
@@ -93,6 +95,8 @@ impl GetObjectAclFluentBuilder {
         let policy: AccessControlPolicy = crate::xml_utils::from_xml(&text)?;
 
         Ok(GetObjectAclOutput {
+            bucket: bucket.clone(),
+            key: key.clone(),
             version_id,
             owner: policy.owner,
             delivered: policy.delivered,
@@ -110,8 +114,14 @@ pub struct GetObjectAclInput {
 }
 
 /// Output for the GetObjectAcl operation.
+///
+/// The grants can be changed with [`add_grant`](Self::add_grant) and
+/// [`remove_grant`](Self::remove_grant) and written back with
+/// [`write_back`](Self::write_back).
 #[derive(Debug, Clone)]
 pub struct GetObjectAclOutput {
+    bucket: String,
+    key: String,
     version_id: Option<String>,
     owner: AclOwner,
     delivered: bool,
@@ -138,26 +148,118 @@ impl GetObjectAclOutput {
     pub fn grants(&self) -> &[Grant] {
         &self.grants
     }
-}
 
-/// Access control policy response from OBS.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename = "AccessControlPolicy")]
-struct AccessControlPolicy {
-    #[serde(rename = "Owner")]
-    owner: AclOwner,
-    #[serde(rename = "Delivered", default)]
-    delivered: bool,
-    #[serde(rename = "AccessControlList")]
-    access_control_list: AccessControlList,
-}
+    /// Add a grant, unless the same grant is already present.
+    ///
+    /// Returns `true` if the grant was added.
+    pub fn add_grant(&mut self, grant: Grant) -> bool {
+        if self.grants.contains(&grant) {
+            return false;
+        }
+        self.grants.push(grant);
+        true
+    }
 
-/// Access control list (internal for deserialization).
-#[derive(Debug, Clone, Deserialize)]
-struct AccessControlList {
-    #[serde(rename = "Grant", default)]
-    grants: Vec<Grant>,
+    /// Remove the grants of a permission to a grantee, or all of its grants if
+    /// `permission` is `None`.
+    ///
+    /// Returns `true` if any grant was removed.
+    pub fn remove_grant(&mut self, grantee: &Grantee, permission: Option<&Permission>) -> bool {
+        let len = self.grants.len();
+        self.grants
+            .retain(|g| &g.grantee != grantee || permission.is_some_and(|p| &g.permission != p));
+        self.grants.len() != len
+    }
+
+    /// Convert into an access control policy that can be passed to
+    /// [`SetObjectAclFluentBuilder::access_control_policy`].
+    pub fn to_policy(&self) -> AccessControlPolicy {
+        AccessControlPolicy {
+            owner: self.owner.clone(),
+            delivered: self.delivered,
+            access_control_list: AccessControlList {
+                grants: self.grants.clone(),
+            },
+        }
+    }
+
+    /// Create a SetObjectAcl request that writes this ACL back to the same
+    /// object version.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use huaweicloud_sdk_rust_obs::{Client, Grant, Grantee, Permission};
+    /// # async fn example(client: Client) -> huaweicloud_sdk_rust_obs::Result<()> {
+    /// let mut acl = client
+    ///     .get_object_acl()
+    ///     .bucket("my-bucket")
+    ///     .key("my-object")
+    ///     .send()
+    ///     .await?;
+    /// acl.add_grant(Grant::new(Grantee::by_id("domain-id"), Permission::Read));
+    /// acl.write_back(&client).send().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_back(&self, client: &Client) -> SetObjectAclFluentBuilder {
+        let mut request = client
+            .set_object_acl()
+            .bucket(&self.bucket)
+            .key(&self.key)
+            .access_control_policy(self.to_policy());
+        if let Some(ref version_id) = self.version_id {
+            request = request.version_id(version_id);
+        }
+        request
+    }
 }
 
 // Re-export types from set_object_acl module
 pub use super::set_object_acl::{AclOwner, Grant};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modify_grants() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<AccessControlPolicy xmlns="http://obs.myhwclouds.com/doc/2015-06-30/">
+  <Owner><ID>owner</ID></Owner>
+  <AccessControlList>
+    <Grant><Grantee><ID>owner</ID></Grantee><Permission>FULL_CONTROL</Permission></Grant>
+    <Grant><Grantee><Canned>Everyone</Canned></Grantee><Permission>READ</Permission></Grant>
+  </AccessControlList>
+</AccessControlPolicy>"#;
+        let policy: AccessControlPolicy = crate::xml_utils::from_xml(xml).unwrap();
+        let mut output = GetObjectAclOutput {
+            bucket: "bucket".to_string(),
+            key: "key".to_string(),
+            version_id: None,
+            owner: policy.owner,
+            delivered: policy.delivered,
+            grants: policy.access_control_list.grants,
+        };
+
+        let read = Grant::new(Grantee::by_id("user"), Permission::Read);
+        assert!(output.add_grant(read.clone()));
+        assert!(!output.add_grant(read));
+        assert!(output.add_grant(Grant::new(Grantee::by_id("user"), Permission::ReadAcp)));
+        assert_eq!(output.grants().len(), 4);
+
+        assert!(output.remove_grant(&Grantee::by_id("user"), Some(&Permission::ReadAcp)));
+        assert!(output.remove_grant(&Grantee::everyone(), None));
+        assert!(!output.remove_grant(&Grantee::everyone(), None));
+
+        let policy = output.to_policy();
+        assert_eq!(policy.owner.id(), "owner");
+        assert_eq!(
+            policy.access_control_list.grants,
+            vec![
+                Grant::new(Grantee::by_id("owner"), Permission::FullControl),
+                Grant::new(Grantee::by_id("user"), Permission::Read),
+            ]
+        );
+    }
+}
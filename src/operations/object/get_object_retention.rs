@@ -0,0 +1,118 @@
+//! GetObjectRetention operation - get the WORM retention of an object in OBS.
+
+use std::collections::HashMap;
+
+use reqwest::Method;
+
+use super::put_object_retention::RetentionXml;
+use super::ObjectRetention;
+use crate::client::Client;
+use crate::error::{ObsError, Result};
+
+/// Fluent builder for the GetObjectRetention operation.
+#[derive(Debug, Clone)]
+pub struct GetObjectRetentionFluentBuilder {
+    client: Client,
+    inner: GetObjectRetentionInput,
+}
+
+impl GetObjectRetentionFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: GetObjectRetentionInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Set the object key.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.inner.key = key.into();
+        self
+    }
+
+    /// Set the version ID.
+    pub fn version_id(mut self, version_id: impl Into<String>) -> Self {
+        self.inner.version_id = Some(version_id.into());
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<GetObjectRetentionOutput> {
+        let bucket = &self.inner.bucket;
+        let key = &self.inner.key;
+
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+        if key.is_empty() {
+            return Err(ObsError::InvalidInput("object key is required".to_string()));
+        }
+
+        let mut params = HashMap::new();
+        params.insert("retention".to_string(), String::new());
+
+        if let Some(ref version_id) = self.inner.version_id {
+            params.insert("versionId".to_string(), version_id.clone());
+        }
+
+        let resp = self
+            .client
+            .do_request(
+                Method::GET,
+                Some(bucket),
+                Some(key),
+                None,
+                Some(params),
+                None,
+            )
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await?;
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        let text = resp.text().await?;
+        let retention: RetentionXml = crate::xml_utils::from_xml(&text)?;
+        let retention = ObjectRetention::parse(&retention.mode, &retention.retain_until_date)
+            .ok_or_else(|| {
+                ObsError::XmlParse(format!(
+                    "invalid retain until date: {}",
+                    retention.retain_until_date
+                ))
+            })?;
+
+        Ok(GetObjectRetentionOutput { retention })
+    }
+}
+
+/// Input for the GetObjectRetention operation.
+#[derive(Debug, Clone, Default)]
+pub struct GetObjectRetentionInput {
+    bucket: String,
+    key: String,
+    version_id: Option<String>,
+}
+
+/// Output for the GetObjectRetention operation.
+#[derive(Debug, Clone)]
+pub struct GetObjectRetentionOutput {
+    retention: ObjectRetention,
+}
+
+impl GetObjectRetentionOutput {
+    /// Get the retention of the object.
+    pub fn retention(&self) -> &ObjectRetention {
+        &self.retention
+    }
+}
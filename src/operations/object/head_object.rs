@@ -2,10 +2,13 @@
 
 use std::collections::HashMap;
 
+use chrono::{DateTime, Utc};
 use reqwest::Method;
 
+use super::{ObjectRetention, RestoreStatus};
 use crate::client::Client;
 use crate::error::{ObsError, Result};
+use crate::operations::{parse_timestamp, StorageClass};
 
 /// Fluent builder for the HeadObject operation.
 #[derive(Debug, Clone)]
@@ -105,12 +108,54 @@ impl HeadObjectFluentBuilder {
             .and_then(|v| v.to_str().ok())
             .map(|s| s.to_string());
 
+        let restore = headers
+            .get("x-obs-restore")
+            .and_then(|v| v.to_str().ok())
+            .and_then(RestoreStatus::parse);
+
+        let header_string = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|s| s.to_string())
+        };
+
+        let retention = match (
+            header_string("x-obs-object-lock-mode"),
+            header_string("x-obs-object-lock-retain-until-date"),
+        ) {
+            (Some(mode), Some(date)) => ObjectRetention::parse(&mode, &date),
+            _ => None,
+        };
+
+        let metadata = headers
+            .iter()
+            .filter_map(|(name, value)| {
+                let key = name.as_str().strip_prefix("x-obs-meta-")?;
+                let value = value.to_str().ok()?;
+                Some((key.to_string(), value.to_string()))
+            })
+            .collect();
+
         Ok(HeadObjectOutput {
             content_type,
             content_length,
             etag,
             last_modified,
             storage_class,
+            restore,
+            version_id: header_string("x-obs-version-id"),
+            content_encoding: header_string("Content-Encoding"),
+            content_disposition: header_string("Content-Disposition"),
+            content_language: header_string("Content-Language"),
+            cache_control: header_string("Cache-Control"),
+            expires: header_string("Expires"),
+            website_redirect_location: header_string("x-obs-website-redirect-location"),
+            object_type: header_string("x-obs-object-type"),
+            next_append_position: header_string("x-obs-next-append-position")
+                .and_then(|s| s.parse().ok()),
+            retention,
+            metadata,
         })
     }
 }
@@ -131,6 +176,18 @@ pub struct HeadObjectOutput {
     etag: Option<String>,
     last_modified: Option<String>,
     storage_class: Option<String>,
+    restore: Option<RestoreStatus>,
+    version_id: Option<String>,
+    content_encoding: Option<String>,
+    content_disposition: Option<String>,
+    content_language: Option<String>,
+    cache_control: Option<String>,
+    expires: Option<String>,
+    website_redirect_location: Option<String>,
+    object_type: Option<String>,
+    next_append_position: Option<u64>,
+    retention: Option<ObjectRetention>,
+    metadata: HashMap<String, String>,
 }
 
 impl HeadObjectOutput {
@@ -154,8 +211,82 @@ impl HeadObjectOutput {
         self.last_modified.as_deref()
     }
 
+    /// Get the last modified time as a UTC timestamp (`None` if it cannot be parsed).
+    pub fn last_modified_time(&self) -> Option<DateTime<Utc>> {
+        self.last_modified.as_deref().and_then(parse_timestamp)
+    }
+
     /// Get the storage class.
     pub fn storage_class(&self) -> Option<&str> {
         self.storage_class.as_deref()
     }
+
+    /// Get the storage class as a [`StorageClass`].
+    pub fn storage_class_kind(&self) -> Option<StorageClass> {
+        self.storage_class.as_deref().map(StorageClass::from)
+    }
+
+    /// Get the restore status of an archived object.
+    ///
+    /// Returns `None` if no restore has been requested for the object.
+    pub fn restore(&self) -> Option<&RestoreStatus> {
+        self.restore.as_ref()
+    }
+
+    /// Get the version ID.
+    pub fn version_id(&self) -> Option<&str> {
+        self.version_id.as_deref()
+    }
+
+    /// Get the content encoding.
+    pub fn content_encoding(&self) -> Option<&str> {
+        self.content_encoding.as_deref()
+    }
+
+    /// Get the content disposition.
+    pub fn content_disposition(&self) -> Option<&str> {
+        self.content_disposition.as_deref()
+    }
+
+    /// Get the content language.
+    pub fn content_language(&self) -> Option<&str> {
+        self.content_language.as_deref()
+    }
+
+    /// Get the cache control.
+    pub fn cache_control(&self) -> Option<&str> {
+        self.cache_control.as_deref()
+    }
+
+    /// Get the expires header.
+    pub fn expires(&self) -> Option<&str> {
+        self.expires.as_deref()
+    }
+
+    /// Get the website redirect location.
+    pub fn website_redirect_location(&self) -> Option<&str> {
+        self.website_redirect_location.as_deref()
+    }
+
+    /// Get the object type (`Appendable` for objects created by AppendObject).
+    pub fn object_type(&self) -> Option<&str> {
+        self.object_type.as_deref()
+    }
+
+    /// Get the position for the next append (appendable objects only).
+    pub fn next_append_position(&self) -> Option<u64> {
+        self.next_append_position
+    }
+
+    /// Get the WORM retention of the object.
+    ///
+    /// Returns `None` if the object is not protected by a retention policy.
+    pub fn retention(&self) -> Option<&ObjectRetention> {
+        self.retention.as_ref()
+    }
+
+    /// Get the custom metadata (keys without the "x-obs-meta-" prefix).
+    pub fn metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
 }
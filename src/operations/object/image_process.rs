@@ -0,0 +1,488 @@
+//! Image processing parameters for GetObject (`x-image-process`).
+
+use std::fmt;
+
+use base64::{engine::general_purpose, Engine};
+
+use crate::error::{ObsError, Result};
+
+/// Image processing applied by OBS when an image object is downloaded.
+///
+/// Actions are applied in the order they are added and rendered as
+/// `image/<action>/<action>...`. A named style created in the OBS console
+/// can be used instead with [`ImageProcess::style`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use huaweicloud_sdk_rust_obs::{Gravity, ImageProcess, Resize, TextWatermark};
+/// let process = ImageProcess::new()
+///     .resize(Resize::new().mode("lfit").width(200).height(200))
+///     .text_watermark(TextWatermark::new("© Example").gravity(Gravity::BottomRight))
+///     .format("webp")
+///     .quality(80);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImageProcess {
+    style: Option<String>,
+    actions: Vec<String>,
+}
+
+impl ImageProcess {
+    /// Create an empty list of image actions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use a named image style instead of inline actions.
+    pub fn style(name: impl Into<String>) -> Self {
+        Self {
+            style: Some(name.into()),
+            actions: Vec::new(),
+        }
+    }
+
+    /// Resize the image.
+    pub fn resize(self, resize: Resize) -> Self {
+        self.action(resize.to_string())
+    }
+
+    /// Crop a `width` x `height` area starting at (`x`, `y`).
+    pub fn crop(self, x: u32, y: u32, width: u32, height: u32) -> Self {
+        self.action(format!("crop,x_{},y_{},w_{},h_{}", x, y, width, height))
+    }
+
+    /// Rotate the image clockwise (0-360 degrees).
+    pub fn rotate(self, degrees: u32) -> Self {
+        self.action(format!("rotate,{}", degrees))
+    }
+
+    /// Add a text watermark.
+    pub fn text_watermark(self, watermark: TextWatermark) -> Self {
+        self.action(watermark.to_string())
+    }
+
+    /// Add an image watermark.
+    pub fn image_watermark(self, watermark: ImageWatermark) -> Self {
+        self.action(watermark.to_string())
+    }
+
+    /// Convert the image format.
+    ///
+    /// Possible values: jpg, png, bmp, webp, gif, tiff.
+    pub fn format(self, format: impl Into<String>) -> Self {
+        self.action(format!("format,{}", format.into()))
+    }
+
+    /// Set the quality relative to the original image (1-100).
+    pub fn quality(self, quality: u8) -> Self {
+        self.action(format!("quality,q_{}", quality))
+    }
+
+    /// Set the absolute quality of the output image (1-100).
+    pub fn absolute_quality(self, quality: u8) -> Self {
+        self.action(format!("quality,Q_{}", quality))
+    }
+
+    /// Check if no style or action has been set.
+    pub fn is_empty(&self) -> bool {
+        self.style.is_none() && self.actions.is_empty()
+    }
+
+    /// Check the parameter can be sent to OBS.
+    pub(crate) fn validate(&self) -> Result<()> {
+        if self.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "image process requires a style or at least one action".to_string(),
+            ));
+        }
+        if self.style.is_some() && !self.actions.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "image style cannot be combined with image actions".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn action(mut self, action: String) -> Self {
+        self.actions.push(action);
+        self
+    }
+}
+
+impl fmt::Display for ImageProcess {
+    /// Render the value of the `x-image-process` parameter.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.style {
+            Some(ref style) => write!(f, "style/{}", style),
+            None => write!(f, "image/{}", self.actions.join("/")),
+        }
+    }
+}
+
+/// Resize action.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Resize {
+    mode: Option<String>,
+    width: Option<u32>,
+    height: Option<u32>,
+    long: Option<u32>,
+    short: Option<u32>,
+    percent: Option<u32>,
+    limit: Option<bool>,
+    color: Option<String>,
+}
+
+impl Resize {
+    /// Create an empty resize action.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the resize mode (default: lfit).
+    ///
+    /// Possible values: lfit, mfit, fill, pad, fixed.
+    pub fn mode(mut self, mode: impl Into<String>) -> Self {
+        self.mode = Some(mode.into());
+        self
+    }
+
+    /// Set the target width in pixels.
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the target height in pixels.
+    pub fn height(mut self, height: u32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Set the target length of the longer side in pixels.
+    pub fn long(mut self, long: u32) -> Self {
+        self.long = Some(long);
+        self
+    }
+
+    /// Set the target length of the shorter side in pixels.
+    pub fn short(mut self, short: u32) -> Self {
+        self.short = Some(short);
+        self
+    }
+
+    /// Scale by a percentage (1-1000).
+    pub fn percent(mut self, percent: u32) -> Self {
+        self.percent = Some(percent);
+        self
+    }
+
+    /// Set whether images smaller than the target are left unchanged (default: true).
+    pub fn limit(mut self, limit: bool) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Set the fill color used by the pad mode, as a hex RGB value (e.g. FFFFFF).
+    pub fn color(mut self, color: impl Into<String>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+impl fmt::Display for Resize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("resize")?;
+        if let Some(ref mode) = self.mode {
+            write!(f, ",m_{}", mode)?;
+        }
+        let sizes = [
+            ("w", self.width),
+            ("h", self.height),
+            ("l", self.long),
+            ("s", self.short),
+            ("p", self.percent),
+        ];
+        for (name, value) in sizes {
+            if let Some(value) = value {
+                write!(f, ",{}_{}", name, value)?;
+            }
+        }
+        if let Some(limit) = self.limit {
+            write!(f, ",limit_{}", u8::from(limit))?;
+        }
+        if let Some(ref color) = self.color {
+            write!(f, ",color_{}", color)?;
+        }
+        Ok(())
+    }
+}
+
+/// Text watermark action.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextWatermark {
+    text: String,
+    font: Option<String>,
+    size: Option<u32>,
+    color: Option<String>,
+    rotate: Option<u32>,
+    placement: WatermarkPlacement,
+}
+
+impl TextWatermark {
+    /// Create a text watermark.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            font: None,
+            size: None,
+            color: None,
+            rotate: None,
+            placement: WatermarkPlacement::default(),
+        }
+    }
+
+    /// Set the font name (e.g. wqy-zenhei).
+    pub fn font(mut self, font: impl Into<String>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Set the font size in pixels.
+    pub fn size(mut self, size: u32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Set the text color as a hex RGB value (e.g. FF0000).
+    pub fn color(mut self, color: impl Into<String>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Rotate the text clockwise (0-360 degrees).
+    pub fn rotate(mut self, degrees: u32) -> Self {
+        self.rotate = Some(degrees);
+        self
+    }
+
+    /// Set the watermark position.
+    pub fn gravity(mut self, gravity: Gravity) -> Self {
+        self.placement.gravity = Some(gravity);
+        self
+    }
+
+    /// Set the horizontal offset from the edge in pixels.
+    pub fn x(mut self, x: u32) -> Self {
+        self.placement.x = Some(x);
+        self
+    }
+
+    /// Set the vertical offset from the edge in pixels.
+    pub fn y(mut self, y: u32) -> Self {
+        self.placement.y = Some(y);
+        self
+    }
+
+    /// Set the opacity (0-100).
+    pub fn transparency(mut self, transparency: u8) -> Self {
+        self.placement.transparency = Some(transparency);
+        self
+    }
+}
+
+impl fmt::Display for TextWatermark {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "watermark,text_{}", encode_param(&self.text))?;
+        if let Some(ref font) = self.font {
+            write!(f, ",type_{}", encode_param(font))?;
+        }
+        if let Some(size) = self.size {
+            write!(f, ",size_{}", size)?;
+        }
+        if let Some(ref color) = self.color {
+            write!(f, ",color_{}", color)?;
+        }
+        if let Some(rotate) = self.rotate {
+            write!(f, ",rotate_{}", rotate)?;
+        }
+        write!(f, "{}", self.placement)
+    }
+}
+
+/// Image watermark action.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageWatermark {
+    key: String,
+    placement: WatermarkPlacement,
+}
+
+impl ImageWatermark {
+    /// Create an image watermark from an object in the same bucket.
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            placement: WatermarkPlacement::default(),
+        }
+    }
+
+    /// Set the watermark position.
+    pub fn gravity(mut self, gravity: Gravity) -> Self {
+        self.placement.gravity = Some(gravity);
+        self
+    }
+
+    /// Set the horizontal offset from the edge in pixels.
+    pub fn x(mut self, x: u32) -> Self {
+        self.placement.x = Some(x);
+        self
+    }
+
+    /// Set the vertical offset from the edge in pixels.
+    pub fn y(mut self, y: u32) -> Self {
+        self.placement.y = Some(y);
+        self
+    }
+
+    /// Set the opacity (0-100).
+    pub fn transparency(mut self, transparency: u8) -> Self {
+        self.placement.transparency = Some(transparency);
+        self
+    }
+}
+
+impl fmt::Display for ImageWatermark {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "watermark,image_{}{}",
+            encode_param(&self.key),
+            self.placement
+        )
+    }
+}
+
+/// Position of a watermark in the image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Gravity {
+    /// Top left corner.
+    TopLeft,
+    /// Top edge, centered.
+    Top,
+    /// Top right corner.
+    TopRight,
+    /// Left edge, centered.
+    Left,
+    /// Center of the image.
+    Center,
+    /// Right edge, centered.
+    Right,
+    /// Bottom left corner.
+    BottomLeft,
+    /// Bottom edge, centered.
+    Bottom,
+    /// Bottom right corner (the OBS default).
+    BottomRight,
+}
+
+impl Gravity {
+    /// Get the parameter value of this position.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Gravity::TopLeft => "tl",
+            Gravity::Top => "top",
+            Gravity::TopRight => "tr",
+            Gravity::Left => "left",
+            Gravity::Center => "center",
+            Gravity::Right => "right",
+            Gravity::BottomLeft => "bl",
+            Gravity::Bottom => "bottom",
+            Gravity::BottomRight => "br",
+        }
+    }
+}
+
+impl fmt::Display for Gravity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Position and opacity shared by all watermarks.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct WatermarkPlacement {
+    gravity: Option<Gravity>,
+    x: Option<u32>,
+    y: Option<u32>,
+    transparency: Option<u8>,
+}
+
+impl fmt::Display for WatermarkPlacement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(transparency) = self.transparency {
+            write!(f, ",t_{}", transparency)?;
+        }
+        if let Some(gravity) = self.gravity {
+            write!(f, ",g_{}", gravity)?;
+        }
+        if let Some(x) = self.x {
+            write!(f, ",x_{}", x)?;
+        }
+        if let Some(y) = self.y {
+            write!(f, ",y_{}", y)?;
+        }
+        Ok(())
+    }
+}
+
+/// Encode a text parameter with URL-safe base64, as required by OBS.
+fn encode_param(value: &str) -> String {
+    general_purpose::URL_SAFE_NO_PAD.encode(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_image_process() {
+        let process = ImageProcess::new()
+            .resize(
+                Resize::new()
+                    .mode("lfit")
+                    .width(200)
+                    .height(100)
+                    .limit(false),
+            )
+            .crop(10, 20, 300, 400)
+            .rotate(90)
+            .text_watermark(
+                TextWatermark::new("Hello")
+                    .size(30)
+                    .color("FF0000")
+                    .gravity(Gravity::BottomRight)
+                    .x(10)
+                    .y(10),
+            )
+            .image_watermark(ImageWatermark::new("logo.png").transparency(50))
+            .format("webp")
+            .quality(80);
+
+        assert_eq!(
+            process.to_string(),
+            "image/resize,m_lfit,w_200,h_100,limit_0/crop,x_10,y_20,w_300,h_400/rotate,90/\
+             watermark,text_SGVsbG8,size_30,color_FF0000,g_br,x_10,y_10/\
+             watermark,image_bG9nby5wbmc,t_50/format,webp/quality,q_80"
+        );
+        assert!(process.validate().is_ok());
+    }
+
+    #[test]
+    fn test_render_style() {
+        let process = ImageProcess::style("thumbnail");
+        assert_eq!(process.to_string(), "style/thumbnail");
+        assert!(process.validate().is_ok());
+        assert!(process.rotate(90).validate().is_err());
+        assert!(ImageProcess::new().validate().is_err());
+    }
+}
@@ -3,30 +3,84 @@
 //! This module provides the following operations:
 //! - [`put_object`] - Upload an object to OBS
 //! - [`get_object`] - Download an object from OBS
+//! - [`image_process`] - Image processing parameters for [`get_object`]
 //! - [`delete_object`] - Delete a single object from OBS
 //! - [`delete_objects`] - Batch delete multiple objects from OBS
+//! - [`delete_prefix`] - Delete every object under a prefix
 //! - [`copy_object`] - Copy an object within OBS
 //! - [`head_object`] - Get object metadata from OBS
+//! - [`options_object`] - Send a CORS preflight request for an object
 //! - [`append_object`] - Append data to an object in OBS
+//! - [`append_writer`] - Stream data into an appendable object
+//! - [`object_reader`] - Random access to an object with range reads
+//! - [`object_writer`] - Stream data of unknown length into an object
 //! - [`set_object_acl`] - Set access control list for an object
 //! - [`get_object_acl`] - Get access control list for an object
+//! - [`acl`] - Canned ACLs and grant headers shared by several operations
+//! - [`restore_object`] - Restore an archived object
+//! - [`set_object_metadata`] - Modify object metadata in place
+//! - [`put_object_tagging`] - Set tags for an object
+//! - [`get_object_tagging`] - Get the tags of an object
+//! - [`delete_object_tagging`] - Remove all tags from an object
+//! - [`put_object_retention`] - Set the WORM retention of an object
+//! - [`get_object_retention`] - Get the WORM retention of an object
+//! - [`rename_object`] - Rename an object or directory (parallel file system)
+//! - [`truncate_object`] - Truncate an object (parallel file system)
+//! - [`modify_object`] - Write data at a position of an object (parallel file system)
+//! - [`delete_directory`] - Recursively delete a directory
 
+mod acl;
 mod append_object;
+mod append_writer;
 mod copy_object;
+mod delete_directory;
 mod delete_object;
+mod delete_object_tagging;
 mod delete_objects;
+mod delete_prefix;
 mod get_object;
 mod get_object_acl;
+mod get_object_retention;
+mod get_object_tagging;
 mod head_object;
+mod image_process;
+mod modify_object;
+mod object_reader;
+mod object_writer;
+mod options_object;
 mod put_object;
+mod put_object_retention;
+mod put_object_tagging;
+mod rename_object;
+mod restore_object;
 mod set_object_acl;
+mod set_object_metadata;
+mod truncate_object;
 
+pub use acl::*;
 pub use append_object::*;
+pub use append_writer::*;
 pub use copy_object::*;
+pub use delete_directory::*;
 pub use delete_object::*;
+pub use delete_object_tagging::*;
 pub use delete_objects::*;
+pub use delete_prefix::*;
 pub use get_object::*;
 pub use get_object_acl::*;
+pub use get_object_retention::*;
+pub use get_object_tagging::*;
 pub use head_object::*;
+pub use image_process::*;
+pub use modify_object::*;
+pub use object_reader::*;
+pub use object_writer::*;
+pub use options_object::*;
 pub use put_object::*;
+pub use put_object_retention::*;
+pub use put_object_tagging::*;
+pub use rename_object::*;
+pub use restore_object::*;
 pub use set_object_acl::*;
+pub use set_object_metadata::*;
+pub use truncate_object::*;
@@ -0,0 +1,451 @@
+//! ObjectReader - random access to an object in OBS.
+
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::io::{self, SeekFrom};
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+use bytes::Bytes;
+use tokio::io::{AsyncRead, AsyncSeek, ReadBuf};
+use tokio::task::JoinHandle;
+
+use crate::client::Client;
+use crate::error::{ObsError, Result};
+
+/// Default block size (1 MiB).
+const DEFAULT_BLOCK_SIZE: u64 = 1024 * 1024;
+
+/// Default number of blocks fetched ahead of sequential reads.
+const DEFAULT_READ_AHEAD: usize = 2;
+
+/// Default number of blocks kept in the cache.
+const DEFAULT_CACHE_BLOCKS: usize = 8;
+
+/// Builder for an [`ObjectReader`].
+#[derive(Debug, Clone)]
+pub struct ObjectReaderBuilder {
+    client: Client,
+    bucket: String,
+    key: String,
+    version_id: Option<String>,
+    block_size: u64,
+    read_ahead: usize,
+    cache_blocks: usize,
+}
+
+impl ObjectReaderBuilder {
+    /// Create a new builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            bucket: String::new(),
+            key: String::new(),
+            version_id: None,
+            block_size: DEFAULT_BLOCK_SIZE,
+            read_ahead: DEFAULT_READ_AHEAD,
+            cache_blocks: DEFAULT_CACHE_BLOCKS,
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.bucket = bucket.into();
+        self
+    }
+
+    /// Set the object key.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key = key.into();
+        self
+    }
+
+    /// Set the version ID to read.
+    pub fn version_id(mut self, version_id: impl Into<String>) -> Self {
+        self.version_id = Some(version_id.into());
+        self
+    }
+
+    /// Set the size of the ranges requested from OBS (default: 1 MiB).
+    pub fn block_size(mut self, block_size: u64) -> Self {
+        self.block_size = block_size;
+        self
+    }
+
+    /// Set how many blocks are fetched ahead of sequential reads (default: 2, 0 disables it).
+    pub fn read_ahead(mut self, read_ahead: usize) -> Self {
+        self.read_ahead = read_ahead;
+        self
+    }
+
+    /// Set how many blocks are kept in the cache (default: 8).
+    pub fn cache_blocks(mut self, cache_blocks: usize) -> Self {
+        self.cache_blocks = cache_blocks;
+        self
+    }
+
+    /// Open the reader.
+    ///
+    /// Looks up the size, ETag and version of the object with HeadObject.
+    pub async fn build(self) -> Result<ObjectReader> {
+        if self.bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+        if self.key.is_empty() {
+            return Err(ObsError::InvalidInput("object key is required".to_string()));
+        }
+        if self.block_size == 0 {
+            return Err(ObsError::InvalidInput(
+                "block size must be greater than zero".to_string(),
+            ));
+        }
+        if self.cache_blocks == 0 {
+            return Err(ObsError::InvalidInput(
+                "cache must hold at least one block".to_string(),
+            ));
+        }
+
+        let mut head = self
+            .client
+            .head_object()
+            .bucket(&self.bucket)
+            .key(&self.key);
+        if let Some(ref version_id) = self.version_id {
+            head = head.version_id(version_id);
+        }
+        let head = head.send().await?;
+
+        let size = head.content_length().ok_or_else(|| {
+            ObsError::Unknown(format!("object {} has no Content-Length", self.key))
+        })?;
+        let etag = head.etag().map(|s| s.to_string());
+        let version_id = self
+            .version_id
+            .or_else(|| head.version_id().map(|s| s.to_string()));
+
+        Ok(ObjectReader {
+            client: self.client,
+            bucket: self.bucket,
+            key: self.key,
+            version_id,
+            etag,
+            size,
+            block_size: self.block_size,
+            read_ahead: self.read_ahead,
+            position: 0,
+            last_block: None,
+            cache: BlockCache::new(self.cache_blocks),
+            pending: HashMap::new(),
+        })
+    }
+}
+
+/// An [`AsyncRead`] + [`AsyncSeek`] over an object in OBS.
+///
+/// Reads are served from blocks of `block_size` bytes fetched with ranged
+/// GetObject requests. Recently used blocks are kept in an LRU cache, and
+/// while reading sequentially the next `read_ahead` blocks are fetched in
+/// the background. Seeking is free until the next read.
+///
+/// The reader is pinned to the ETag and version seen when it was opened:
+/// if the object is overwritten, reading a block that is not cached fails
+/// with a `412 Precondition Failed` service error. I/O errors wrap an
+/// [`ObsError`].
+///
+/// ```rust,no_run
+/// use tokio::io::{AsyncReadExt, AsyncSeekExt};
+/// use std::io::SeekFrom;
+///
+/// let mut reader = client.object_reader()
+///     .bucket("my-bucket")
+///     .key("data/table.parquet")
+///     .build()
+///     .await?;
+///
+/// // Parquet files end with the footer length and the magic "PAR1".
+/// let mut tail = [0u8; 8];
+/// reader.seek(SeekFrom::End(-8)).await?;
+/// reader.read_exact(&mut tail).await?;
+/// ```
+pub struct ObjectReader {
+    client: Client,
+    bucket: String,
+    key: String,
+    version_id: Option<String>,
+    etag: Option<String>,
+    size: u64,
+    block_size: u64,
+    read_ahead: usize,
+    position: u64,
+    last_block: Option<u64>,
+    cache: BlockCache,
+    pending: HashMap<u64, JoinHandle<Result<Bytes>>>,
+}
+
+impl ObjectReader {
+    /// Get the bucket name.
+    pub fn bucket(&self) -> &str {
+        &self.bucket
+    }
+
+    /// Get the object key.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Get the size of the object.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Get the ETag the reader is pinned to.
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    /// Get the version ID the reader is pinned to.
+    pub fn version_id(&self) -> Option<&str> {
+        self.version_id.as_deref()
+    }
+
+    /// Get the current read position.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Number of blocks in the object.
+    fn block_count(&self) -> u64 {
+        self.size.div_ceil(self.block_size)
+    }
+
+    /// Start fetching a block unless it is cached or already being fetched.
+    fn fetch(&mut self, index: u64) {
+        if index >= self.block_count()
+            || self.cache.contains(index)
+            || self.pending.contains_key(&index)
+        {
+            return;
+        }
+
+        let start = index * self.block_size;
+        let end = (start + self.block_size).min(self.size) - 1;
+        let mut request = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(&self.key)
+            .range(format!("bytes={}-{}", start, end));
+        if let Some(ref etag) = self.etag {
+            request = request.if_match(format!("\"{}\"", etag));
+        }
+        if let Some(ref version_id) = self.version_id {
+            request = request.version_id(version_id);
+        }
+
+        let expected = end - start + 1;
+        self.pending.insert(
+            index,
+            tokio::spawn(async move {
+                let body = request.send().await?.into_body();
+                if body.len() as u64 != expected {
+                    return Err(ObsError::Unknown(format!(
+                        "expected {} bytes for range {}-{}, got {}",
+                        expected,
+                        start,
+                        end,
+                        body.len()
+                    )));
+                }
+                Ok(body)
+            }),
+        );
+    }
+
+    /// Cancel fetches outside the blocks `[first, last]`.
+    fn cancel_outside(&mut self, first: u64, last: u64) {
+        self.pending.retain(|index, handle| {
+            let keep = (first..=last).contains(index);
+            if !keep {
+                handle.abort();
+            }
+            keep
+        });
+    }
+
+    /// Get a block, fetching it and the read-ahead blocks if needed.
+    fn poll_block(&mut self, cx: &mut Context<'_>, index: u64) -> Poll<io::Result<Bytes>> {
+        if let Some(block) = self.cache.get(index) {
+            return Poll::Ready(Ok(block));
+        }
+
+        let sequential = match self.last_block {
+            Some(last) => index == last + 1,
+            None => index == 0,
+        };
+        let read_ahead = if sequential {
+            self.read_ahead as u64
+        } else {
+            0
+        };
+        self.cancel_outside(index, index + read_ahead);
+        for ahead in index..=index + read_ahead {
+            self.fetch(ahead);
+        }
+
+        let handle = self
+            .pending
+            .get_mut(&index)
+            .expect("block is being fetched");
+        let result = ready!(Pin::new(handle).poll(cx));
+        self.pending.remove(&index);
+        let block = match result {
+            Ok(Ok(block)) => block,
+            Ok(Err(e)) => return Poll::Ready(Err(io::Error::other(e))),
+            Err(e) => {
+                let error = ObsError::Unknown(format!("block fetch task failed: {}", e));
+                return Poll::Ready(Err(io::Error::other(error)));
+            }
+        };
+        self.cache.insert(index, block.clone());
+        Poll::Ready(Ok(block))
+    }
+}
+
+impl AsyncRead for ObjectReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if this.position >= this.size || buf.remaining() == 0 {
+            return Poll::Ready(Ok(()));
+        }
+
+        let index = this.position / this.block_size;
+        let block = ready!(this.poll_block(cx, index))?;
+        this.last_block = Some(index);
+
+        let offset = (this.position - index * this.block_size) as usize;
+        let n = (block.len() - offset).min(buf.remaining());
+        buf.put_slice(&block[offset..offset + n]);
+        this.position += n as u64;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncSeek for ObjectReader {
+    fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+        let this = self.get_mut();
+        let (base, offset) = match position {
+            SeekFrom::Start(position) => {
+                this.position = position;
+                return Ok(());
+            }
+            SeekFrom::End(offset) => (this.size, offset),
+            SeekFrom::Current(offset) => (this.position, offset),
+        };
+        match base.checked_add_signed(offset) {
+            Some(position) => {
+                this.position = position;
+                Ok(())
+            }
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        Poll::Ready(Ok(self.position))
+    }
+}
+
+impl Drop for ObjectReader {
+    fn drop(&mut self) {
+        for handle in self.pending.values() {
+            handle.abort();
+        }
+    }
+}
+
+impl std::fmt::Debug for ObjectReader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ObjectReader")
+            .field("bucket", &self.bucket)
+            .field("key", &self.key)
+            .field("version_id", &self.version_id)
+            .field("etag", &self.etag)
+            .field("size", &self.size)
+            .field("block_size", &self.block_size)
+            .field("position", &self.position)
+            .field("cached", &self.cache.blocks.len())
+            .field("pending", &self.pending.len())
+            .finish()
+    }
+}
+
+/// Least recently used cache of blocks.
+struct BlockCache {
+    capacity: usize,
+    /// Blocks ordered from least to most recently used.
+    blocks: VecDeque<(u64, Bytes)>,
+}
+
+impl BlockCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            blocks: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    fn contains(&self, index: u64) -> bool {
+        self.blocks.iter().any(|(i, _)| *i == index)
+    }
+
+    /// Get a block and mark it as most recently used.
+    fn get(&mut self, index: u64) -> Option<Bytes> {
+        let pos = self.blocks.iter().position(|(i, _)| *i == index)?;
+        let entry = self.blocks.remove(pos)?;
+        let block = entry.1.clone();
+        self.blocks.push_back(entry);
+        Some(block)
+    }
+
+    /// Insert a block, evicting the least recently used one if full.
+    fn insert(&mut self, index: u64, block: Bytes) {
+        self.blocks.retain(|(i, _)| *i != index);
+        if self.blocks.len() == self.capacity {
+            self.blocks.pop_front();
+        }
+        self.blocks.push_back((index, block));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_cache_lru() {
+        let mut cache = BlockCache::new(2);
+        cache.insert(0, Bytes::from_static(b"a"));
+        cache.insert(1, Bytes::from_static(b"b"));
+        assert_eq!(cache.get(0), Some(Bytes::from_static(b"a")));
+
+        // Block 1 is now the least recently used and gets evicted.
+        cache.insert(2, Bytes::from_static(b"c"));
+        assert!(cache.contains(0));
+        assert!(!cache.contains(1));
+        assert!(cache.contains(2));
+
+        cache.insert(0, Bytes::from_static(b"A"));
+        cache.insert(3, Bytes::from_static(b"d"));
+        assert_eq!(cache.get(0), Some(Bytes::from_static(b"A")));
+        assert!(!cache.contains(2));
+    }
+}
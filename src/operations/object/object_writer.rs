@@ -0,0 +1,474 @@
+//! ObjectWriter - stream data of unknown length into an object in OBS.
+
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+use futures::stream::{FuturesUnordered, StreamExt};
+use tokio::io::AsyncWrite;
+use tokio::task::JoinHandle;
+
+use crate::client::Client;
+use crate::error::{ObsError, Result};
+use crate::operations::multipart::{AbortGuard, CompletedPart};
+use crate::operations::transfer::{MAX_PARTS, MAX_PART_SIZE, MIN_PART_SIZE};
+
+/// Default part size (8 MiB).
+const DEFAULT_PART_SIZE: usize = 8 * 1024 * 1024;
+
+/// Default number of parts uploaded at the same time.
+const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Builder for an [`ObjectWriter`].
+#[derive(Debug, Clone)]
+pub struct ObjectWriterBuilder {
+    client: Client,
+    bucket: String,
+    key: String,
+    content_type: Option<String>,
+    storage_class: Option<String>,
+    part_size: usize,
+    concurrency: usize,
+}
+
+impl ObjectWriterBuilder {
+    /// Create a new builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            bucket: String::new(),
+            key: String::new(),
+            content_type: None,
+            storage_class: None,
+            part_size: DEFAULT_PART_SIZE,
+            concurrency: DEFAULT_CONCURRENCY,
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.bucket = bucket.into();
+        self
+    }
+
+    /// Set the object key.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key = key.into();
+        self
+    }
+
+    /// Set the content type.
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+
+    /// Set the storage class.
+    ///
+    /// Possible values: STANDARD, WARM, COLD, DEEP_ARCHIVE
+    pub fn storage_class(mut self, storage_class: impl Into<String>) -> Self {
+        self.storage_class = Some(storage_class.into());
+        self
+    }
+
+    /// Set the part size (default: 8 MiB).
+    ///
+    /// Objects up to this size are uploaded with a single PutObject. At most
+    /// 10000 parts can be written, so the part size bounds the object size.
+    pub fn part_size(mut self, part_size: usize) -> Self {
+        self.part_size = part_size;
+        self
+    }
+
+    /// Set how many parts are uploaded at the same time (default: 4).
+    ///
+    /// Writes wait while this many parts are in flight, so memory use is
+    /// bounded by `part_size * (concurrency + 1)`.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Create the writer.
+    pub fn build(self) -> Result<ObjectWriter> {
+        if self.bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+        if self.key.is_empty() {
+            return Err(ObsError::InvalidInput("object key is required".to_string()));
+        }
+        if !(MIN_PART_SIZE..=MAX_PART_SIZE).contains(&(self.part_size as u64)) {
+            return Err(ObsError::InvalidInput(format!(
+                "part size must be between {} and {} bytes",
+                MIN_PART_SIZE, MAX_PART_SIZE
+            )));
+        }
+        if self.concurrency == 0 {
+            return Err(ObsError::InvalidInput(
+                "concurrency must be greater than zero".to_string(),
+            ));
+        }
+
+        Ok(ObjectWriter {
+            client: self.client,
+            bucket: self.bucket,
+            key: self.key,
+            content_type: self.content_type,
+            storage_class: self.storage_class,
+            part_size: self.part_size,
+            concurrency: self.concurrency,
+            buffer: Vec::new(),
+            bytes_written: 0,
+            initiating: None,
+            upload: None,
+            next_part_number: 1,
+            in_flight: FuturesUnordered::new(),
+            completed: Vec::new(),
+            finishing: None,
+            state: WriterState::Open,
+            etag: None,
+        })
+    }
+}
+
+type BoxFuture<T> = Pin<Box<dyn Future<Output = Result<T>> + Send>>;
+
+/// Lifecycle of an [`ObjectWriter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WriterState {
+    Open,
+    Closed,
+    Failed,
+}
+
+/// An [`AsyncWrite`] that uploads an object of unknown length.
+///
+/// Written data is collected into parts that are uploaded in the background
+/// with UploadPart. The object is created on `shutdown()`: if everything fit
+/// in the first part it is sent with a single PutObject, otherwise the
+/// multipart upload is completed. Until then nothing is visible in the
+/// bucket; `flush()` only waits for the parts already being uploaded.
+///
+/// If a request fails, or the writer is dropped before `shutdown()`
+/// finishes, the multipart upload is aborted. I/O errors wrap an [`ObsError`].
+///
+/// ```rust,no_run
+/// use tokio::io::AsyncWriteExt;
+///
+/// let mut writer = client.object_writer()
+///     .bucket("my-bucket")
+///     .key("exports/data.csv.gz")
+///     .content_type("application/gzip")
+///     .build()?;
+///
+/// tokio::io::copy(&mut encoder_output, &mut writer).await?;
+/// writer.shutdown().await?;
+/// println!("ETag: {:?}", writer.etag());
+/// ```
+pub struct ObjectWriter {
+    client: Client,
+    bucket: String,
+    key: String,
+    content_type: Option<String>,
+    storage_class: Option<String>,
+    part_size: usize,
+    concurrency: usize,
+    buffer: Vec<u8>,
+    bytes_written: u64,
+    initiating: Option<BoxFuture<String>>,
+    upload: Option<AbortGuard>,
+    next_part_number: i32,
+    in_flight: FuturesUnordered<JoinHandle<Result<CompletedPart>>>,
+    completed: Vec<CompletedPart>,
+    finishing: Option<BoxFuture<Option<String>>>,
+    state: WriterState,
+    etag: Option<String>,
+}
+
+impl ObjectWriter {
+    /// Get the bucket name.
+    pub fn bucket(&self) -> &str {
+        &self.bucket
+    }
+
+    /// Get the object key.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Get the number of bytes written so far.
+    pub fn bytes_written(&self) -> u64 {
+        self.bytes_written
+    }
+
+    /// Get the multipart upload ID, once a multipart upload was started.
+    pub fn upload_id(&self) -> Option<&str> {
+        self.upload.as_ref().map(|upload| upload.upload_id())
+    }
+
+    /// Get the ETag of the object, available after a successful `shutdown()`.
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    /// Mark the writer as failed and abort the upload.
+    fn fail(&mut self, error: ObsError) -> io::Error {
+        self.state = WriterState::Failed;
+        self.initiating = None;
+        self.abort_parts();
+        self.finishing = None;
+        self.upload = None;
+        io::Error::other(error)
+    }
+
+    /// Cancel the parts still being uploaded.
+    fn abort_parts(&mut self) {
+        for part in self.in_flight.iter() {
+            part.abort();
+        }
+        self.in_flight.clear();
+    }
+
+    /// Return an error if the writer can no longer be written to.
+    fn check_open(&self) -> io::Result<()> {
+        match self.state {
+            WriterState::Open => Ok(()),
+            WriterState::Closed => Err(io::Error::other(ObsError::InvalidInput(
+                "object writer is already shut down".to_string(),
+            ))),
+            WriterState::Failed => Err(io::Error::other(ObsError::InvalidInput(
+                "object writer failed and the upload was aborted".to_string(),
+            ))),
+        }
+    }
+
+    /// Make sure the multipart upload is initiated.
+    fn poll_initiate(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        if self.upload.is_some() {
+            return Poll::Ready(Ok(()));
+        }
+        if self.initiating.is_none() {
+            let mut request = self
+                .client
+                .initiate_multipart_upload()
+                .bucket(&self.bucket)
+                .key(&self.key);
+            if let Some(ref content_type) = self.content_type {
+                request = request.content_type(content_type);
+            }
+            if let Some(ref storage_class) = self.storage_class {
+                request = request.storage_class(storage_class);
+            }
+            self.initiating = Some(Box::pin(async move {
+                Ok(request.send().await?.upload_id().to_string())
+            }));
+        }
+
+        let result = ready!(self.initiating.as_mut().unwrap().as_mut().poll(cx));
+        self.initiating = None;
+        match result {
+            Ok(upload_id) => {
+                self.upload = Some(AbortGuard::new(
+                    self.client.clone(),
+                    &self.bucket,
+                    &self.key,
+                    &upload_id,
+                ));
+                Poll::Ready(Ok(()))
+            }
+            Err(e) => Poll::Ready(Err(self.fail(e))),
+        }
+    }
+
+    /// Collect finished parts until at most `max_in_flight` are left.
+    fn poll_parts(&mut self, cx: &mut Context<'_>, max_in_flight: usize) -> Poll<io::Result<()>> {
+        while self.in_flight.len() > max_in_flight {
+            match ready!(self.in_flight.poll_next_unpin(cx)) {
+                Some(Ok(Ok(part))) => self.completed.push(part),
+                Some(Ok(Err(e))) => return Poll::Ready(Err(self.fail(e))),
+                Some(Err(e)) => {
+                    let error = ObsError::Unknown(format!("part upload task failed: {}", e));
+                    return Poll::Ready(Err(self.fail(error)));
+                }
+                None => break,
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    /// Upload the buffered data as the next part.
+    fn poll_upload_buffer(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(self.poll_initiate(cx))?;
+        ready!(self.poll_parts(cx, self.concurrency - 1))?;
+
+        if self.next_part_number as u64 > MAX_PARTS {
+            let error = ObsError::InvalidInput(format!(
+                "object exceeds {} parts of {} bytes",
+                MAX_PARTS, self.part_size
+            ));
+            return Poll::Ready(Err(self.fail(error)));
+        }
+
+        let part_number = self.next_part_number;
+        self.next_part_number += 1;
+        let request = self
+            .client
+            .upload_part()
+            .bucket(&self.bucket)
+            .key(&self.key)
+            .upload_id(self.upload_id().unwrap_or_default())
+            .part_number(part_number)
+            .body(std::mem::take(&mut self.buffer));
+        self.in_flight.push(tokio::spawn(async move {
+            let output = request.send().await?;
+            Ok(CompletedPart::new(part_number, output.etag()))
+        }));
+        Poll::Ready(Ok(()))
+    }
+
+    /// Start the request that creates the object.
+    fn start_finishing(&mut self) {
+        let client = self.client.clone();
+        let (bucket, key) = (self.bucket.clone(), self.key.clone());
+
+        if self.upload.is_none() {
+            // Everything fit in one part: a single PutObject is enough.
+            let mut request = client
+                .put_object()
+                .bucket(bucket)
+                .key(key)
+                .body(std::mem::take(&mut self.buffer));
+            if let Some(ref content_type) = self.content_type {
+                request = request.content_type(content_type);
+            }
+            if let Some(ref storage_class) = self.storage_class {
+                request = request.storage_class(storage_class);
+            }
+            self.finishing = Some(Box::pin(async move {
+                Ok(request.send().await?.etag().map(|s| s.to_string()))
+            }));
+        } else {
+            let mut parts = std::mem::take(&mut self.completed);
+            parts.sort_by_key(|p| p.part_number);
+            let request = client
+                .complete_multipart_upload()
+                .bucket(bucket)
+                .key(key)
+                .upload_id(self.upload_id().unwrap_or_default())
+                .parts(parts);
+            self.finishing = Some(Box::pin(async move {
+                Ok(Some(request.send().await?.etag().to_string()))
+            }));
+        }
+    }
+}
+
+impl AsyncWrite for ObjectWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        this.check_open()?;
+
+        if this.buffer.len() >= this.part_size {
+            ready!(this.poll_upload_buffer(cx))?;
+        }
+
+        let n = buf.len().min(this.part_size - this.buffer.len());
+        this.buffer.extend_from_slice(&buf[..n]);
+        this.bytes_written += n as u64;
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        this.check_open()?;
+        this.poll_parts(cx, 0)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if this.state == WriterState::Closed {
+            return Poll::Ready(Ok(()));
+        }
+        this.check_open()?;
+
+        if this.finishing.is_none() {
+            if this.upload.is_some() || this.initiating.is_some() {
+                if !this.buffer.is_empty() {
+                    ready!(this.poll_upload_buffer(cx))?;
+                }
+                ready!(this.poll_parts(cx, 0))?;
+            }
+            this.start_finishing();
+        }
+
+        let result = ready!(this.finishing.as_mut().unwrap().as_mut().poll(cx));
+        this.finishing = None;
+        match result {
+            Ok(etag) => {
+                this.etag = etag;
+                this.state = WriterState::Closed;
+                if let Some(upload) = this.upload.take() {
+                    upload.disarm();
+                }
+                Poll::Ready(Ok(()))
+            }
+            Err(e) => Poll::Ready(Err(this.fail(e))),
+        }
+    }
+}
+
+impl Drop for ObjectWriter {
+    fn drop(&mut self) {
+        self.abort_parts();
+    }
+}
+
+impl std::fmt::Debug for ObjectWriter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ObjectWriter")
+            .field("bucket", &self.bucket)
+            .field("key", &self.key)
+            .field("part_size", &self.part_size)
+            .field("bytes_written", &self.bytes_written)
+            .field("buffered", &self.buffer.len())
+            .field("upload_id", &self.upload_id())
+            .field("in_flight", &self.in_flight.len())
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    fn client() -> Client {
+        let config = Config::builder()
+            .access_key("ak", "sk")
+            .endpoint("obs.cn-north-4.myhuaweicloud.com")
+            .build()
+            .unwrap();
+        Client::from_config(config).unwrap()
+    }
+
+    #[test]
+    fn test_build_validation() {
+        let builder = client().object_writer().bucket("bucket").key("key");
+        assert!(builder.clone().build().is_ok());
+        assert!(builder
+            .clone()
+            .part_size(MIN_PART_SIZE as usize - 1)
+            .build()
+            .is_err());
+        assert!(builder.clone().concurrency(0).build().is_err());
+        assert!(client().object_writer().key("key").build().is_err());
+    }
+}
@@ -0,0 +1,99 @@
+//! OptionsObject operation - send a CORS preflight request for an object in OBS.
+
+use crate::client::Client;
+use crate::error::{ObsError, Result};
+use crate::operations::{CorsHeaders, CorsMethod, Preflight};
+
+/// Fluent builder for the OptionsObject operation.
+///
+/// Sends the preflight request a browser would send before a cross-origin
+/// request to the object. OBS answers with an error if no CORS rule of the
+/// bucket allows it.
+#[derive(Debug, Clone)]
+pub struct OptionsObjectFluentBuilder {
+    client: Client,
+    inner: OptionsObjectInput,
+}
+
+impl OptionsObjectFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: OptionsObjectInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Set the object key.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.inner.key = key.into();
+        self
+    }
+
+    /// Set the origin of the cross-origin request.
+    pub fn origin(mut self, origin: impl Into<String>) -> Self {
+        self.inner.preflight.origin = origin.into();
+        self
+    }
+
+    /// Set the method of the cross-origin request.
+    pub fn request_method(mut self, method: CorsMethod) -> Self {
+        self.inner.preflight.method = Some(method);
+        self
+    }
+
+    /// Add a header that the cross-origin request would send.
+    pub fn request_header(mut self, header: impl Into<String>) -> Self {
+        self.inner.preflight.headers.push(header.into());
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<OptionsObjectOutput> {
+        let bucket = &self.inner.bucket;
+        let key = &self.inner.key;
+
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+        if key.is_empty() {
+            return Err(ObsError::InvalidInput("object key is required".to_string()));
+        }
+
+        let cors = self
+            .inner
+            .preflight
+            .send(&self.client, bucket, Some(key))
+            .await?;
+        Ok(OptionsObjectOutput { cors })
+    }
+}
+
+/// Input for the OptionsObject operation.
+#[derive(Debug, Clone, Default)]
+pub struct OptionsObjectInput {
+    bucket: String,
+    key: String,
+    preflight: Preflight,
+}
+
+/// Output for the OptionsObject operation.
+#[derive(Debug, Clone)]
+pub struct OptionsObjectOutput {
+    cors: CorsHeaders,
+}
+
+impl OptionsObjectOutput {
+    /// Get the CORS settings that allow the request.
+    pub fn cors(&self) -> &CorsHeaders {
+        &self.cors
+    }
+}
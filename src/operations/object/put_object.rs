@@ -7,8 +7,10 @@ use reqwest::{
     Body, Method,
 };
 
+use super::{CannedAcl, GrantHeaders, TagSet};
 use crate::client::Client;
 use crate::error::{ObsError, Result};
+use crate::progress::{ProgressListener, ProgressTracker};
 
 /// Fluent builder for the PutObject operation.
 pub struct PutObjectFluentBuilder {
@@ -73,6 +75,18 @@ impl PutObjectFluentBuilder {
         self
     }
 
+    /// Set a canned ACL (predefined ACL).
+    pub fn canned_acl(mut self, acl: CannedAcl) -> Self {
+        self.inner.acl = Some(acl);
+        self
+    }
+
+    /// Set the grants of the object, sent as `x-obs-grant-*` headers.
+    pub fn grants(mut self, grants: GrantHeaders) -> Self {
+        self.inner.grants = grants;
+        self
+    }
+
     /// Set the storage class.
     pub fn storage_class(mut self, storage_class: impl Into<String>) -> Self {
         self.inner.storage_class = Some(storage_class.into());
@@ -85,12 +99,47 @@ impl PutObjectFluentBuilder {
         self
     }
 
+    /// Set the website redirect location.
+    ///
+    /// When the bucket hosts a static website, requests for the object are
+    /// redirected to this object in the same bucket or to an external URL.
+    pub fn website_redirect_location(mut self, location: impl Into<String>) -> Self {
+        self.inner.website_redirect_location = Some(location.into());
+        self
+    }
+
+    /// Set the object tagging.
+    ///
+    /// Format: TagA=A&TagB&TagC
+    pub fn tagging(mut self, tagging: impl Into<String>) -> Self {
+        self.inner.tagging = Some(tagging.into());
+        self
+    }
+
+    /// Set the object tags from a tag set.
+    pub fn tag_set(mut self, tag_set: TagSet) -> Self {
+        self.inner.tagging = Some(tag_set.to_header_value());
+        self
+    }
+
     /// Set the content length (required for streaming uploads).
     pub fn content_length(mut self, content_length: u64) -> Self {
         self.inner.content_length = Some(content_length);
         self
     }
 
+    /// Set a listener that is notified as the body is uploaded.
+    pub fn progress_listener(mut self, listener: impl ProgressListener + 'static) -> Self {
+        self.inner.progress = Some(ProgressTracker::new(listener));
+        self
+    }
+
+    /// Report upload progress to an existing tracker.
+    pub(crate) fn progress_tracker(mut self, tracker: ProgressTracker) -> Self {
+        self.inner.progress = Some(tracker);
+        self
+    }
+
     /// Send the request.
     pub async fn send(self) -> Result<PutObjectOutput> {
         let bucket = &self.inner.bucket;
@@ -114,7 +163,13 @@ impl PutObjectFluentBuilder {
                     "Content-Length",
                     HeaderValue::from_str(&bytes.len().to_string()).unwrap(),
                 );
-                Some(Body::from(bytes))
+                match self.inner.progress {
+                    Some(ref tracker) => {
+                        tracker.set_total(bytes.len() as u64);
+                        Some(tracker.bytes_body(bytes))
+                    }
+                    None => Some(Body::from(bytes)),
+                }
             }
             Some(PutObjectBody::Stream(stream)) => {
                 // For streaming, content_length must be set
@@ -123,12 +178,18 @@ impl PutObjectFluentBuilder {
                         "Content-Length",
                         HeaderValue::from_str(&content_length.to_string()).unwrap(),
                     );
+                    if let Some(ref tracker) = self.inner.progress {
+                        tracker.set_total(content_length);
+                    }
                 } else {
                     return Err(ObsError::InvalidInput(
                         "content_length is required for streaming uploads".to_string(),
                     ));
                 }
-                Some(stream)
+                match self.inner.progress {
+                    Some(ref tracker) => Some(tracker.stream_body(stream)),
+                    None => Some(stream),
+                }
             }
             None => {
                 headers.insert("Content-Length", HeaderValue::from_str("0").unwrap());
@@ -168,6 +229,28 @@ impl PutObjectFluentBuilder {
             );
         }
 
+        if let Some(acl) = self.inner.acl {
+            headers.insert("x-obs-acl", HeaderValue::from_static(acl.as_str()));
+        }
+        self.inner.grants.apply(&mut headers)?;
+
+        if let Some(ref tagging) = self.inner.tagging {
+            headers.insert(
+                "x-obs-tagging",
+                HeaderValue::from_str(tagging)
+                    .map_err(|e| ObsError::InvalidInput(format!("Invalid tagging: {}", e)))?,
+            );
+        }
+
+        if let Some(ref location) = self.inner.website_redirect_location {
+            headers.insert(
+                "x-obs-website-redirect-location",
+                HeaderValue::from_str(location).map_err(|e| {
+                    ObsError::InvalidInput(format!("Invalid website redirect location: {}", e))
+                })?,
+            );
+        }
+
         if let Some(ref metadata) = self.inner.metadata {
             for (k, v) in metadata {
                 let header_name: reqwest::header::HeaderName =
@@ -206,7 +289,16 @@ impl PutObjectFluentBuilder {
             .and_then(|v| v.to_str().ok())
             .map(|s| s.to_string());
 
-        Ok(PutObjectOutput { etag, request_id })
+        let version_id = response_headers
+            .get("x-obs-version-id")
+            .and_then(|v| v.to_str().ok())
+            .map(|s| s.to_string());
+
+        Ok(PutObjectOutput {
+            etag,
+            request_id,
+            version_id,
+        })
     }
 }
 
@@ -247,8 +339,13 @@ pub struct PutObjectInput {
     content_disposition: Option<String>,
     cache_control: Option<String>,
     storage_class: Option<String>,
+    acl: Option<CannedAcl>,
+    grants: GrantHeaders,
     metadata: Option<HashMap<String, String>>,
+    website_redirect_location: Option<String>,
+    tagging: Option<String>,
     content_length: Option<u64>,
+    progress: Option<ProgressTracker>,
 }
 
 /// Output for the PutObject operation.
@@ -256,6 +353,7 @@ pub struct PutObjectInput {
 pub struct PutObjectOutput {
     etag: Option<String>,
     request_id: Option<String>,
+    version_id: Option<String>,
 }
 
 impl PutObjectOutput {
@@ -268,4 +366,9 @@ impl PutObjectOutput {
     pub fn request_id(&self) -> Option<&str> {
         self.request_id.as_deref()
     }
+
+    /// Get the version ID (only returned when versioning is enabled).
+    pub fn version_id(&self) -> Option<&str> {
+        self.version_id.as_deref()
+    }
 }
@@ -0,0 +1,311 @@
+//! PutObjectTagging operation - set tags for an object in OBS.
+
+use std::collections::HashMap;
+
+use reqwest::{
+    header::{HeaderMap, HeaderValue},
+    Method,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+use crate::error::{ObsError, Result};
+
+/// Fluent builder for the PutObjectTagging operation.
+#[derive(Debug, Clone)]
+pub struct PutObjectTaggingFluentBuilder {
+    client: Client,
+    inner: PutObjectTaggingInput,
+}
+
+impl PutObjectTaggingFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: PutObjectTaggingInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Set the object key.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.inner.key = key.into();
+        self
+    }
+
+    /// Set the version ID.
+    pub fn version_id(mut self, version_id: impl Into<String>) -> Self {
+        self.inner.version_id = Some(version_id.into());
+        self
+    }
+
+    /// Add a tag.
+    pub fn tag(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.inner.tag_set = self.inner.tag_set.tag(key, value);
+        self
+    }
+
+    /// Set all tags, replacing any tags added before.
+    pub fn tag_set(mut self, tag_set: TagSet) -> Self {
+        self.inner.tag_set = tag_set;
+        self
+    }
+
+    /// Send the request.
+    ///
+    /// The tags replace all existing tags of the object.
+    pub async fn send(&self) -> Result<PutObjectTaggingOutput> {
+        let bucket = &self.inner.bucket;
+        let key = &self.inner.key;
+
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+        if key.is_empty() {
+            return Err(ObsError::InvalidInput("object key is required".to_string()));
+        }
+        self.inner.tag_set.validate()?;
+
+        let mut params = HashMap::new();
+        params.insert("tagging".to_string(), String::new());
+
+        if let Some(ref version_id) = self.inner.version_id {
+            params.insert("versionId".to_string(), version_id.clone());
+        }
+
+        let tagging = Tagging {
+            tag_set: self.inner.tag_set.clone(),
+        };
+        let body = crate::xml_utils::to_xml(&tagging)?;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "Content-MD5",
+            crate::xml_utils::content_md5(body.as_bytes()),
+        );
+        headers.insert(
+            "Content-Length",
+            HeaderValue::from_str(&body.len().to_string()).unwrap(),
+        );
+
+        let resp = self
+            .client
+            .do_request(
+                Method::PUT,
+                Some(bucket),
+                Some(key),
+                Some(headers),
+                Some(params),
+                Some(body.into_bytes()),
+            )
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await?;
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        let version_id = resp
+            .headers()
+            .get("x-obs-version-id")
+            .and_then(|v| v.to_str().ok())
+            .map(|s| s.to_string());
+
+        Ok(PutObjectTaggingOutput { version_id })
+    }
+}
+
+/// Input for the PutObjectTagging operation.
+#[derive(Debug, Clone, Default)]
+pub struct PutObjectTaggingInput {
+    bucket: String,
+    key: String,
+    version_id: Option<String>,
+    tag_set: TagSet,
+}
+
+/// Output for the PutObjectTagging operation.
+#[derive(Debug, Clone)]
+pub struct PutObjectTaggingOutput {
+    version_id: Option<String>,
+}
+
+impl PutObjectTaggingOutput {
+    /// Get the version ID.
+    pub fn version_id(&self) -> Option<&str> {
+        self.version_id.as_deref()
+    }
+}
+
+/// Maximum number of tags on a single object.
+const MAX_TAGS: usize = 10;
+
+/// A set of object tags.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagSet {
+    #[serde(rename = "Tag", default)]
+    tags: Vec<Tag>,
+}
+
+impl TagSet {
+    /// Create an empty tag set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a tag, replacing the value if the key already exists.
+    pub fn tag(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        let key = key.into();
+        let value = value.into();
+        match self.tags.iter_mut().find(|t| t.key == key) {
+            Some(tag) => tag.value = value,
+            None => self.tags.push(Tag { key, value }),
+        }
+        self
+    }
+
+    /// Get the tags.
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+
+    /// Get the value of a tag by key.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|t| t.key == key)
+            .map(|t| t.value.as_str())
+    }
+
+    /// Check if the tag set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    /// Get the number of tags.
+    pub fn len(&self) -> usize {
+        self.tags.len()
+    }
+
+    /// Render the tags in the `x-obs-tagging` header format (`TagA=A&TagB=B`).
+    pub fn to_header_value(&self) -> String {
+        self.tags
+            .iter()
+            .map(|t| {
+                format!(
+                    "{}={}",
+                    urlencoding::encode(&t.key),
+                    urlencoding::encode(&t.value)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
+    /// Check the tag limits enforced by OBS.
+    pub(crate) fn validate(&self) -> Result<()> {
+        if self.tags.len() > MAX_TAGS {
+            return Err(ObsError::InvalidInput(format!(
+                "maximum {} tags are allowed on an object",
+                MAX_TAGS
+            )));
+        }
+        for tag in &self.tags {
+            if tag.key.is_empty() || tag.key.chars().count() > 128 {
+                return Err(ObsError::InvalidInput(
+                    "tag key must be 1 to 128 characters".to_string(),
+                ));
+            }
+            if tag.value.chars().count() > 255 {
+                return Err(ObsError::InvalidInput(
+                    "tag value must be at most 255 characters".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for TagSet {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(TagSet::new(), |set, (k, v)| set.tag(k, v))
+    }
+}
+
+/// A single object tag.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Tag {
+    #[serde(rename = "Key")]
+    key: String,
+    #[serde(rename = "Value")]
+    value: String,
+}
+
+impl Tag {
+    /// Create a new tag.
+    pub fn new(key: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Get the tag key.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Get the tag value.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+/// Tagging document used by the tagging APIs.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "Tagging")]
+pub(crate) struct Tagging {
+    #[serde(rename = "TagSet", default)]
+    pub(crate) tag_set: TagSet,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tagging_xml_round_trip() {
+        let tagging = Tagging {
+            tag_set: TagSet::new().tag("project", "lake").tag("tier", "hot"),
+        };
+        let xml = crate::xml_utils::to_xml(&tagging).unwrap();
+        assert_eq!(
+            xml,
+            "<Tagging><TagSet><Tag><Key>project</Key><Value>lake</Value></Tag>\
+             <Tag><Key>tier</Key><Value>hot</Value></Tag></TagSet></Tagging>"
+        );
+
+        let parsed: Tagging = crate::xml_utils::from_xml(&xml).unwrap();
+        assert_eq!(parsed.tag_set, tagging.tag_set);
+
+        let empty: Tagging = crate::xml_utils::from_xml("<Tagging><TagSet/></Tagging>").unwrap();
+        assert!(empty.tag_set.is_empty());
+    }
+
+    #[test]
+    fn test_tag_set_header_value() {
+        let tag_set: TagSet = [("a b", "1&2"), ("c", "")].into_iter().collect();
+        assert_eq!(tag_set.to_header_value(), "a%20b=1%262&c=");
+        assert_eq!(tag_set.get("c"), Some(""));
+    }
+}
@@ -0,0 +1,376 @@
+//! RestoreObject operation - restore an archived (Cold/Deep Archive) object in OBS.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use reqwest::{
+    header::{HeaderMap, HeaderValue},
+    Method, StatusCode,
+};
+use serde::Serialize;
+
+use super::HeadObjectOutput;
+use crate::client::Client;
+use crate::error::{ObsError, Result};
+
+/// Fluent builder for the RestoreObject operation.
+///
+/// Archived objects must be restored before they can be downloaded.
+#[derive(Debug, Clone)]
+pub struct RestoreObjectFluentBuilder {
+    client: Client,
+    inner: RestoreObjectInput,
+}
+
+impl RestoreObjectFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: RestoreObjectInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Set the object key.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.inner.key = key.into();
+        self
+    }
+
+    /// Set the version ID.
+    pub fn version_id(mut self, version_id: impl Into<String>) -> Self {
+        self.inner.version_id = Some(version_id.into());
+        self
+    }
+
+    /// Set how many days the restored copy is kept (1-30).
+    pub fn days(mut self, days: u32) -> Self {
+        self.inner.days = days;
+        self
+    }
+
+    /// Set the restore tier.
+    ///
+    /// Possible values: Expedited, Standard, Bulk.
+    /// Expedited is not available for Deep Archive objects.
+    pub fn tier(mut self, tier: impl Into<String>) -> Self {
+        self.inner.tier = Some(tier.into());
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<RestoreObjectOutput> {
+        let bucket = &self.inner.bucket;
+        let key = &self.inner.key;
+
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+        if key.is_empty() {
+            return Err(ObsError::InvalidInput("object key is required".to_string()));
+        }
+        if self.inner.days < 1 || self.inner.days > 30 {
+            return Err(ObsError::InvalidInput(
+                "restore days must be between 1 and 30".to_string(),
+            ));
+        }
+
+        let mut params = HashMap::new();
+        params.insert("restore".to_string(), String::new());
+
+        if let Some(ref version_id) = self.inner.version_id {
+            params.insert("versionId".to_string(), version_id.clone());
+        }
+
+        let request = RestoreRequest {
+            days: self.inner.days,
+            restore_job: self.inner.tier.clone().map(|tier| RestoreJob { tier }),
+        };
+
+        let body = crate::xml_utils::to_xml(&request)?;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "Content-MD5",
+            crate::xml_utils::content_md5(body.as_bytes()),
+        );
+        headers.insert(
+            "Content-Length",
+            HeaderValue::from_str(&body.len().to_string()).unwrap(),
+        );
+
+        let resp = self
+            .client
+            .do_request(
+                Method::POST,
+                Some(bucket),
+                Some(key),
+                Some(headers),
+                Some(params),
+                Some(body.into_bytes()),
+            )
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await?;
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        // 202 Accepted: restore started; 200 OK: object already restored
+        Ok(RestoreObjectOutput {
+            already_restored: status == StatusCode::OK,
+        })
+    }
+}
+
+/// Input for the RestoreObject operation.
+#[derive(Debug, Clone)]
+pub struct RestoreObjectInput {
+    bucket: String,
+    key: String,
+    version_id: Option<String>,
+    days: u32,
+    tier: Option<String>,
+}
+
+impl Default for RestoreObjectInput {
+    fn default() -> Self {
+        Self {
+            bucket: String::new(),
+            key: String::new(),
+            version_id: None,
+            days: 1,
+            tier: None,
+        }
+    }
+}
+
+/// Output for the RestoreObject operation.
+#[derive(Debug, Clone)]
+pub struct RestoreObjectOutput {
+    already_restored: bool,
+}
+
+impl RestoreObjectOutput {
+    /// Check if the object had already been restored before this request.
+    ///
+    /// When false, the restore job has been accepted and is in progress.
+    pub fn already_restored(&self) -> bool {
+        self.already_restored
+    }
+}
+
+/// Restore status of an archived object, parsed from the `x-obs-restore` header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RestoreStatus {
+    ongoing: bool,
+    expiry_date: Option<String>,
+}
+
+impl RestoreStatus {
+    /// Parse the value of the `x-obs-restore` header.
+    ///
+    /// Format: `ongoing-request="false", expiry-date="Wed, 7 Nov 2012 00:00:00 GMT"`
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        let mut ongoing = None;
+        let mut expiry_date = None;
+
+        let mut rest = value.trim();
+        while !rest.is_empty() {
+            let (name, after_name) = rest.split_once('=')?;
+            let after_name = after_name.trim_start();
+            let (field, remaining) = match after_name.strip_prefix('"') {
+                Some(quoted) => {
+                    let end = quoted.find('"')?;
+                    (&quoted[..end], &quoted[end + 1..])
+                }
+                None => after_name.split_once(',').unwrap_or((after_name, "")),
+            };
+
+            match name.trim().to_ascii_lowercase().as_str() {
+                "ongoing-request" => ongoing = Some(field.trim() == "true"),
+                "expiry-date" => expiry_date = Some(field.trim().to_string()),
+                _ => {}
+            }
+
+            rest = remaining.trim_start().trim_start_matches(',').trim_start();
+        }
+
+        Some(Self {
+            ongoing: ongoing?,
+            expiry_date,
+        })
+    }
+
+    /// Check if the restore job is still in progress.
+    pub fn is_ongoing(&self) -> bool {
+        self.ongoing
+    }
+
+    /// Check if the restored copy is available for download.
+    pub fn is_restored(&self) -> bool {
+        !self.ongoing
+    }
+
+    /// Get the time when the restored copy expires.
+    pub fn expiry_date(&self) -> Option<&str> {
+        self.expiry_date.as_deref()
+    }
+}
+
+/// Fluent builder that polls HeadObject until a restore job completes.
+#[derive(Debug, Clone)]
+pub struct WaitUntilRestoredFluentBuilder {
+    client: Client,
+    bucket: String,
+    key: String,
+    version_id: Option<String>,
+    poll_interval: Duration,
+    timeout: Option<Duration>,
+}
+
+impl WaitUntilRestoredFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            bucket: String::new(),
+            key: String::new(),
+            version_id: None,
+            poll_interval: Duration::from_secs(60),
+            timeout: None,
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.bucket = bucket.into();
+        self
+    }
+
+    /// Set the object key.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key = key.into();
+        self
+    }
+
+    /// Set the version ID.
+    pub fn version_id(mut self, version_id: impl Into<String>) -> Self {
+        self.version_id = Some(version_id.into());
+        self
+    }
+
+    /// Set the interval between two HeadObject requests (default: 60 seconds).
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Set the maximum time to wait (default: wait forever).
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Poll until the object is restored and return its final metadata.
+    pub async fn send(&self) -> Result<HeadObjectOutput> {
+        let started = tokio::time::Instant::now();
+
+        loop {
+            let mut head = self
+                .client
+                .head_object()
+                .bucket(&self.bucket)
+                .key(&self.key);
+            if let Some(ref version_id) = self.version_id {
+                head = head.version_id(version_id);
+            }
+            let output = head.send().await?;
+
+            match output.restore() {
+                Some(status) if status.is_restored() => return Ok(output),
+                Some(_) => {}
+                None => {
+                    // Objects that are not archived can be read directly.
+                    return match output.storage_class_kind() {
+                        Some(class) if class.is_archive() => Err(ObsError::InvalidInput(format!(
+                            "no restore in progress for object {}",
+                            self.key
+                        ))),
+                        _ => Ok(output),
+                    };
+                }
+            }
+
+            if let Some(timeout) = self.timeout {
+                if started.elapsed() + self.poll_interval > timeout {
+                    return Err(ObsError::Timeout(format!(
+                        "object {} was not restored within {:?}",
+                        self.key, timeout
+                    )));
+                }
+            }
+
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename = "RestoreRequest")]
+struct RestoreRequest {
+    #[serde(rename = "Days")]
+    days: u32,
+    #[serde(rename = "RestoreJob", skip_serializing_if = "Option::is_none")]
+    restore_job: Option<RestoreJob>,
+}
+
+#[derive(Debug, Serialize)]
+struct RestoreJob {
+    #[serde(rename = "Tier")]
+    tier: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_restore_status() {
+        let status = RestoreStatus::parse(
+            r#"ongoing-request="false", expiry-date="Wed, 7 Nov 2012 00:00:00 GMT""#,
+        )
+        .unwrap();
+        assert!(status.is_restored());
+        assert_eq!(status.expiry_date(), Some("Wed, 7 Nov 2012 00:00:00 GMT"));
+
+        let status = RestoreStatus::parse(r#"ongoing-request="true""#).unwrap();
+        assert!(status.is_ongoing());
+        assert_eq!(status.expiry_date(), None);
+
+        assert!(RestoreStatus::parse("garbage").is_none());
+    }
+
+    #[test]
+    fn test_restore_request_xml() {
+        let request = RestoreRequest {
+            days: 3,
+            restore_job: Some(RestoreJob {
+                tier: "Expedited".to_string(),
+            }),
+        };
+        assert_eq!(
+            crate::xml_utils::to_xml(&request).unwrap(),
+            "<RestoreRequest><Days>3</Days><RestoreJob><Tier>Expedited</Tier></RestoreJob></RestoreRequest>"
+        );
+    }
+}
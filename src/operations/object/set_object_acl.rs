@@ -5,6 +5,7 @@ use std::collections::HashMap;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
+use super::CannedAcl;
 use crate::client::Client;
 use crate::error::{ObsError, Result};
 
@@ -66,13 +67,26 @@ impl SetObjectAclFluentBuilder {
         self
     }
 
+    /// Set the whole access control policy, replacing the owner, delivered flag and grants.
+    pub fn access_control_policy(mut self, policy: AccessControlPolicy) -> Self {
+        self.inner.acl = policy;
+        self
+    }
+
     /// Set a canned ACL (predefined ACL).
     /// Common values: "private", "public-read", "public-read-write".
+    #[deprecated(note = "use `acl` with a `CannedAcl` instead")]
     pub fn canned_acl(mut self, canned_acl: impl Into<String>) -> Self {
         self.inner.canned_acl = Some(canned_acl.into());
         self
     }
 
+    /// Set a canned ACL (predefined ACL) instead of an explicit grant list.
+    pub fn acl(mut self, acl: CannedAcl) -> Self {
+        self.inner.canned_acl = Some(acl.to_string());
+        self
+    }
+
     /// Send the request.
     pub async fn send(&self) -> Result<SetObjectAclOutput> {
         let bucket = &self.inner.bucket;
@@ -98,10 +112,10 @@ impl SetObjectAclFluentBuilder {
 
         // If canned ACL is set, use x-obs-acl header
         if let Some(ref canned_acl) = self.inner.canned_acl {
+            let canned_acl: CannedAcl = canned_acl.parse()?;
             headers.insert(
                 "x-obs-acl",
-                reqwest::header::HeaderValue::from_str(canned_acl)
-                    .map_err(|e| ObsError::InvalidInput(format!("Invalid canned ACL: {}", e)))?,
+                reqwest::header::HeaderValue::from_static(canned_acl.as_str()),
             );
         }
 
@@ -173,7 +187,11 @@ pub struct AccessControlPolicy {
     #[serde(rename = "Owner")]
     pub owner: AclOwner,
     /// Whether the object ACL inherits from the bucket ACL.
-    #[serde(rename = "Delivered", skip_serializing_if = "std::ops::Not::not")]
+    #[serde(
+        rename = "Delivered",
+        default,
+        skip_serializing_if = "std::ops::Not::not"
+    )]
     pub delivered: bool,
     /// Access control list.
     #[serde(rename = "AccessControlList")]
@@ -199,12 +217,12 @@ impl AclOwner {
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AccessControlList {
     /// List of grants.
-    #[serde(rename = "Grant")]
+    #[serde(rename = "Grant", default)]
     pub grants: Vec<Grant>,
 }
 
 /// A grant in the ACL.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Grant {
     /// Grantee information.
     #[serde(rename = "Grantee")]
@@ -235,7 +253,7 @@ impl Grant {
 }
 
 /// Grantee information.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Grantee {
     /// Grantee's domain ID (for specific user).
     #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
@@ -279,7 +297,7 @@ impl Grantee {
 }
 
 /// Canned grantee types.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CannedGrantee {
     /// Everyone (public access).
     #[serde(rename = "Everyone")]
@@ -287,7 +305,7 @@ pub enum CannedGrantee {
 }
 
 /// Permission types for object ACL.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Permission {
     /// Read access to the object.
     #[serde(rename = "READ")]
@@ -315,6 +333,7 @@ impl std::fmt::Display for Permission {
 }
 
 /// Canned ACL constants.
+#[deprecated(note = "use `CannedAcl` instead")]
 pub mod canned_acl {
     /// Private (owner gets full control, no one else has access).
     pub const PRIVATE: &str = "private";
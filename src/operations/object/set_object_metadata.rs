@@ -0,0 +1,267 @@
+//! SetObjectMetadata operation - modify object metadata in place without rewriting data.
+
+use std::collections::HashMap;
+
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Method,
+};
+
+use crate::client::Client;
+use crate::error::{ObsError, Result};
+use crate::operations::StorageClass;
+
+/// Fluent builder for the SetObjectMetadata operation.
+#[derive(Debug, Clone)]
+pub struct SetObjectMetadataFluentBuilder {
+    client: Client,
+    inner: SetObjectMetadataInput,
+}
+
+impl SetObjectMetadataFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: SetObjectMetadataInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Set the object key.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.inner.key = key.into();
+        self
+    }
+
+    /// Set the version ID.
+    pub fn version_id(mut self, version_id: impl Into<String>) -> Self {
+        self.inner.version_id = Some(version_id.into());
+        self
+    }
+
+    /// Set the metadata directive (required).
+    ///
+    /// Possible values:
+    /// - REPLACE_NEW: replace the metadata given in this request, add new
+    ///   metadata and keep the metadata that is not specified.
+    /// - REPLACE: replace all metadata with the metadata given in this
+    ///   request; metadata that is not specified is deleted.
+    pub fn metadata_directive(mut self, directive: impl Into<String>) -> Self {
+        self.inner.metadata_directive = Some(directive.into());
+        self
+    }
+
+    /// Set the content type.
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.inner.content_type = Some(content_type.into());
+        self
+    }
+
+    /// Set the content encoding.
+    pub fn content_encoding(mut self, content_encoding: impl Into<String>) -> Self {
+        self.inner.content_encoding = Some(content_encoding.into());
+        self
+    }
+
+    /// Set the content disposition.
+    pub fn content_disposition(mut self, content_disposition: impl Into<String>) -> Self {
+        self.inner.content_disposition = Some(content_disposition.into());
+        self
+    }
+
+    /// Set the content language.
+    pub fn content_language(mut self, content_language: impl Into<String>) -> Self {
+        self.inner.content_language = Some(content_language.into());
+        self
+    }
+
+    /// Set the cache control.
+    pub fn cache_control(mut self, cache_control: impl Into<String>) -> Self {
+        self.inner.cache_control = Some(cache_control.into());
+        self
+    }
+
+    /// Set the `Expires` header returned when the object is downloaded.
+    pub fn expires(mut self, expires: impl Into<String>) -> Self {
+        self.inner.expires = Some(expires.into());
+        self
+    }
+
+    /// Set the website redirect location.
+    pub fn website_redirect_location(mut self, location: impl Into<String>) -> Self {
+        self.inner.website_redirect_location = Some(location.into());
+        self
+    }
+
+    /// Set the storage class.
+    ///
+    /// Possible values: STANDARD, WARM, COLD, DEEP_ARCHIVE.
+    pub fn storage_class(mut self, storage_class: impl Into<String>) -> Self {
+        self.inner.storage_class = Some(storage_class.into());
+        self
+    }
+
+    /// Add a custom metadata header.
+    ///
+    /// The key should not include the "x-obs-meta-" prefix.
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.inner.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<SetObjectMetadataOutput> {
+        let bucket = &self.inner.bucket;
+        let key = &self.inner.key;
+
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+        if key.is_empty() {
+            return Err(ObsError::InvalidInput("object key is required".to_string()));
+        }
+        let directive =
+            self.inner.metadata_directive.as_deref().ok_or_else(|| {
+                ObsError::InvalidInput("metadata directive is required".to_string())
+            })?;
+        if directive != "REPLACE_NEW" && directive != "REPLACE" {
+            return Err(ObsError::InvalidInput(format!(
+                "metadata directive must be REPLACE_NEW or REPLACE, got {}",
+                directive
+            )));
+        }
+
+        let mut params = HashMap::new();
+        params.insert("metadata".to_string(), String::new());
+
+        if let Some(ref version_id) = self.inner.version_id {
+            params.insert("versionId".to_string(), version_id.clone());
+        }
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-obs-metadata-directive",
+            HeaderValue::from_str(directive).unwrap(),
+        );
+
+        let optional_headers = [
+            ("Content-Type", &self.inner.content_type),
+            ("Content-Encoding", &self.inner.content_encoding),
+            ("Content-Disposition", &self.inner.content_disposition),
+            ("Content-Language", &self.inner.content_language),
+            ("Cache-Control", &self.inner.cache_control),
+            ("Expires", &self.inner.expires),
+            (
+                "x-obs-website-redirect-location",
+                &self.inner.website_redirect_location,
+            ),
+            ("x-obs-storage-class", &self.inner.storage_class),
+        ];
+
+        for (name, value) in optional_headers {
+            if let Some(value) = value {
+                headers.insert(
+                    name,
+                    HeaderValue::from_str(value).map_err(|e| {
+                        ObsError::InvalidInput(format!("Invalid {} header: {}", name, e))
+                    })?,
+                );
+            }
+        }
+
+        // Custom metadata
+        for (k, v) in &self.inner.metadata {
+            let name: HeaderName = format!("x-obs-meta-{}", k)
+                .parse()
+                .map_err(|e| ObsError::InvalidInput(format!("Invalid metadata key: {}", e)))?;
+            let value = HeaderValue::from_str(v)
+                .map_err(|e| ObsError::InvalidInput(format!("Invalid metadata value: {}", e)))?;
+            headers.insert(name, value);
+        }
+
+        let resp = self
+            .client
+            .do_request(
+                Method::PUT,
+                Some(bucket),
+                Some(key),
+                Some(headers),
+                Some(params),
+                None,
+            )
+            .await?;
+
+        let status = resp.status();
+        let response_headers = resp.headers().clone();
+
+        if !status.is_success() {
+            let text = resp.text().await?;
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        let version_id = response_headers
+            .get("x-obs-version-id")
+            .and_then(|v| v.to_str().ok())
+            .map(|s| s.to_string());
+
+        let storage_class = response_headers
+            .get("x-obs-storage-class")
+            .and_then(|v| v.to_str().ok())
+            .map(|s| s.to_string());
+
+        Ok(SetObjectMetadataOutput {
+            version_id,
+            storage_class,
+        })
+    }
+}
+
+/// Input for the SetObjectMetadata operation.
+#[derive(Debug, Clone, Default)]
+pub struct SetObjectMetadataInput {
+    bucket: String,
+    key: String,
+    version_id: Option<String>,
+    metadata_directive: Option<String>,
+    content_type: Option<String>,
+    content_encoding: Option<String>,
+    content_disposition: Option<String>,
+    content_language: Option<String>,
+    cache_control: Option<String>,
+    expires: Option<String>,
+    website_redirect_location: Option<String>,
+    storage_class: Option<String>,
+    metadata: HashMap<String, String>,
+}
+
+/// Output for the SetObjectMetadata operation.
+#[derive(Debug, Clone)]
+pub struct SetObjectMetadataOutput {
+    version_id: Option<String>,
+    storage_class: Option<String>,
+}
+
+impl SetObjectMetadataOutput {
+    /// Get the version ID.
+    pub fn version_id(&self) -> Option<&str> {
+        self.version_id.as_deref()
+    }
+
+    /// Get the storage class after the change.
+    pub fn storage_class(&self) -> Option<&str> {
+        self.storage_class.as_deref()
+    }
+
+    /// Get the storage class as a [`StorageClass`].
+    pub fn storage_class_kind(&self) -> Option<StorageClass> {
+        self.storage_class.as_deref().map(StorageClass::from)
+    }
+}
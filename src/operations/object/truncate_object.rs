@@ -0,0 +1,100 @@
+//! TruncateObject operation - truncate an object in a parallel file system bucket.
+
+use std::collections::HashMap;
+
+use reqwest::Method;
+
+use crate::client::Client;
+use crate::error::{ObsError, Result};
+
+/// Fluent builder for the TruncateObject operation.
+///
+/// This operation is only supported by parallel file system (PFS) buckets.
+#[derive(Debug, Clone)]
+pub struct TruncateObjectFluentBuilder {
+    client: Client,
+    inner: TruncateObjectInput,
+}
+
+impl TruncateObjectFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: TruncateObjectInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Set the object key.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.inner.key = key.into();
+        self
+    }
+
+    /// Set the length in bytes the object is truncated to.
+    pub fn length(mut self, length: u64) -> Self {
+        self.inner.length = Some(length);
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(&self) -> Result<TruncateObjectOutput> {
+        let bucket = &self.inner.bucket;
+        let key = &self.inner.key;
+
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+        if key.is_empty() {
+            return Err(ObsError::InvalidInput("object key is required".to_string()));
+        }
+        let length = self
+            .inner
+            .length
+            .ok_or_else(|| ObsError::InvalidInput("length is required".to_string()))?;
+
+        let mut params = HashMap::new();
+        params.insert("truncate".to_string(), String::new());
+        params.insert("length".to_string(), length.to_string());
+
+        let resp = self
+            .client
+            .do_request(
+                Method::PUT,
+                Some(bucket),
+                Some(key),
+                None,
+                Some(params),
+                None,
+            )
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await?;
+            return Err(ObsError::service_error(status, &text));
+        }
+
+        Ok(TruncateObjectOutput {})
+    }
+}
+
+/// Input for the TruncateObject operation.
+#[derive(Debug, Clone, Default)]
+pub struct TruncateObjectInput {
+    bucket: String,
+    key: String,
+    length: Option<u64>,
+}
+
+/// Output for the TruncateObject operation.
+#[derive(Debug, Clone)]
+pub struct TruncateObjectOutput {}
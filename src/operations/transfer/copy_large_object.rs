@@ -0,0 +1,386 @@
+//! CopyLargeObject helper - server-side copy of objects of any size.
+
+use std::collections::HashMap;
+
+use futures::stream::{self, StreamExt, TryStreamExt};
+
+use super::{pick_part_size, with_retries};
+use crate::client::Client;
+use crate::error::{ObsError, Result};
+use crate::operations::multipart::{AbortGuard, CompletedPart};
+use crate::operations::object::encode_copy_source;
+use crate::progress::{ProgressListener, ProgressTracker};
+
+/// Default part size (64 MiB).
+const DEFAULT_PART_SIZE: u64 = 64 * 1024 * 1024;
+
+/// Default number of parts copied at the same time.
+const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Fluent builder for copying an object of any size.
+///
+/// CopyObject only accepts sources up to 5 GB. This helper looks up the
+/// source with HeadObject and, if it does not fit in a single part, copies it
+/// with a multipart upload whose parts are copied concurrently with
+/// CopyPart. Every part is pinned to the source ETag, so a source that
+/// changes during the copy fails the copy instead of mixing versions. On
+/// failure the multipart upload is aborted.
+///
+/// With the default `COPY` metadata directive the content headers
+/// (Content-Type, Content-Encoding, Content-Disposition, Content-Language,
+/// Cache-Control, Expires), the website redirect location and the custom
+/// metadata of the source are carried over, whether the object is copied in
+/// parts or not; with `REPLACE` the values set on this builder are used
+/// instead. Tags, ACLs and encryption settings of the source are not copied
+/// to objects copied in parts.
+#[derive(Debug, Clone)]
+pub struct CopyLargeObjectFluentBuilder {
+    client: Client,
+    inner: CopyLargeObjectInput,
+}
+
+impl CopyLargeObjectFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: CopyLargeObjectInput {
+                concurrency: DEFAULT_CONCURRENCY,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Set the destination bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Set the destination object key.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.inner.key = key.into();
+        self
+    }
+
+    /// Set the copy source (format: "source-bucket/source-key").
+    pub fn copy_source(mut self, copy_source: impl Into<String>) -> Self {
+        self.inner.copy_source = copy_source.into();
+        self
+    }
+
+    /// Set the version ID of the source object.
+    pub fn copy_source_version_id(mut self, version_id: impl Into<String>) -> Self {
+        self.inner.copy_source_version_id = Some(version_id.into());
+        self
+    }
+
+    /// Set the part size.
+    ///
+    /// By default 64 MiB is used, increased as needed to stay within 10000 parts.
+    pub fn part_size(mut self, part_size: u64) -> Self {
+        self.inner.part_size = Some(part_size);
+        self
+    }
+
+    /// Set how many parts are copied at the same time (default: 4).
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.inner.concurrency = concurrency;
+        self
+    }
+
+    /// Set the metadata directive.
+    ///
+    /// Possible values: COPY (default, keep the source metadata),
+    /// REPLACE (use the metadata supplied with this request).
+    pub fn metadata_directive(mut self, directive: impl Into<String>) -> Self {
+        self.inner.metadata_directive = Some(directive.into());
+        self
+    }
+
+    /// Set the content type (only used with the REPLACE directive).
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.inner.content_type = Some(content_type.into());
+        self
+    }
+
+    /// Add custom metadata (only used with the REPLACE directive).
+    ///
+    /// The key should not include the "x-obs-meta-" prefix.
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.inner.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set the storage class of the destination object.
+    ///
+    /// Possible values: STANDARD, WARM, COLD, DEEP_ARCHIVE
+    pub fn storage_class(mut self, storage_class: impl Into<String>) -> Self {
+        self.inner.storage_class = Some(storage_class.into());
+        self
+    }
+
+    /// Set how many times a failed part is retried (default: 0).
+    ///
+    /// Only network errors and throttling or server errors are retried.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.inner.max_retries = max_retries;
+        self
+    }
+
+    /// Set a listener that is notified of copied bytes, completed parts and retries.
+    pub fn progress_listener(mut self, listener: impl ProgressListener + 'static) -> Self {
+        self.inner.progress = Some(ProgressTracker::new(listener));
+        self
+    }
+
+    /// Copy the object.
+    pub async fn send(&self) -> Result<CopyLargeObjectOutput> {
+        let bucket = &self.inner.bucket;
+        let key = &self.inner.key;
+
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+        if key.is_empty() {
+            return Err(ObsError::InvalidInput("object key is required".to_string()));
+        }
+        let (source_bucket, source_key) = self
+            .inner
+            .copy_source
+            .trim_start_matches('/')
+            .split_once('/')
+            .filter(|(b, k)| !b.is_empty() && !k.is_empty())
+            .ok_or_else(|| {
+                ObsError::InvalidInput(
+                    "copy source must have the format \"bucket/key\"".to_string(),
+                )
+            })?;
+        let replace = match self.inner.metadata_directive.as_deref() {
+            None | Some("COPY") => false,
+            Some("REPLACE") => true,
+            Some(_) => {
+                return Err(ObsError::InvalidInput(
+                    "metadata directive must be COPY or REPLACE".to_string(),
+                ))
+            }
+        };
+        if self.inner.concurrency == 0 {
+            return Err(ObsError::InvalidInput(
+                "concurrency must be greater than zero".to_string(),
+            ));
+        }
+
+        let mut head = self
+            .client
+            .head_object()
+            .bucket(source_bucket)
+            .key(source_key);
+        if let Some(ref version_id) = self.inner.copy_source_version_id {
+            head = head.version_id(version_id);
+        }
+        let head = head.send().await?;
+        let size = head.content_length().ok_or_else(|| {
+            ObsError::Unknown(format!("object {} has no Content-Length", source_key))
+        })?;
+        let source_etag = head.etag().map(|s| s.to_string());
+        let part_size = pick_part_size(size, self.inner.part_size, DEFAULT_PART_SIZE)?;
+
+        if size <= part_size {
+            return self.copy_whole_object(size, source_etag).await;
+        }
+
+        let mut initiate = self
+            .client
+            .initiate_multipart_upload()
+            .bucket(bucket)
+            .key(key);
+        if replace {
+            if let Some(ref content_type) = self.inner.content_type {
+                initiate = initiate.content_type(content_type);
+            }
+            for (name, value) in &self.inner.metadata {
+                initiate = initiate.metadata(name, value);
+            }
+        } else {
+            if let Some(content_type) = head.content_type() {
+                initiate = initiate.content_type(content_type);
+            }
+            if let Some(content_encoding) = head.content_encoding() {
+                initiate = initiate.content_encoding(content_encoding);
+            }
+            if let Some(content_disposition) = head.content_disposition() {
+                initiate = initiate.content_disposition(content_disposition);
+            }
+            if let Some(content_language) = head.content_language() {
+                initiate = initiate.content_language(content_language);
+            }
+            if let Some(cache_control) = head.cache_control() {
+                initiate = initiate.cache_control(cache_control);
+            }
+            if let Some(expires) = head.expires() {
+                initiate = initiate.http_expires(expires);
+            }
+            if let Some(location) = head.website_redirect_location() {
+                initiate = initiate.website_redirect_location(location);
+            }
+            for (name, value) in head.metadata() {
+                initiate = initiate.metadata(name, value);
+            }
+        }
+        if let Some(ref storage_class) = self.inner.storage_class {
+            initiate = initiate.storage_class(storage_class);
+        }
+        let upload_id = initiate.send().await?.upload_id().to_string();
+        let guard = AbortGuard::new(self.client.clone(), bucket, key, &upload_id);
+
+        let mut copy_source = encode_copy_source(&self.inner.copy_source);
+        if let Some(ref version_id) = self.inner.copy_source_version_id {
+            copy_source.push_str("?versionId=");
+            copy_source.push_str(version_id);
+        }
+
+        let progress = self.inner.progress.as_ref();
+        if let Some(progress) = progress {
+            progress.set_total(size);
+        }
+
+        let part_count = size.div_ceil(part_size) as i32;
+        let (upload_id, copy_source, source_etag) = (&upload_id, &copy_source, &source_etag);
+        let mut parts: Vec<CompletedPart> = stream::iter(1..=part_count)
+            .map(|part| async move {
+                let start = (part as u64 - 1) * part_size;
+                let end = (start + part_size).min(size) - 1;
+                let output = with_retries(self.inner.max_retries, progress, part, |_| async {
+                    let mut request = self
+                        .client
+                        .copy_part()
+                        .bucket(bucket)
+                        .key(key)
+                        .upload_id(upload_id)
+                        .part_number(part)
+                        .copy_source(copy_source)
+                        .copy_source_range(format!("bytes={}-{}", start, end));
+                    if let Some(ref etag) = source_etag {
+                        request = request.copy_source_if_match(etag);
+                    }
+                    request.send().await
+                })
+                .await?;
+                if let Some(progress) = progress {
+                    progress.add(end - start + 1);
+                    progress.part_completed(part, end - start + 1);
+                }
+                Ok::<_, ObsError>(CompletedPart::new(part, output.etag()))
+            })
+            .buffer_unordered(self.inner.concurrency)
+            .try_collect()
+            .await?;
+        parts.sort_by_key(|p| p.part_number);
+
+        let output = self
+            .client
+            .complete_multipart_upload()
+            .bucket(bucket)
+            .key(key)
+            .upload_id(upload_id)
+            .parts(parts)
+            .send()
+            .await?;
+        guard.disarm();
+
+        Ok(CopyLargeObjectOutput {
+            size,
+            etag: output.etag().to_string(),
+            upload_id: Some(upload_id.clone()),
+        })
+    }
+
+    /// Copy an object that fits in a single part with CopyObject.
+    async fn copy_whole_object(
+        &self,
+        size: u64,
+        source_etag: Option<String>,
+    ) -> Result<CopyLargeObjectOutput> {
+        let mut request = self
+            .client
+            .copy_object()
+            .bucket(&self.inner.bucket)
+            .key(&self.inner.key)
+            .copy_source(&self.inner.copy_source);
+        if let Some(ref version_id) = self.inner.copy_source_version_id {
+            request = request.copy_source_version_id(version_id);
+        }
+        if let Some(ref etag) = source_etag {
+            request = request.copy_source_if_match(etag);
+        }
+        if let Some(ref directive) = self.inner.metadata_directive {
+            request = request.metadata_directive(directive);
+        }
+        if let Some(ref content_type) = self.inner.content_type {
+            request = request.content_type(content_type);
+        }
+        for (name, value) in &self.inner.metadata {
+            request = request.metadata(name, value);
+        }
+        if let Some(ref storage_class) = self.inner.storage_class {
+            request = request.storage_class(storage_class);
+        }
+
+        let output = request.send().await?;
+        if let Some(ref progress) = self.inner.progress {
+            progress.set_total(size);
+            progress.add(size);
+        }
+
+        Ok(CopyLargeObjectOutput {
+            size,
+            etag: output.etag().to_string(),
+            upload_id: None,
+        })
+    }
+}
+
+/// Input for the CopyLargeObject helper.
+#[derive(Debug, Clone, Default)]
+pub struct CopyLargeObjectInput {
+    bucket: String,
+    key: String,
+    copy_source: String,
+    copy_source_version_id: Option<String>,
+    part_size: Option<u64>,
+    concurrency: usize,
+    metadata_directive: Option<String>,
+    content_type: Option<String>,
+    metadata: HashMap<String, String>,
+    storage_class: Option<String>,
+    max_retries: u32,
+    progress: Option<ProgressTracker>,
+}
+
+/// Output for the CopyLargeObject helper.
+#[derive(Debug, Clone)]
+pub struct CopyLargeObjectOutput {
+    size: u64,
+    etag: String,
+    upload_id: Option<String>,
+}
+
+impl CopyLargeObjectOutput {
+    /// Get the size of the copied object.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Get the ETag of the new object.
+    pub fn etag(&self) -> &str {
+        &self.etag
+    }
+
+    /// Get the multipart upload ID (`None` if the object was copied with CopyObject).
+    pub fn upload_id(&self) -> Option<&str> {
+        self.upload_id.as_deref()
+    }
+}
@@ -0,0 +1,154 @@
+//! Transfer module - high-level helpers built on top of the object and multipart operations.
+//!
+//! This module provides the following helpers:
+//! - [`copy_large_object`] - Server-side copy of objects of any size with parallel CopyPart
+//! - [`download_file`] - Resumable, parallel ranged download to a local file
+//! - [`upload_file`] - Resumable, parallel multipart upload of a local file
+//! - [`transfer_manager`] - Many concurrent uploads and downloads under shared limits
+
+mod copy_large_object;
+mod download_file;
+mod transfer_manager;
+mod upload_file;
+
+pub use copy_large_object::*;
+pub use download_file::*;
+pub use transfer_manager::*;
+pub use upload_file::*;
+
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::error::{ObsError, Result};
+use crate::progress::ProgressTracker;
+
+/// Smallest part size accepted by OBS (100 KiB), except for the last part.
+pub(crate) const MIN_PART_SIZE: u64 = 100 * 1024;
+
+/// Largest part size accepted by OBS (5 GiB).
+pub(crate) const MAX_PART_SIZE: u64 = 5 * 1024 * 1024 * 1024;
+
+/// Largest number of parts in a multipart upload.
+pub(crate) const MAX_PARTS: u64 = 10000;
+
+/// Append a suffix to a path (`file.bin` -> `file.bin.<suffix>`).
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".");
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// Load a checkpoint, ignoring missing or unreadable files.
+async fn load_checkpoint<T: DeserializeOwned>(path: &Path) -> Option<T> {
+    let text = tokio::fs::read_to_string(path).await.ok()?;
+    crate::xml_utils::from_xml(&text).ok()
+}
+
+/// Write a checkpoint atomically.
+async fn save_checkpoint<T: Serialize>(path: &Path, checkpoint: &T) -> Result<()> {
+    let xml = crate::xml_utils::to_xml(checkpoint)?;
+    let temp = with_suffix(path, "tmp");
+    tokio::fs::write(&temp, xml).await?;
+    tokio::fs::rename(&temp, path).await?;
+    Ok(())
+}
+
+/// Run a part request, retrying retryable errors up to `max_retries` times.
+///
+/// Each attempt gets its own progress tracker so the bytes of a failed
+/// attempt are taken back before retrying.
+async fn with_retries<T, F, Fut>(
+    max_retries: u32,
+    progress: Option<&ProgressTracker>,
+    part_number: i32,
+    mut request: F,
+) -> Result<T>
+where
+    F: FnMut(Option<ProgressTracker>) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut attempt = 0;
+    loop {
+        let tracker = progress.map(|p| p.attempt());
+        match request(tracker.clone()).await {
+            Ok(output) => return Ok(output),
+            Err(e) => {
+                if let Some(ref tracker) = tracker {
+                    tracker.rollback();
+                }
+                if attempt >= max_retries || !e.is_retryable() {
+                    return Err(e);
+                }
+                attempt += 1;
+                if let Some(progress) = progress {
+                    progress.retry(part_number, attempt, &e);
+                }
+                tokio::time::sleep(Duration::from_millis(200 << attempt.min(5))).await;
+            }
+        }
+    }
+}
+
+/// Pick the part size for an object, validating an explicitly requested size.
+///
+/// Without a requested size, `default` is used unless the object needs
+/// larger parts to stay within the part limit.
+fn pick_part_size(size: u64, requested: Option<u64>, default: u64) -> Result<u64> {
+    match requested {
+        Some(part_size) => {
+            if !(MIN_PART_SIZE..=MAX_PART_SIZE).contains(&part_size) {
+                return Err(ObsError::InvalidInput(format!(
+                    "part size must be between {} and {} bytes",
+                    MIN_PART_SIZE, MAX_PART_SIZE
+                )));
+            }
+            if size.div_ceil(part_size) > MAX_PARTS {
+                return Err(ObsError::InvalidInput(format!(
+                    "part size {} would need more than {} parts",
+                    part_size, MAX_PARTS
+                )));
+            }
+            Ok(part_size)
+        }
+        None => {
+            const MIB: u64 = 1024 * 1024;
+            let needed = size.div_ceil(MAX_PARTS).div_ceil(MIB) * MIB;
+            Ok(needed.max(default))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_suffix() {
+        assert_eq!(
+            with_suffix(Path::new("/tmp/data.bin"), "checkpoint"),
+            PathBuf::from("/tmp/data.bin.checkpoint")
+        );
+    }
+
+    #[test]
+    fn test_pick_part_size() {
+        const DEFAULT: u64 = 8 * 1024 * 1024;
+        assert_eq!(pick_part_size(1024, None, DEFAULT).unwrap(), DEFAULT);
+        // 100 GiB needs at least 10.24 MiB per part, rounded up to 11 MiB.
+        let size = pick_part_size(100 * 1024 * 1024 * 1024, None, DEFAULT).unwrap();
+        assert_eq!(size, 11 * 1024 * 1024);
+
+        assert!(pick_part_size(1024, Some(1024), DEFAULT).is_err());
+        assert!(
+            pick_part_size(MAX_PARTS * MIN_PART_SIZE + 1, Some(MIN_PART_SIZE), DEFAULT).is_err()
+        );
+        assert_eq!(
+            pick_part_size(MAX_PARTS * MIN_PART_SIZE, Some(MIN_PART_SIZE), DEFAULT).unwrap(),
+            MIN_PART_SIZE
+        );
+    }
+}
@@ -0,0 +1,985 @@
+//! TransferManager - many concurrent uploads and downloads under shared limits.
+
+use std::future::Future;
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use std::sync::Arc;
+
+use futures::stream::{self, StreamExt, TryStreamExt};
+use tokio::sync::{watch, Semaphore, SemaphorePermit};
+use tokio::task::JoinHandle;
+
+use super::{
+    pick_part_size, with_retries, with_suffix, PartUploader, RangeDownloader, MAX_PART_SIZE,
+    MIN_PART_SIZE,
+};
+use crate::client::Client;
+use crate::error::{ObsError, Result};
+use crate::operations::multipart::{AbortGuard, CompletedPart};
+use crate::operations::object::directory_key;
+use crate::progress::ProgressTracker;
+
+/// Default size above which files are transferred in parts (16 MiB).
+const DEFAULT_MULTIPART_THRESHOLD: u64 = 16 * 1024 * 1024;
+
+/// Default part size (8 MiB).
+const DEFAULT_PART_SIZE: u64 = 8 * 1024 * 1024;
+
+/// Default number of requests in flight across all transfers.
+const DEFAULT_MAX_CONCURRENCY: usize = 16;
+
+/// Default number of bytes buffered across all transfers (256 MiB).
+const DEFAULT_MAX_MEMORY: u64 = 256 * 1024 * 1024;
+
+/// Granularity of the memory limit (1 KiB per semaphore permit).
+const MEMORY_UNIT: u64 = 1024;
+
+/// Largest part size allowed by a memory limit: whole memory units, at most
+/// the OBS part size limit.
+fn part_size_limit(max_memory: u64) -> u64 {
+    (max_memory / MEMORY_UNIT * MEMORY_UNIT).min(MAX_PART_SIZE)
+}
+
+/// Builder for a [`TransferManager`].
+#[derive(Debug, Clone)]
+pub struct TransferManagerBuilder {
+    client: Client,
+    multipart_threshold: u64,
+    part_size: u64,
+    max_concurrency: usize,
+    max_memory: u64,
+    max_retries: u32,
+}
+
+impl TransferManagerBuilder {
+    /// Create a new builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            multipart_threshold: DEFAULT_MULTIPART_THRESHOLD,
+            part_size: DEFAULT_PART_SIZE,
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+            max_memory: DEFAULT_MAX_MEMORY,
+            max_retries: 0,
+        }
+    }
+
+    /// Set the size above which files are transferred in parts (default: 16 MiB).
+    ///
+    /// Smaller files are sent with a single PutObject or GetObject request.
+    pub fn multipart_threshold(mut self, threshold: u64) -> Self {
+        self.multipart_threshold = threshold;
+        self
+    }
+
+    /// Set the part size (default: 8 MiB).
+    ///
+    /// The part size is increased as needed to stay within 10000 parts.
+    pub fn part_size(mut self, part_size: u64) -> Self {
+        self.part_size = part_size;
+        self
+    }
+
+    /// Set how many requests may be in flight across all transfers (default: 16).
+    pub fn max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency;
+        self
+    }
+
+    /// Set how many bytes may be buffered across all transfers (default: 256 MiB).
+    ///
+    /// Must be at least the multipart threshold and the part size.
+    pub fn max_memory(mut self, max_memory: u64) -> Self {
+        self.max_memory = max_memory;
+        self
+    }
+
+    /// Set how many times a failed request is retried (default: 0).
+    ///
+    /// Only network errors and throttling or server errors are retried.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Create the transfer manager.
+    pub fn build(self) -> Result<TransferManager> {
+        if self.max_concurrency == 0 {
+            return Err(ObsError::InvalidInput(
+                "max concurrency must be greater than zero".to_string(),
+            ));
+        }
+        if !(MIN_PART_SIZE..=MAX_PART_SIZE).contains(&self.part_size) {
+            return Err(ObsError::InvalidInput(format!(
+                "part size must be between {} and {} bytes",
+                MIN_PART_SIZE, MAX_PART_SIZE
+            )));
+        }
+        if self.max_memory < self.multipart_threshold
+            || part_size_limit(self.max_memory) < self.part_size
+        {
+            return Err(ObsError::InvalidInput(
+                "max memory must be at least the multipart threshold and the part size".to_string(),
+            ));
+        }
+        // Round up so that any request of up to `max_memory` bytes can get its permits
+        let memory_permits = usize::try_from(self.max_memory.div_ceil(MEMORY_UNIT))
+            .unwrap_or(Semaphore::MAX_PERMITS)
+            .min(Semaphore::MAX_PERMITS);
+
+        Ok(TransferManager {
+            inner: Arc::new(Shared {
+                client: self.client,
+                multipart_threshold: self.multipart_threshold,
+                part_size: self.part_size,
+                max_concurrency: self.max_concurrency,
+                max_memory: self.max_memory,
+                max_retries: self.max_retries,
+                requests: Semaphore::new(self.max_concurrency),
+                memory: Semaphore::new(memory_permits),
+            }),
+        })
+    }
+}
+
+/// Runs many uploads and downloads concurrently under shared limits.
+///
+/// Every transfer is started immediately and returns a [`TransferHandle`].
+/// Files up to the multipart threshold are sent with a single request;
+/// larger files are split into parts. Requests of all transfers share one
+/// concurrency limit and one memory limit, so starting thousands of
+/// transfers at once is safe. Cancelling or failing a multipart upload
+/// aborts it on OBS.
+///
+/// The manager is cheap to clone and must be used within a Tokio runtime.
+///
+/// ```rust,no_run
+/// let manager = client.transfer_manager()
+///     .max_concurrency(32)
+///     .build()?;
+///
+/// let handle = manager.upload_directory("my-bucket", "datasets/2024", "/data/2024");
+/// println!("{:?}: {} bytes", handle.status(), handle.bytes_transferred());
+///
+/// let output = handle.join().await?;
+/// for failure in output.failures() {
+///     println!("{} failed: {}", failure.key(), failure.error());
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransferManager {
+    inner: Arc<Shared>,
+}
+
+impl TransferManager {
+    /// Upload a local file to an object.
+    pub fn upload_file(
+        &self,
+        bucket: impl Into<String>,
+        key: impl Into<String>,
+        file: impl AsRef<Path>,
+    ) -> TransferHandle<TransferOutput> {
+        let shared = self.inner.clone();
+        let (bucket, key) = (bucket.into(), key.into());
+        let file = file.as_ref().to_path_buf();
+        spawn(move |progress| async move {
+            validate_location(&bucket, &key)?;
+            let size = tokio::fs::metadata(&file).await?.len();
+            progress.total.store(size, Ordering::Relaxed);
+            shared
+                .upload_file(&progress.tracker, &bucket, &key, &file, size)
+                .await
+        })
+    }
+
+    /// Download an object to a local file.
+    pub fn download_file(
+        &self,
+        bucket: impl Into<String>,
+        key: impl Into<String>,
+        file: impl AsRef<Path>,
+    ) -> TransferHandle<TransferOutput> {
+        let shared = self.inner.clone();
+        let (bucket, key) = (bucket.into(), key.into());
+        let file = file.as_ref().to_path_buf();
+        spawn(move |progress| async move {
+            validate_location(&bucket, &key)?;
+            let head = {
+                let _permit = shared.request_permit().await?;
+                shared
+                    .client
+                    .head_object()
+                    .bucket(&bucket)
+                    .key(&key)
+                    .send()
+                    .await?
+            };
+            let size = head.content_length().ok_or_else(|| {
+                ObsError::Unknown(format!("object {} has no Content-Length", key))
+            })?;
+            let etag = head
+                .etag()
+                .ok_or_else(|| ObsError::Unknown(format!("object {} has no ETag", key)))?
+                .to_string();
+            progress.total.store(size, Ordering::Relaxed);
+            shared
+                .download_file(&progress.tracker, &bucket, &key, &file, size, &etag)
+                .await
+        })
+    }
+
+    /// Upload every file under a local directory to objects under a prefix.
+    ///
+    /// Object keys are the prefix followed by the file's path relative to the
+    /// directory, using `/` as separator. Failed files are reported in the
+    /// output instead of failing the whole transfer.
+    pub fn upload_directory(
+        &self,
+        bucket: impl Into<String>,
+        prefix: impl Into<String>,
+        directory: impl AsRef<Path>,
+    ) -> TransferHandle<DirectoryTransferOutput> {
+        let shared = self.inner.clone();
+        let (bucket, prefix) = (bucket.into(), key_prefix(&prefix.into()));
+        let directory = directory.as_ref().to_path_buf();
+        spawn(move |progress| async move {
+            validate_location(&bucket, "-")?;
+            let files = walk_directory(&directory).await?;
+            let total = files.iter().map(|(_, _, size)| size).sum();
+            progress.total.store(total, Ordering::Relaxed);
+
+            let results = stream::iter(files)
+                .map(|(relative, path, size)| {
+                    let (shared, progress, bucket) = (&shared, &progress.tracker, &bucket);
+                    let key = format!("{}{}", prefix, relative);
+                    async move {
+                        let result = shared
+                            .upload_file(progress, bucket, &key, &path, size)
+                            .await;
+                        (key, path, result)
+                    }
+                })
+                .buffer_unordered(shared.max_concurrency)
+                .collect::<Vec<_>>()
+                .await;
+
+            Ok(DirectoryTransferOutput::from_results(results))
+        })
+    }
+
+    /// Download every object under a prefix into a local directory.
+    ///
+    /// Local paths are the object key without the prefix, relative to the
+    /// directory. Directory marker objects (keys ending with `/`) are
+    /// skipped, and keys that cannot be mapped safely to a local path (for
+    /// example containing `..`) are reported as failures.
+    pub fn download_directory(
+        &self,
+        bucket: impl Into<String>,
+        prefix: impl Into<String>,
+        directory: impl AsRef<Path>,
+    ) -> TransferHandle<DirectoryTransferOutput> {
+        let shared = self.inner.clone();
+        let (bucket, prefix) = (bucket.into(), key_prefix(&prefix.into()));
+        let directory = directory.as_ref().to_path_buf();
+        spawn(move |progress| async move {
+            validate_location(&bucket, "-")?;
+            let objects = shared.list_objects(&bucket, &prefix).await?;
+            let total = objects.iter().map(|(_, size, _)| size).sum();
+            progress.total.store(total, Ordering::Relaxed);
+
+            let results = stream::iter(objects)
+                .map(|(key, size, etag)| {
+                    let (shared, progress, bucket) = (&shared, &progress.tracker, &bucket);
+                    let path = local_path(&directory, &prefix, &key);
+                    async move {
+                        let Some(path) = path else {
+                            let error = ObsError::InvalidInput(format!(
+                                "object key {} cannot be mapped to a local path",
+                                key
+                            ));
+                            return (key, PathBuf::new(), Err(error));
+                        };
+                        let result = async {
+                            if let Some(parent) = path.parent() {
+                                tokio::fs::create_dir_all(parent).await?;
+                            }
+                            shared
+                                .download_file(progress, bucket, &key, &path, size, &etag)
+                                .await
+                        }
+                        .await;
+                        (key, path, result)
+                    }
+                })
+                .buffer_unordered(shared.max_concurrency)
+                .collect::<Vec<_>>()
+                .await;
+
+            Ok(DirectoryTransferOutput::from_results(results))
+        })
+    }
+}
+
+/// State shared by all transfers of a manager.
+#[derive(Debug)]
+struct Shared {
+    client: Client,
+    multipart_threshold: u64,
+    part_size: u64,
+    max_concurrency: usize,
+    max_memory: u64,
+    max_retries: u32,
+    requests: Semaphore,
+    memory: Semaphore,
+}
+
+impl Shared {
+    /// Wait for a free request slot.
+    async fn request_permit(&self) -> Result<SemaphorePermit<'_>> {
+        self.requests
+            .acquire()
+            .await
+            .map_err(|_| ObsError::Cancelled)
+    }
+
+    /// Wait until `len` bytes may be buffered and a request slot is free.
+    async fn permits(&self, len: u64) -> Result<(SemaphorePermit<'_>, SemaphorePermit<'_>)> {
+        let memory = self
+            .memory
+            .acquire_many(len.div_ceil(MEMORY_UNIT) as u32)
+            .await
+            .map_err(|_| ObsError::Cancelled)?;
+        Ok((memory, self.request_permit().await?))
+    }
+
+    /// Pick the part size for an object, staying within 10000 parts.
+    fn part_size_for(&self, size: u64) -> Result<u64> {
+        let part_size = pick_part_size(size, None, self.part_size)?;
+        if part_size > part_size_limit(self.max_memory) {
+            return Err(ObsError::InvalidInput(format!(
+                "a {} byte object needs parts larger than the memory limit",
+                size
+            )));
+        }
+        Ok(part_size)
+    }
+
+    /// Upload one file with PutObject or a multipart upload.
+    async fn upload_file(
+        &self,
+        progress: &ProgressTracker,
+        bucket: &str,
+        key: &str,
+        path: &Path,
+        size: u64,
+    ) -> Result<TransferOutput> {
+        if size <= self.multipart_threshold {
+            let _permits = self.permits(size).await?;
+            let output = with_retries(self.max_retries, Some(progress), 1, |tracker| async {
+                let body = tokio::fs::read(path).await?;
+                let mut request = self.client.put_object().bucket(bucket).key(key).body(body);
+                if let Some(tracker) = tracker {
+                    request = request.progress_tracker(tracker);
+                }
+                request.send().await
+            })
+            .await?;
+            return Ok(TransferOutput {
+                key: key.to_string(),
+                size,
+                etag: output.etag().map(|s| s.to_string()),
+                multipart: false,
+            });
+        }
+
+        let part_size = self.part_size_for(size)?;
+        let upload_id = {
+            let _permit = self.request_permit().await?;
+            self.client
+                .initiate_multipart_upload()
+                .bucket(bucket)
+                .key(key)
+                .send()
+                .await?
+                .upload_id()
+                .to_string()
+        };
+        let guard = AbortGuard::new(self.client.clone(), bucket, key, &upload_id);
+
+        let uploader = &PartUploader {
+            client: &self.client,
+            bucket,
+            key,
+            upload_id: &upload_id,
+            file: path,
+            file_size: size,
+            part_size,
+            max_retries: self.max_retries,
+            progress: Some(progress),
+        };
+        let mut parts: Vec<CompletedPart> = stream::iter(1..=uploader.part_count())
+            .map(|part| async move {
+                let _permits = self.permits(uploader.part_len(part)).await?;
+                uploader.upload(part).await
+            })
+            .buffer_unordered(self.max_concurrency)
+            .try_collect()
+            .await?;
+        parts.sort_by_key(|p| p.part_number);
+
+        let output = {
+            let _permit = self.request_permit().await?;
+            self.client
+                .complete_multipart_upload()
+                .bucket(bucket)
+                .key(key)
+                .upload_id(&upload_id)
+                .parts(parts)
+                .send()
+                .await?
+        };
+        guard.disarm();
+
+        Ok(TransferOutput {
+            key: key.to_string(),
+            size,
+            etag: Some(output.etag().to_string()),
+            multipart: true,
+        })
+    }
+
+    /// Download one object with a single GetObject or ranged requests.
+    ///
+    /// Data is written to `<file>.download` and renamed once complete; every
+    /// request carries `If-Match` so a changed object fails the download.
+    async fn download_file(
+        &self,
+        progress: &ProgressTracker,
+        bucket: &str,
+        key: &str,
+        path: &Path,
+        size: u64,
+        etag: &str,
+    ) -> Result<TransferOutput> {
+        let temp = TempFile(Some(with_suffix(path, "download")));
+        let temp_path = temp.0.as_deref().unwrap_or(path);
+
+        let multipart = size > self.multipart_threshold;
+        if !multipart {
+            let _permits = self.permits(size).await?;
+            let if_match = &format!("\"{}\"", etag);
+            let output = with_retries(self.max_retries, Some(progress), 1, |tracker| async {
+                let mut request = self
+                    .client
+                    .get_object()
+                    .bucket(bucket)
+                    .key(key)
+                    .if_match(if_match);
+                if let Some(tracker) = tracker {
+                    request = request.progress_tracker(tracker);
+                }
+                request.send().await
+            })
+            .await?;
+            tokio::fs::write(temp_path, output.body()).await?;
+        } else {
+            let file = tokio::fs::File::create(temp_path).await?;
+            file.set_len(size).await?;
+            drop(file);
+
+            let downloader = &RangeDownloader {
+                client: &self.client,
+                bucket,
+                key,
+                version_id: None,
+                etag,
+                file: temp_path,
+                size,
+                part_size: self.part_size_for(size)?,
+                max_retries: self.max_retries,
+                progress: Some(progress),
+            };
+            stream::iter(0..downloader.part_count())
+                .map(|part| async move {
+                    let _permits = self.permits(downloader.part_len(part)).await?;
+                    downloader.download(part).await
+                })
+                .buffer_unordered(self.max_concurrency)
+                .try_collect::<Vec<()>>()
+                .await?;
+        }
+
+        tokio::fs::rename(temp_path, path).await?;
+        let mut temp = temp;
+        temp.0 = None;
+
+        Ok(TransferOutput {
+            key: key.to_string(),
+            size,
+            etag: Some(etag.to_string()),
+            multipart,
+        })
+    }
+
+    /// List the key, size and ETag of every object under a prefix.
+    async fn list_objects(&self, bucket: &str, prefix: &str) -> Result<Vec<(String, u64, String)>> {
+        let mut objects = Vec::new();
+        let mut marker: Option<String> = None;
+        loop {
+            let mut request = self
+                .client
+                .list_objects()
+                .bucket(bucket)
+                .prefix(prefix)
+                .max_keys(1000);
+            if let Some(ref marker) = marker {
+                request = request.marker(marker);
+            }
+            let page = {
+                let _permit = self.request_permit().await?;
+                request.send().await?
+            };
+
+            for object in page.contents() {
+                if !object.key().ends_with('/') {
+                    objects.push((
+                        object.key().to_string(),
+                        object.size(),
+                        object.etag().trim_matches('"').to_string(),
+                    ));
+                }
+            }
+
+            if !page.is_truncated() {
+                break;
+            }
+            marker = page
+                .next_marker()
+                .map(|m| m.to_string())
+                .or_else(|| page.contents().last().map(|o| o.key().to_string()));
+            if marker.is_none() {
+                break;
+            }
+        }
+        Ok(objects)
+    }
+}
+
+/// Status of a transfer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferStatus {
+    /// The transfer has not started yet.
+    Pending,
+    /// The transfer is running.
+    InProgress,
+    /// The transfer finished successfully.
+    Completed,
+    /// The transfer failed.
+    Failed,
+    /// The transfer was cancelled.
+    Cancelled,
+}
+
+impl TransferStatus {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            1 => TransferStatus::InProgress,
+            2 => TransferStatus::Completed,
+            3 => TransferStatus::Failed,
+            4 => TransferStatus::Cancelled,
+            _ => TransferStatus::Pending,
+        }
+    }
+
+    fn as_u8(self) -> u8 {
+        match self {
+            TransferStatus::Pending => 0,
+            TransferStatus::InProgress => 1,
+            TransferStatus::Completed => 2,
+            TransferStatus::Failed => 3,
+            TransferStatus::Cancelled => 4,
+        }
+    }
+}
+
+/// Progress of a transfer, shared between the task and its handle.
+#[derive(Debug)]
+struct Progress {
+    status: AtomicU8,
+    tracker: ProgressTracker,
+    total: AtomicU64,
+    cancel: watch::Sender<bool>,
+}
+
+impl Progress {
+    fn set_status(&self, status: TransferStatus) {
+        self.status.store(status.as_u8(), Ordering::Release);
+    }
+}
+
+/// Spawn a transfer task that stops when its handle is cancelled.
+fn spawn<T, F, Fut>(work: F) -> TransferHandle<T>
+where
+    F: FnOnce(Arc<Progress>) -> Fut,
+    Fut: Future<Output = Result<T>> + Send + 'static,
+    T: Send + 'static,
+{
+    let progress = Arc::new(Progress {
+        status: AtomicU8::new(TransferStatus::Pending.as_u8()),
+        tracker: ProgressTracker::new(|_: u64, _: Option<u64>| {}),
+        total: AtomicU64::new(0),
+        cancel: watch::channel(false).0,
+    });
+    let work = work(progress.clone());
+    let state = progress.clone();
+    let task = tokio::spawn(async move {
+        let mut cancelled = state.cancel.subscribe();
+        state.set_status(TransferStatus::InProgress);
+        let result = tokio::select! {
+            result = work => result,
+            _ = cancelled.wait_for(|cancelled| *cancelled) => Err(ObsError::Cancelled),
+        };
+        state.set_status(match result {
+            Ok(_) => TransferStatus::Completed,
+            Err(ObsError::Cancelled) => TransferStatus::Cancelled,
+            Err(_) => TransferStatus::Failed,
+        });
+        result
+    });
+    TransferHandle { progress, task }
+}
+
+/// Handle to a transfer started by a [`TransferManager`].
+///
+/// Dropping the handle does not stop the transfer; use [`cancel`](Self::cancel).
+pub struct TransferHandle<T> {
+    progress: Arc<Progress>,
+    task: JoinHandle<Result<T>>,
+}
+
+impl<T> TransferHandle<T> {
+    /// Get the current status.
+    pub fn status(&self) -> TransferStatus {
+        TransferStatus::from_u8(self.progress.status.load(Ordering::Acquire))
+    }
+
+    /// Get the number of bytes transferred so far.
+    pub fn bytes_transferred(&self) -> u64 {
+        self.progress.tracker.transferred()
+    }
+
+    /// Get the total number of bytes to transfer (0 until known).
+    pub fn total_bytes(&self) -> u64 {
+        self.progress.total.load(Ordering::Relaxed)
+    }
+
+    /// Check if the transfer has finished, successfully or not.
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// Cancel the transfer.
+    ///
+    /// In-flight requests are dropped and unfinished multipart uploads are
+    /// aborted. [`join`](Self::join) then returns [`ObsError::Cancelled`].
+    pub fn cancel(&self) {
+        self.progress.cancel.send_replace(true);
+    }
+
+    /// Wait for the transfer to finish and get its result.
+    pub async fn join(self) -> Result<T> {
+        match self.task.await {
+            Ok(result) => result,
+            Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+            Err(_) => Err(ObsError::Cancelled),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for TransferHandle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TransferHandle")
+            .field("status", &self.status())
+            .field("bytes_transferred", &self.bytes_transferred())
+            .field("total_bytes", &self.total_bytes())
+            .finish()
+    }
+}
+
+/// Output of a single file transfer.
+#[derive(Debug, Clone)]
+pub struct TransferOutput {
+    key: String,
+    size: u64,
+    etag: Option<String>,
+    multipart: bool,
+}
+
+impl TransferOutput {
+    /// Get the object key.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Get the number of bytes transferred.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Get the ETag of the object.
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    /// Check if the file was transferred in parts.
+    pub fn is_multipart(&self) -> bool {
+        self.multipart
+    }
+}
+
+/// Output of a directory transfer.
+#[derive(Debug)]
+pub struct DirectoryTransferOutput {
+    transferred: Vec<TransferOutput>,
+    failures: Vec<TransferFailure>,
+}
+
+impl DirectoryTransferOutput {
+    fn from_results(results: Vec<(String, PathBuf, Result<TransferOutput>)>) -> Self {
+        let mut transferred = Vec::new();
+        let mut failures = Vec::new();
+        for (key, path, result) in results {
+            match result {
+                Ok(output) => transferred.push(output),
+                Err(error) => failures.push(TransferFailure { key, path, error }),
+            }
+        }
+        transferred.sort_by(|a, b| a.key.cmp(&b.key));
+        failures.sort_by(|a, b| a.key.cmp(&b.key));
+        Self {
+            transferred,
+            failures,
+        }
+    }
+
+    /// Get the files that were transferred.
+    pub fn transferred(&self) -> &[TransferOutput] {
+        &self.transferred
+    }
+
+    /// Get the files that failed.
+    pub fn failures(&self) -> &[TransferFailure] {
+        &self.failures
+    }
+
+    /// Get the number of bytes transferred.
+    pub fn bytes_transferred(&self) -> u64 {
+        self.transferred.iter().map(|t| t.size).sum()
+    }
+
+    /// Check if every file was transferred successfully.
+    pub fn is_all_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// A file that failed within a directory transfer.
+#[derive(Debug)]
+pub struct TransferFailure {
+    key: String,
+    path: PathBuf,
+    error: ObsError,
+}
+
+impl TransferFailure {
+    /// Get the object key.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Get the local path (empty if the key has no safe local path).
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Get the error.
+    pub fn error(&self) -> &ObsError {
+        &self.error
+    }
+}
+
+/// Removes a partially written file when dropped before completion.
+struct TempFile(Option<PathBuf>);
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        if let Some(ref path) = self.0 {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+/// Check the bucket name and object key are set.
+fn validate_location(bucket: &str, key: &str) -> Result<()> {
+    if bucket.is_empty() {
+        return Err(ObsError::InvalidInput(
+            "bucket name is required".to_string(),
+        ));
+    }
+    if key.is_empty() {
+        return Err(ObsError::InvalidInput("object key is required".to_string()));
+    }
+    Ok(())
+}
+
+/// Normalize a key prefix so that it is empty or ends with exactly one slash.
+fn key_prefix(prefix: &str) -> String {
+    if prefix.trim_end_matches('/').is_empty() {
+        String::new()
+    } else {
+        directory_key(prefix)
+    }
+}
+
+/// List every file under a directory with its `/`-separated relative path and size.
+async fn walk_directory(root: &Path) -> Result<Vec<(String, PathBuf, u64)>> {
+    let mut files = Vec::new();
+    let mut directories = vec![root.to_path_buf()];
+    while let Some(directory) = directories.pop() {
+        let mut entries = tokio::fs::read_dir(&directory).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            let file_type = entry.file_type().await?;
+            if file_type.is_dir() {
+                directories.push(path);
+            } else if file_type.is_file() {
+                let relative = relative_key(root, &path).ok_or_else(|| {
+                    ObsError::InvalidInput(format!("{} is not valid UTF-8", path.display()))
+                })?;
+                let size = entry.metadata().await?.len();
+                files.push((relative, path, size));
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Get the `/`-separated path of a file relative to a directory.
+fn relative_key(root: &Path, path: &Path) -> Option<String> {
+    let segments = path
+        .strip_prefix(root)
+        .ok()?
+        .components()
+        .map(|c| match c {
+            Component::Normal(segment) => segment.to_str(),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(segments.join("/"))
+}
+
+/// Map an object key under a prefix to a path under a directory.
+///
+/// Returns `None` for keys with empty, `.` or `..` segments so a download can
+/// never write outside the directory.
+fn local_path(root: &Path, prefix: &str, key: &str) -> Option<PathBuf> {
+    let relative = key.strip_prefix(prefix)?;
+    let mut path = root.to_path_buf();
+    for segment in relative.split('/') {
+        if segment.is_empty() || segment == "." || segment == ".." || segment.contains('\\') {
+            return None;
+        }
+        path.push(segment);
+    }
+    Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::operations::transfer::MAX_PARTS;
+
+    fn manager() -> TransferManagerBuilder {
+        let config = Config::builder()
+            .access_key("ak", "sk")
+            .endpoint("obs.cn-north-4.myhuaweicloud.com")
+            .build()
+            .unwrap();
+        TransferManagerBuilder::new(Client::from_config(config).unwrap())
+    }
+
+    #[test]
+    fn test_build_validation() {
+        assert!(manager().build().is_ok());
+        assert!(manager().max_concurrency(0).build().is_err());
+        assert!(manager().part_size(1024).build().is_err());
+        assert!(manager().max_memory(4 * 1024 * 1024).build().is_err());
+        assert!(manager()
+            .part_size(DEFAULT_MULTIPART_THRESHOLD + 512)
+            .max_memory(DEFAULT_MULTIPART_THRESHOLD + 512)
+            .build()
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_memory_limit_not_multiple_of_unit() {
+        let max_memory = DEFAULT_MULTIPART_THRESHOLD + 512;
+        let manager = manager()
+            .part_size(DEFAULT_MULTIPART_THRESHOLD)
+            .max_memory(max_memory)
+            .build()
+            .unwrap();
+        let shared = &manager.inner;
+
+        // The largest part fits in whole memory units
+        let size = MAX_PARTS * DEFAULT_MULTIPART_THRESHOLD;
+        assert_eq!(
+            shared.part_size_for(size).unwrap(),
+            DEFAULT_MULTIPART_THRESHOLD
+        );
+        assert!(shared.part_size_for(size + MAX_PARTS).is_err());
+
+        // A request of the whole memory limit gets its permits instead of waiting forever
+        let permits = tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            shared.permits(max_memory),
+        )
+        .await
+        .expect("memory permits never became available");
+        assert!(permits.is_ok());
+    }
+
+    #[test]
+    fn test_key_mapping() {
+        assert_eq!(key_prefix(""), "");
+        assert_eq!(key_prefix("/"), "");
+        assert_eq!(key_prefix("backup//"), "backup/");
+
+        let root = Path::new("/data");
+        assert_eq!(
+            relative_key(root, Path::new("/data/a/b.txt")).as_deref(),
+            Some("a/b.txt")
+        );
+        assert_eq!(
+            local_path(root, "backup/", "backup/a/b.txt"),
+            Some(PathBuf::from("/data/a/b.txt"))
+        );
+        assert_eq!(local_path(root, "backup/", "backup/../etc/passwd"), None);
+        assert_eq!(local_path(root, "backup/", "backup/a//b.txt"), None);
+        assert_eq!(local_path(root, "backup/", "other/b.txt"), None);
+    }
+
+    #[tokio::test]
+    async fn test_cancel() {
+        let handle = spawn(|_| futures::future::pending::<Result<()>>());
+        handle.cancel();
+        while !handle.is_finished() {
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(handle.status(), TransferStatus::Cancelled);
+        assert!(matches!(handle.join().await, Err(ObsError::Cancelled)));
+    }
+}
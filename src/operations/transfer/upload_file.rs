@@ -0,0 +1,629 @@
+//! UploadFile - resumable, parallel multipart upload of a local file.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::SeekFrom;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use futures::stream::{self, StreamExt, TryStreamExt};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+use super::{load_checkpoint, pick_part_size, save_checkpoint, with_retries, with_suffix};
+use crate::client::Client;
+use crate::error::{ObsError, Result};
+use crate::operations::multipart::CompletedPart;
+use crate::progress::{ProgressListener, ProgressTracker};
+
+/// Default part size (8 MiB).
+const DEFAULT_PART_SIZE: u64 = 8 * 1024 * 1024;
+
+/// Default number of parts uploaded at the same time.
+const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Fluent builder for uploading a file to an object.
+///
+/// Files that fit in a single part are sent with PutObject. Larger files are
+/// uploaded as a multipart upload whose parts are sent concurrently. The
+/// upload ID, the ETag of every finished part and the file's size and
+/// modification time are recorded in a checkpoint file
+/// (`<file>.upload-checkpoint` by default). When the upload is started again
+/// for an unchanged file, the checkpoint is reconciled with ListParts and only
+/// the missing parts are uploaded before the upload is completed.
+#[derive(Debug, Clone)]
+pub struct UploadFileFluentBuilder {
+    client: Client,
+    inner: UploadFileInput,
+}
+
+impl UploadFileFluentBuilder {
+    /// Create a new fluent builder.
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            inner: UploadFileInput::default(),
+        }
+    }
+
+    /// Set the bucket name.
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.inner.bucket = bucket.into();
+        self
+    }
+
+    /// Set the object key.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.inner.key = key.into();
+        self
+    }
+
+    /// Set the local file to upload (required).
+    pub fn file(mut self, path: impl AsRef<Path>) -> Self {
+        self.inner.file = path.as_ref().to_path_buf();
+        self
+    }
+
+    /// Set the part size.
+    ///
+    /// By default 8 MiB is used, increased as needed to stay within 10000 parts.
+    pub fn part_size(mut self, part_size: u64) -> Self {
+        self.inner.part_size = Some(part_size);
+        self
+    }
+
+    /// Set how many parts are uploaded at the same time (default: 4).
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.inner.concurrency = concurrency;
+        self
+    }
+
+    /// Enable or disable the checkpoint file (default: enabled).
+    ///
+    /// Without a checkpoint, a failed multipart upload is aborted.
+    pub fn enable_checkpoint(mut self, enable: bool) -> Self {
+        self.inner.enable_checkpoint = enable;
+        self
+    }
+
+    /// Set the checkpoint file path (default: `<file>.upload-checkpoint`).
+    pub fn checkpoint_file(mut self, path: impl AsRef<Path>) -> Self {
+        self.inner.checkpoint_file = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Set the content type.
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.inner.content_type = Some(content_type.into());
+        self
+    }
+
+    /// Set the storage class.
+    ///
+    /// Possible values: STANDARD, WARM, COLD, DEEP_ARCHIVE
+    pub fn storage_class(mut self, storage_class: impl Into<String>) -> Self {
+        self.inner.storage_class = Some(storage_class.into());
+        self
+    }
+
+    /// Add custom metadata.
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.inner.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set how many times a failed part is retried (default: 0).
+    ///
+    /// Only network errors and throttling or server errors are retried.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.inner.max_retries = max_retries;
+        self
+    }
+
+    /// Set a listener that is notified of uploaded bytes, completed parts and retries.
+    pub fn progress_listener(mut self, listener: impl ProgressListener + 'static) -> Self {
+        self.inner.progress = Some(ProgressTracker::new(listener));
+        self
+    }
+
+    /// Upload the file.
+    pub async fn send(&self) -> Result<UploadFileOutput> {
+        let bucket = &self.inner.bucket;
+        let key = &self.inner.key;
+
+        if bucket.is_empty() {
+            return Err(ObsError::InvalidInput(
+                "bucket name is required".to_string(),
+            ));
+        }
+        if key.is_empty() {
+            return Err(ObsError::InvalidInput("object key is required".to_string()));
+        }
+        if self.inner.file.as_os_str().is_empty() {
+            return Err(ObsError::InvalidInput("file path is required".to_string()));
+        }
+        if self.inner.concurrency == 0 {
+            return Err(ObsError::InvalidInput(
+                "concurrency must be greater than zero".to_string(),
+            ));
+        }
+
+        let metadata = tokio::fs::metadata(&self.inner.file).await?;
+        let file_size = metadata.len();
+        let file_modified = metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+        let part_size = pick_part_size(file_size, self.inner.part_size, DEFAULT_PART_SIZE)?;
+
+        if file_size <= part_size {
+            return self.put_whole_file(file_size).await;
+        }
+
+        let checkpoint_file = self
+            .inner
+            .checkpoint_file
+            .clone()
+            .unwrap_or_else(|| with_suffix(&self.inner.file, "upload-checkpoint"));
+
+        // Resume only if the file is unchanged and the upload still exists.
+        let mut existing = None;
+        if self.inner.enable_checkpoint {
+            if let Some(checkpoint) = load_checkpoint::<UploadCheckpoint>(&checkpoint_file).await {
+                if checkpoint.matches(bucket, key, file_size, file_modified, part_size) {
+                    existing = self.reconcile(checkpoint).await?;
+                } else {
+                    self.abort(&checkpoint.bucket, &checkpoint.key, &checkpoint.upload_id)
+                        .await;
+                }
+            }
+        }
+        let resumed = existing.is_some();
+
+        let mut checkpoint = match existing {
+            Some(checkpoint) => checkpoint,
+            None => {
+                let upload_id = self.initiate().await?;
+                let checkpoint = UploadCheckpoint {
+                    bucket: bucket.clone(),
+                    key: key.clone(),
+                    upload_id,
+                    file_size,
+                    file_modified,
+                    part_size,
+                    parts: Vec::new(),
+                };
+                if self.inner.enable_checkpoint {
+                    save_checkpoint(&checkpoint_file, &checkpoint).await?;
+                }
+                checkpoint
+            }
+        };
+
+        let upload_id = checkpoint.upload_id.clone();
+        let uploader = PartUploader {
+            client: &self.client,
+            bucket,
+            key,
+            upload_id: &upload_id,
+            file: &self.inner.file,
+            file_size,
+            part_size,
+            max_retries: self.inner.max_retries,
+            progress: self.inner.progress.as_ref(),
+        };
+        let pending: Vec<i32> = (1..=uploader.part_count())
+            .filter(|&part| !checkpoint.parts.iter().any(|p| p.part_number == part))
+            .collect();
+        let resumed_bytes = checkpoint
+            .parts
+            .iter()
+            .map(|p| uploader.part_len(p.part_number))
+            .sum();
+        if let Some(ref progress) = self.inner.progress {
+            progress.set_total(file_size);
+            progress.add(resumed_bytes);
+        }
+
+        let result = async {
+            let mut uploads = stream::iter(pending)
+                .map(|part| uploader.upload(part))
+                .buffer_unordered(self.inner.concurrency);
+
+            while let Some(part) = uploads.try_next().await? {
+                checkpoint.parts.push(CheckpointPart {
+                    part_number: part.part_number,
+                    etag: part.etag,
+                });
+                if self.inner.enable_checkpoint {
+                    save_checkpoint(&checkpoint_file, &checkpoint).await?;
+                }
+            }
+
+            let mut parts: Vec<CompletedPart> = checkpoint
+                .parts
+                .iter()
+                .map(|p| CompletedPart::new(p.part_number, &p.etag))
+                .collect();
+            parts.sort_by_key(|p| p.part_number);
+
+            self.client
+                .complete_multipart_upload()
+                .bucket(bucket)
+                .key(key)
+                .upload_id(&upload_id)
+                .parts(parts)
+                .send()
+                .await
+        }
+        .await;
+
+        let output = match result {
+            Ok(output) => output,
+            Err(e) => {
+                if !self.inner.enable_checkpoint {
+                    self.abort(bucket, key, &upload_id).await;
+                }
+                return Err(e);
+            }
+        };
+
+        if self.inner.enable_checkpoint {
+            let _ = tokio::fs::remove_file(&checkpoint_file).await;
+        }
+
+        Ok(UploadFileOutput {
+            file_size,
+            etag: Some(output.etag().to_string()),
+            upload_id: Some(upload_id),
+            resumed,
+            resumed_bytes,
+        })
+    }
+
+    /// Upload a file that fits in a single part with PutObject.
+    async fn put_whole_file(&self, file_size: u64) -> Result<UploadFileOutput> {
+        let body = tokio::fs::read(&self.inner.file).await?;
+        let mut request = self
+            .client
+            .put_object()
+            .bucket(&self.inner.bucket)
+            .key(&self.inner.key)
+            .body(body);
+        if let Some(ref content_type) = self.inner.content_type {
+            request = request.content_type(content_type);
+        }
+        if let Some(ref storage_class) = self.inner.storage_class {
+            request = request.storage_class(storage_class);
+        }
+        if !self.inner.metadata.is_empty() {
+            request = request.metadata(self.inner.metadata.clone());
+        }
+        if let Some(ref progress) = self.inner.progress {
+            request = request.progress_tracker(progress.clone());
+        }
+        let output = request.send().await?;
+
+        Ok(UploadFileOutput {
+            file_size,
+            etag: output.etag().map(|s| s.to_string()),
+            upload_id: None,
+            resumed: false,
+            resumed_bytes: 0,
+        })
+    }
+
+    /// Start a new multipart upload and return its upload ID.
+    async fn initiate(&self) -> Result<String> {
+        let mut request = self
+            .client
+            .initiate_multipart_upload()
+            .bucket(&self.inner.bucket)
+            .key(&self.inner.key);
+        if let Some(ref content_type) = self.inner.content_type {
+            request = request.content_type(content_type);
+        }
+        if let Some(ref storage_class) = self.inner.storage_class {
+            request = request.storage_class(storage_class);
+        }
+        for (name, value) in &self.inner.metadata {
+            request = request.metadata(name, value);
+        }
+        Ok(request.send().await?.upload_id().to_string())
+    }
+
+    /// Keep only the checkpointed parts that OBS still has.
+    ///
+    /// Parts listed by OBS but missing from the checkpoint (uploaded just
+    /// before an interruption) are kept when their size is as expected.
+    /// Returns `None` if the upload no longer exists.
+    async fn reconcile(
+        &self,
+        mut checkpoint: UploadCheckpoint,
+    ) -> Result<Option<UploadCheckpoint>> {
+        let mut uploaded = BTreeMap::new();
+        let mut marker = None;
+        loop {
+            let mut request = self
+                .client
+                .list_parts()
+                .bucket(&checkpoint.bucket)
+                .key(&checkpoint.key)
+                .upload_id(&checkpoint.upload_id)
+                .max_parts(1000);
+            if let Some(marker) = marker {
+                request = request.part_number_marker(marker);
+            }
+            let output = match request.send().await {
+                Ok(output) => output,
+                Err(e) if e.status_code() == Some(StatusCode::NOT_FOUND) => return Ok(None),
+                Err(e) => return Err(e),
+            };
+            for part in output.parts() {
+                uploaded.insert(
+                    part.part_number(),
+                    (part.etag().trim_matches('"').to_string(), part.size()),
+                );
+            }
+            match output.next_part_number_marker() {
+                Some(next) if output.is_truncated() => marker = Some(next),
+                _ => break,
+            }
+        }
+
+        let part_size = checkpoint.part_size;
+        let file_size = checkpoint.file_size;
+        let expected_len = |part: i32| {
+            let start = (part as u64 - 1) * part_size;
+            (file_size.saturating_sub(start)).min(part_size)
+        };
+        let recorded: HashMap<i32, String> = checkpoint
+            .parts
+            .drain(..)
+            .map(|p| (p.part_number, p.etag))
+            .collect();
+        checkpoint.parts = uploaded
+            .into_iter()
+            .filter(|(number, (etag, size))| {
+                *size == expected_len(*number)
+                    && recorded.get(number).is_none_or(|recorded| recorded == etag)
+            })
+            .map(|(part_number, (etag, _))| CheckpointPart { part_number, etag })
+            .collect();
+
+        Ok(Some(checkpoint))
+    }
+
+    /// Abort a multipart upload, ignoring errors.
+    async fn abort(&self, bucket: &str, key: &str, upload_id: &str) {
+        let _ = self
+            .client
+            .abort_multipart_upload()
+            .bucket(bucket)
+            .key(key)
+            .upload_id(upload_id)
+            .send()
+            .await;
+    }
+}
+
+/// Reads parts of a file and uploads them to a multipart upload.
+///
+/// Shared by [`UploadFileFluentBuilder`] and the transfer manager, which
+/// decide how many parts run at once.
+pub(super) struct PartUploader<'a> {
+    pub(super) client: &'a Client,
+    pub(super) bucket: &'a str,
+    pub(super) key: &'a str,
+    pub(super) upload_id: &'a str,
+    pub(super) file: &'a Path,
+    pub(super) file_size: u64,
+    pub(super) part_size: u64,
+    pub(super) max_retries: u32,
+    pub(super) progress: Option<&'a ProgressTracker>,
+}
+
+impl PartUploader<'_> {
+    /// Get the number of parts.
+    pub(super) fn part_count(&self) -> i32 {
+        self.file_size.div_ceil(self.part_size) as i32
+    }
+
+    /// Get the length of a part (numbered from 1).
+    pub(super) fn part_len(&self, part: i32) -> u64 {
+        let start = (part as u64 - 1) * self.part_size;
+        self.file_size.saturating_sub(start).min(self.part_size)
+    }
+
+    /// Read a part from the file and upload it, retrying failed attempts.
+    pub(super) async fn upload(&self, part: i32) -> Result<CompletedPart> {
+        let len = self.part_len(part);
+        let output = with_retries(self.max_retries, self.progress, part, |tracker| async {
+            let mut body = vec![0u8; len as usize];
+            let mut file = tokio::fs::File::open(self.file).await?;
+            file.seek(SeekFrom::Start((part as u64 - 1) * self.part_size))
+                .await?;
+            file.read_exact(&mut body).await?;
+
+            let mut request = self
+                .client
+                .upload_part()
+                .bucket(self.bucket)
+                .key(self.key)
+                .upload_id(self.upload_id)
+                .part_number(part)
+                .body(body);
+            if let Some(tracker) = tracker {
+                request = request.progress_tracker(tracker);
+            }
+            request.send().await
+        })
+        .await?;
+        if let Some(progress) = self.progress {
+            progress.part_completed(part, len);
+        }
+        Ok(CompletedPart::new(part, output.etag()))
+    }
+}
+
+/// Input for the UploadFile operation.
+#[derive(Debug, Clone)]
+pub struct UploadFileInput {
+    bucket: String,
+    key: String,
+    file: PathBuf,
+    part_size: Option<u64>,
+    concurrency: usize,
+    enable_checkpoint: bool,
+    checkpoint_file: Option<PathBuf>,
+    content_type: Option<String>,
+    storage_class: Option<String>,
+    metadata: HashMap<String, String>,
+    max_retries: u32,
+    progress: Option<ProgressTracker>,
+}
+
+impl Default for UploadFileInput {
+    fn default() -> Self {
+        Self {
+            bucket: String::new(),
+            key: String::new(),
+            file: PathBuf::new(),
+            part_size: None,
+            concurrency: DEFAULT_CONCURRENCY,
+            enable_checkpoint: true,
+            checkpoint_file: None,
+            content_type: None,
+            storage_class: None,
+            metadata: HashMap::new(),
+            max_retries: 0,
+            progress: None,
+        }
+    }
+}
+
+/// Output for the UploadFile operation.
+#[derive(Debug, Clone)]
+pub struct UploadFileOutput {
+    file_size: u64,
+    etag: Option<String>,
+    upload_id: Option<String>,
+    resumed: bool,
+    resumed_bytes: u64,
+}
+
+impl UploadFileOutput {
+    /// Get the size of the uploaded file.
+    pub fn file_size(&self) -> u64 {
+        self.file_size
+    }
+
+    /// Get the ETag of the uploaded object.
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    /// Get the multipart upload ID (`None` if the file was sent with PutObject).
+    pub fn upload_id(&self) -> Option<&str> {
+        self.upload_id.as_deref()
+    }
+
+    /// Check if the upload resumed from a checkpoint.
+    pub fn resumed(&self) -> bool {
+        self.resumed
+    }
+
+    /// Get the number of bytes that were already uploaded before this call.
+    pub fn resumed_bytes(&self) -> u64 {
+        self.resumed_bytes
+    }
+}
+
+/// Progress of an upload, persisted between runs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "UploadCheckpoint")]
+struct UploadCheckpoint {
+    #[serde(rename = "Bucket")]
+    bucket: String,
+    #[serde(rename = "Key")]
+    key: String,
+    #[serde(rename = "UploadId")]
+    upload_id: String,
+    #[serde(rename = "FileSize")]
+    file_size: u64,
+    #[serde(rename = "FileModified")]
+    file_modified: u64,
+    #[serde(rename = "PartSize")]
+    part_size: u64,
+    #[serde(rename = "Part", default)]
+    parts: Vec<CheckpointPart>,
+}
+
+impl UploadCheckpoint {
+    /// Check the checkpoint belongs to the same object, file and part layout.
+    fn matches(
+        &self,
+        bucket: &str,
+        key: &str,
+        file_size: u64,
+        file_modified: u64,
+        part_size: u64,
+    ) -> bool {
+        self.bucket == bucket
+            && self.key == key
+            && self.file_size == file_size
+            && self.file_modified == file_modified
+            && self.part_size == part_size
+    }
+}
+
+/// A part recorded in the checkpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CheckpointPart {
+    #[serde(rename = "PartNumber")]
+    part_number: i32,
+    #[serde(rename = "ETag")]
+    etag: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checkpoint_round_trip() {
+        let checkpoint = UploadCheckpoint {
+            bucket: "bucket".to_string(),
+            key: "dir/data.bin".to_string(),
+            upload_id: "0000016C4F6B3EBB".to_string(),
+            file_size: 20 * 1024 * 1024,
+            file_modified: 1_700_000_000_000_000_000,
+            part_size: DEFAULT_PART_SIZE,
+            parts: vec![CheckpointPart {
+                part_number: 2,
+                etag: "b026324c6904b2a9cb4b88d6d61c81d1".to_string(),
+            }],
+        };
+        let xml = crate::xml_utils::to_xml(&checkpoint).unwrap();
+        let parsed: UploadCheckpoint = crate::xml_utils::from_xml(&xml).unwrap();
+        assert_eq!(parsed.upload_id, checkpoint.upload_id);
+        assert_eq!(parsed.parts.len(), 1);
+        assert_eq!(parsed.parts[0].part_number, 2);
+        assert!(parsed.matches(
+            "bucket",
+            "dir/data.bin",
+            20 * 1024 * 1024,
+            1_700_000_000_000_000_000,
+            DEFAULT_PART_SIZE
+        ));
+        assert!(!parsed.matches(
+            "bucket",
+            "dir/data.bin",
+            20 * 1024 * 1024,
+            1_700_000_000_000_000_001,
+            DEFAULT_PART_SIZE
+        ));
+    }
+}
@@ -0,0 +1,110 @@
+//! Types shared by several operations.
+
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+
+/// Storage class of a bucket or object.
+///
+/// Values that are not known to this SDK are kept in
+/// [`Unknown`](Self::Unknown). The names used by the S3 compatible API
+/// (`STANDARD_IA`, `GLACIER`) are mapped to their OBS equivalents.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StorageClass {
+    /// Standard storage.
+    Standard,
+    /// Infrequent access storage.
+    Warm,
+    /// Archive storage.
+    Cold,
+    /// Deep archive storage.
+    DeepArchive,
+    /// A storage class not known to this SDK.
+    Unknown(String),
+}
+
+impl StorageClass {
+    /// Get the header value of this storage class.
+    pub fn as_str(&self) -> &str {
+        match self {
+            StorageClass::Standard => "STANDARD",
+            StorageClass::Warm => "WARM",
+            StorageClass::Cold => "COLD",
+            StorageClass::DeepArchive => "DEEP_ARCHIVE",
+            StorageClass::Unknown(value) => value,
+        }
+    }
+
+    /// Check if objects of this storage class must be restored before they can be read.
+    pub fn is_archive(&self) -> bool {
+        matches!(self, StorageClass::Cold | StorageClass::DeepArchive)
+    }
+}
+
+impl From<&str> for StorageClass {
+    fn from(value: &str) -> Self {
+        match value {
+            "STANDARD" => StorageClass::Standard,
+            "WARM" | "STANDARD_IA" => StorageClass::Warm,
+            "COLD" | "GLACIER" => StorageClass::Cold,
+            "DEEP_ARCHIVE" => StorageClass::DeepArchive,
+            other => StorageClass::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl From<StorageClass> for String {
+    fn from(value: StorageClass) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+impl fmt::Display for StorageClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Parse a timestamp returned by OBS.
+///
+/// XML bodies use ISO 8601 (`2024-01-02T03:04:05.000Z`), headers use
+/// RFC 1123 (`Tue, 02 Jan 2024 03:04:05 GMT`).
+pub(crate) fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .or_else(|_| DateTime::parse_from_rfc2822(value))
+        .ok()
+        .map(|d| d.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_parse_timestamp() {
+        let expected = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+        assert_eq!(parse_timestamp("2024-01-02T03:04:05.000Z"), Some(expected));
+        assert_eq!(parse_timestamp("2024-01-02T03:04:05Z"), Some(expected));
+        assert_eq!(
+            parse_timestamp("Tue, 02 Jan 2024 03:04:05 GMT"),
+            Some(expected)
+        );
+        assert_eq!(parse_timestamp(""), None);
+        assert_eq!(parse_timestamp("yesterday"), None);
+    }
+
+    #[test]
+    fn test_storage_class() {
+        assert_eq!(StorageClass::from("STANDARD"), StorageClass::Standard);
+        assert_eq!(StorageClass::from("STANDARD_IA"), StorageClass::Warm);
+        assert_eq!(StorageClass::from("GLACIER"), StorageClass::Cold);
+        assert_eq!(StorageClass::from("DEEP_ARCHIVE").as_str(), "DEEP_ARCHIVE");
+        assert!(StorageClass::DeepArchive.is_archive());
+        assert_eq!(
+            StorageClass::from("INTELLIGENT"),
+            StorageClass::Unknown("INTELLIGENT".to_string())
+        );
+        assert_eq!(String::from(StorageClass::Warm), "WARM");
+    }
+}
@@ -0,0 +1,275 @@
+//! Progress reporting for uploads and downloads.
+//!
+//! Attach a [`ProgressListener`] to PutObject, GetObject, UploadPart,
+//! AppendObject or the file transfer helpers with `.progress_listener()`.
+//! A closure taking `(transferred, total)` is the simplest listener:
+//!
+//! ```rust,no_run
+//! client.put_object()
+//!     .bucket("my-bucket")
+//!     .key("video.mp4")
+//!     .body(data)
+//!     .progress_listener(|transferred: u64, total: Option<u64>| {
+//!         println!("{} / {:?} bytes", transferred, total);
+//!     })
+//!     .send()
+//!     .await?;
+//! ```
+
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use bytes::{Bytes, BytesMut};
+use futures::stream::{self, StreamExt};
+use http_body::Body as _;
+use reqwest::{Body, Response};
+
+use crate::error::{ObsError, Result};
+
+/// Size of the chunks in-memory bodies are sent in, so progress is reported
+/// while the body is being uploaded.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Marker for an unknown total size.
+const UNKNOWN_TOTAL: u64 = u64::MAX;
+
+/// Receives progress events of a transfer.
+///
+/// Listeners are called from the task that drives the request and should
+/// return quickly.
+pub trait ProgressListener: Send + Sync {
+    /// Called when bytes were sent or received.
+    ///
+    /// `transferred` is the number of bytes transferred so far and `total`
+    /// the expected size, if known. When a part is retried, `transferred`
+    /// goes back by the bytes of the failed attempt.
+    fn on_progress(&self, transferred: u64, total: Option<u64>);
+
+    /// Called when a part of a multipart upload or ranged download completed.
+    fn on_part_completed(&self, _part_number: i32, _size: u64) {}
+
+    /// Called before a failed part is retried (`attempt` starts at 1).
+    fn on_retry(&self, _part_number: i32, _attempt: u32, _error: &ObsError) {}
+}
+
+impl<F> ProgressListener for F
+where
+    F: Fn(u64, Option<u64>) + Send + Sync,
+{
+    fn on_progress(&self, transferred: u64, total: Option<u64>) {
+        self(transferred, total)
+    }
+}
+
+impl<L> ProgressListener for Arc<L>
+where
+    L: ProgressListener + ?Sized,
+{
+    fn on_progress(&self, transferred: u64, total: Option<u64>) {
+        (**self).on_progress(transferred, total)
+    }
+
+    fn on_part_completed(&self, part_number: i32, size: u64) {
+        (**self).on_part_completed(part_number, size)
+    }
+
+    fn on_retry(&self, part_number: i32, attempt: u32, error: &ObsError) {
+        (**self).on_retry(part_number, attempt, error)
+    }
+}
+
+/// Counts the bytes of a transfer and forwards them to a listener.
+///
+/// Clones share the count. An [`attempt`](Self::attempt) additionally keeps
+/// its own count so a failed attempt can be rolled back.
+#[derive(Clone)]
+pub(crate) struct ProgressTracker {
+    shared: Arc<Shared>,
+    attempt: Arc<AtomicU64>,
+}
+
+struct Shared {
+    listener: Box<dyn ProgressListener>,
+    transferred: AtomicU64,
+    total: AtomicU64,
+}
+
+impl ProgressTracker {
+    /// Create a tracker with an unknown total.
+    pub(crate) fn new(listener: impl ProgressListener + 'static) -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                listener: Box::new(listener),
+                transferred: AtomicU64::new(0),
+                total: AtomicU64::new(UNKNOWN_TOTAL),
+            }),
+            attempt: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Set the total size, unless it is already known.
+    pub(crate) fn set_total(&self, total: u64) {
+        let _ = self.shared.total.compare_exchange(
+            UNKNOWN_TOTAL,
+            total,
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        );
+    }
+
+    /// Get the number of bytes transferred so far.
+    pub(crate) fn transferred(&self) -> u64 {
+        self.shared.transferred.load(Ordering::Relaxed)
+    }
+
+    /// Start a new attempt sharing the count of this tracker.
+    pub(crate) fn attempt(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+            attempt: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Record transferred bytes.
+    pub(crate) fn add(&self, bytes: u64) {
+        self.attempt.fetch_add(bytes, Ordering::Relaxed);
+        let transferred = self.shared.transferred.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        self.notify(transferred);
+    }
+
+    /// Take back the bytes recorded by this attempt.
+    pub(crate) fn rollback(&self) {
+        let bytes = self.attempt.swap(0, Ordering::Relaxed);
+        if bytes > 0 {
+            let transferred = self.shared.transferred.fetch_sub(bytes, Ordering::Relaxed) - bytes;
+            self.notify(transferred);
+        }
+    }
+
+    /// Report a completed part.
+    pub(crate) fn part_completed(&self, part_number: i32, size: u64) {
+        self.shared.listener.on_part_completed(part_number, size);
+    }
+
+    /// Report a retry of a part.
+    pub(crate) fn retry(&self, part_number: i32, attempt: u32, error: &ObsError) {
+        self.shared.listener.on_retry(part_number, attempt, error);
+    }
+
+    fn notify(&self, transferred: u64) {
+        let total = match self.shared.total.load(Ordering::Relaxed) {
+            UNKNOWN_TOTAL => None,
+            total => Some(total),
+        };
+        self.shared.listener.on_progress(transferred, total);
+    }
+
+    /// Wrap an in-memory body so bytes are counted as they are sent.
+    pub(crate) fn bytes_body(&self, bytes: Vec<u8>) -> Body {
+        let data = Bytes::from(bytes);
+        let len = data.len();
+        let tracker = self.clone();
+        let chunks = stream::iter((0..len).step_by(CHUNK_SIZE)).map(move |start| {
+            let chunk = data.slice(start..(start + CHUNK_SIZE).min(len));
+            tracker.add(chunk.len() as u64);
+            Ok::<_, std::io::Error>(chunk)
+        });
+        Body::wrap_stream(chunks)
+    }
+
+    /// Wrap a streaming body so bytes are counted as they are sent.
+    pub(crate) fn stream_body(&self, body: Body) -> Body {
+        let tracker = self.clone();
+        let frames = stream::unfold(Some(Box::pin(body)), move |body| {
+            let tracker = tracker.clone();
+            async move {
+                let mut body: Pin<Box<Body>> = body?;
+                loop {
+                    match std::future::poll_fn(|cx| body.as_mut().poll_frame(cx)).await? {
+                        Ok(frame) => {
+                            if let Ok(data) = frame.into_data() {
+                                tracker.add(data.len() as u64);
+                                return Some((Ok(data), Some(body)));
+                            }
+                        }
+                        Err(e) => return Some((Err(e), None)),
+                    }
+                }
+            }
+        });
+        Body::wrap_stream(frames)
+    }
+
+    /// Read a response body, counting bytes as they are received.
+    pub(crate) async fn read_body(&self, mut resp: Response) -> Result<Bytes> {
+        let capacity = resp.content_length().unwrap_or(0).min(64 * 1024 * 1024);
+        let mut body = BytesMut::with_capacity(capacity as usize);
+        while let Some(chunk) = resp.chunk().await? {
+            self.add(chunk.len() as u64);
+            body.extend_from_slice(&chunk);
+        }
+        Ok(body.freeze())
+    }
+}
+
+impl std::fmt::Debug for ProgressTracker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProgressTracker")
+            .field(
+                "transferred",
+                &self.shared.transferred.load(Ordering::Relaxed),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_attempt_rollback() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded = events.clone();
+        let tracker = ProgressTracker::new(move |transferred, total| {
+            recorded.lock().unwrap().push((transferred, total));
+        });
+
+        tracker.add(10);
+        tracker.set_total(100);
+        let attempt = tracker.attempt();
+        attempt.add(30);
+        attempt.rollback();
+        let attempt = tracker.attempt();
+        attempt.add(90);
+        tracker.set_total(5);
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                (10, None),
+                (40, Some(100)),
+                (10, Some(100)),
+                (100, Some(100)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bytes_body_chunks() {
+        let tracker = ProgressTracker::new(|_, _| {});
+        let body = tracker.bytes_body(vec![7u8; CHUNK_SIZE * 2 + 1]);
+        let mut frames = Box::pin(body);
+        let mut sizes = Vec::new();
+        while let Some(frame) = std::future::poll_fn(|cx| frames.as_mut().poll_frame(cx)).await {
+            sizes.push(frame.unwrap().into_data().unwrap().len());
+        }
+        assert_eq!(sizes, vec![CHUNK_SIZE, CHUNK_SIZE, 1]);
+        assert_eq!(
+            tracker.shared.transferred.load(Ordering::Relaxed),
+            (CHUNK_SIZE * 2 + 1) as u64
+        );
+    }
+}
@@ -1,7 +1,10 @@
 //! XML serialization/deserialization utilities using quick-xml.
 
+use base64::{engine::general_purpose, Engine};
+use md5::{Digest, Md5};
 use quick_xml::de::from_str;
 use quick_xml::se::to_string;
+use reqwest::header::HeaderValue;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
@@ -16,3 +19,9 @@ pub fn to_xml<T: Serialize>(value: &T) -> Result<String> {
 pub fn from_xml<T: DeserializeOwned>(xml: &str) -> Result<T> {
     from_str(xml).map_err(|e| ObsError::XmlParse(e.to_string()))
 }
+
+/// Compute the `Content-MD5` header value (base64-encoded MD5) of a request body.
+pub(crate) fn content_md5(body: &[u8]) -> HeaderValue {
+    let md5_value = general_purpose::STANDARD.encode(Md5::digest(body));
+    HeaderValue::from_str(&md5_value).expect("base64 is a valid header value")
+}
@@ -2,7 +2,10 @@
 
 mod common;
 
-use huaweicloud_sdk_rust_obs::ObsError;
+use huaweicloud_sdk_rust_obs::{
+    CannedAcl, CorsMethod, CorsRule, LifecycleRule, ObsError, Policy, Principal, RoutingRule,
+    Statement, StorageClass, WebsiteConfiguration,
+};
 
 #[tokio::test]
 async fn test_list_buckets() -> Result<(), ObsError> {
@@ -65,6 +68,11 @@ async fn test_list_objects() -> Result<(), ObsError> {
     println!("Objects in bucket '{}':", bucket_name);
     for obj in result.contents() {
         println!("  - {} ({} bytes)", obj.key(), obj.size());
+        assert!(obj.last_modified_time().is_some());
+        assert!(!matches!(
+            obj.storage_class_kind(),
+            StorageClass::Unknown(_)
+        ));
     }
 
     Ok(())
@@ -91,3 +99,369 @@ async fn test_get_bucket_location() -> Result<(), ObsError> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_head_bucket() -> Result<(), ObsError> {
+    let obs = common::setup()?;
+    let bucket_name = format!("test-head-{}", chrono::Utc::now().timestamp());
+
+    // Missing bucket
+    let result = obs.head_bucket().bucket(&bucket_name).send().await;
+    assert!(matches!(result, Err(ObsError::NoSuchBucket(_))));
+
+    obs.create_bucket()
+        .bucket(&bucket_name)
+        .location_constraint("cn-north-4")
+        .send()
+        .await?;
+
+    let result = obs.head_bucket().bucket(&bucket_name).send().await?;
+    assert_eq!(result.location(), Some("cn-north-4"));
+    assert_eq!(result.storage_class_kind(), Some(StorageClass::Standard));
+    assert!(!result.is_parallel_file_system());
+    println!(
+        "Bucket '{}': version {:?}, AZ redundancy {:?}",
+        bucket_name,
+        result.obs_version(),
+        result.az_redundancy()
+    );
+
+    obs.delete_bucket().bucket(&bucket_name).send().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_bucket_with_options() -> Result<(), ObsError> {
+    let obs = common::setup()?;
+    let bucket_name = format!("test-options-{}", chrono::Utc::now().timestamp());
+
+    let result = obs
+        .create_bucket()
+        .bucket(&bucket_name)
+        .location_constraint("cn-north-4")
+        .canned_acl(CannedAcl::Private)
+        .storage_class(StorageClass::Warm)
+        .multi_az(true)
+        .send()
+        .await?;
+    println!("Created bucket: {:?}", result.location());
+
+    let result = obs.head_bucket().bucket(&bucket_name).send().await?;
+    assert_eq!(result.storage_class_kind(), Some(StorageClass::Warm));
+    assert!(result.is_multi_az());
+
+    obs.delete_bucket().bucket(&bucket_name).send().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bucket_policy() -> Result<(), ObsError> {
+    let obs = common::setup()?;
+    let bucket_name = format!("test-policy-{}", chrono::Utc::now().timestamp());
+
+    obs.create_bucket()
+        .bucket(&bucket_name)
+        .location_constraint("cn-north-4")
+        .send()
+        .await?;
+
+    let policy = Policy::new().statement(Statement::public_read(&bucket_name, "public/"));
+    obs.put_bucket_policy()
+        .bucket(&bucket_name)
+        .policy(policy)
+        .send()
+        .await?;
+
+    let result = obs.get_bucket_policy().bucket(&bucket_name).send().await?;
+    let statements = &result.policy().statements;
+    assert_eq!(statements.len(), 1);
+    assert_eq!(statements[0].principal, Principal::Everyone);
+    assert_eq!(
+        statements[0].resources,
+        [format!("{}/public/*", bucket_name)]
+    );
+
+    obs.delete_bucket_policy()
+        .bucket(&bucket_name)
+        .send()
+        .await?;
+    let result = obs.get_bucket_policy().bucket(&bucket_name).send().await;
+    assert_eq!(
+        result.unwrap_err().status_code(),
+        Some(reqwest::StatusCode::NOT_FOUND)
+    );
+
+    obs.delete_bucket().bucket(&bucket_name).send().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bucket_lifecycle() -> Result<(), ObsError> {
+    let obs = common::setup()?;
+    let bucket_name = format!("test-lifecycle-{}", chrono::Utc::now().timestamp());
+
+    obs.create_bucket()
+        .bucket(&bucket_name)
+        .location_constraint("cn-north-4")
+        .send()
+        .await?;
+
+    let rule = LifecycleRule::new("archive-logs")
+        .prefix("logs/")
+        .transition_after_days(30, StorageClass::Warm)
+        .transition_after_days(60, StorageClass::Cold)
+        .expire_after_days(365)
+        .abort_incomplete_multipart_upload_after_days(7);
+    obs.put_bucket_lifecycle()
+        .bucket(&bucket_name)
+        .rule(rule.clone())
+        .send()
+        .await?;
+
+    let result = obs
+        .get_bucket_lifecycle()
+        .bucket(&bucket_name)
+        .send()
+        .await?;
+    assert_eq!(result.rules(), [rule]);
+
+    obs.delete_bucket_lifecycle()
+        .bucket(&bucket_name)
+        .send()
+        .await?;
+    let result = obs.get_bucket_lifecycle().bucket(&bucket_name).send().await;
+    assert_eq!(
+        result.unwrap_err().status_code(),
+        Some(reqwest::StatusCode::NOT_FOUND)
+    );
+
+    obs.delete_bucket().bucket(&bucket_name).send().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bucket_cors() -> Result<(), ObsError> {
+    let obs = common::setup()?;
+    let bucket_name = format!("test-cors-{}", chrono::Utc::now().timestamp());
+    let origin = "https://app.example.com";
+
+    obs.create_bucket()
+        .bucket(&bucket_name)
+        .location_constraint("cn-north-4")
+        .send()
+        .await?;
+
+    let rule = CorsRule::new()
+        .id("spa")
+        .allow_origin(origin)
+        .allow_method(CorsMethod::Get)
+        .allow_method(CorsMethod::Put)
+        .allow_header("*")
+        .expose_header("ETag")
+        .max_age(3600);
+    obs.put_bucket_cors()
+        .bucket(&bucket_name)
+        .rule(rule.clone())
+        .send()
+        .await?;
+
+    let result = obs.get_bucket_cors().bucket(&bucket_name).send().await?;
+    assert_eq!(result.rules(), [rule]);
+
+    let result = obs
+        .options_bucket()
+        .bucket(&bucket_name)
+        .origin(origin)
+        .request_method(CorsMethod::Put)
+        .request_header("Content-Type")
+        .send()
+        .await?;
+    assert_eq!(result.cors().allow_origin(), origin);
+    assert!(result.cors().allow_methods().contains(&"PUT".to_string()));
+
+    let result = obs
+        .options_object()
+        .bucket(&bucket_name)
+        .key("index.html")
+        .origin(origin)
+        .request_method(CorsMethod::Get)
+        .send()
+        .await?;
+    assert_eq!(result.cors().max_age(), Some(3600));
+
+    // Methods not allowed by any rule are rejected
+    let result = obs
+        .options_object()
+        .bucket(&bucket_name)
+        .key("index.html")
+        .origin(origin)
+        .request_method(CorsMethod::Delete)
+        .send()
+        .await;
+    assert!(result.is_err());
+
+    obs.delete_bucket_cors().bucket(&bucket_name).send().await?;
+    let result = obs.get_bucket_cors().bucket(&bucket_name).send().await;
+    assert_eq!(
+        result.unwrap_err().status_code(),
+        Some(reqwest::StatusCode::NOT_FOUND)
+    );
+
+    obs.delete_bucket().bucket(&bucket_name).send().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bucket_website() -> Result<(), ObsError> {
+    let obs = common::setup()?;
+    let bucket_name = format!("test-website-{}", chrono::Utc::now().timestamp());
+
+    obs.create_bucket()
+        .bucket(&bucket_name)
+        .location_constraint("cn-north-4")
+        .send()
+        .await?;
+
+    let website = WebsiteConfiguration::index("index.html")
+        .error_page("404.html")
+        .routing_rule(
+            RoutingRule::new()
+                .when_key_prefix("docs/v1/")
+                .replace_key_prefix("docs/v2/"),
+        );
+    obs.put_bucket_website()
+        .bucket(&bucket_name)
+        .configuration(website.clone())
+        .send()
+        .await?;
+
+    let result = obs.get_bucket_website().bucket(&bucket_name).send().await?;
+    assert_eq!(result.configuration(), &website);
+
+    // Objects can redirect to another page of the site
+    obs.put_object()
+        .bucket(&bucket_name)
+        .key("old.html")
+        .body(Vec::new())
+        .website_redirect_location("/index.html")
+        .send()
+        .await?;
+    let result = obs
+        .head_object()
+        .bucket(&bucket_name)
+        .key("old.html")
+        .send()
+        .await?;
+    assert_eq!(result.website_redirect_location(), Some("/index.html"));
+    obs.delete_object()
+        .bucket(&bucket_name)
+        .key("old.html")
+        .send()
+        .await?;
+
+    obs.delete_bucket_website()
+        .bucket(&bucket_name)
+        .send()
+        .await?;
+    let result = obs.get_bucket_website().bucket(&bucket_name).send().await;
+    assert_eq!(
+        result.unwrap_err().status_code(),
+        Some(reqwest::StatusCode::NOT_FOUND)
+    );
+
+    obs.delete_bucket().bucket(&bucket_name).send().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bucket_versioning() -> Result<(), ObsError> {
+    let obs = common::setup()?;
+    let bucket_name = format!("test-versioning-{}", chrono::Utc::now().timestamp());
+    let key = "versioned.txt";
+
+    obs.create_bucket()
+        .bucket(&bucket_name)
+        .location_constraint("cn-north-4")
+        .send()
+        .await?;
+
+    // A new bucket has never had versioning enabled
+    let result = obs
+        .get_bucket_versioning()
+        .bucket(&bucket_name)
+        .send()
+        .await?;
+    assert_eq!(result.status(), None);
+
+    obs.put_bucket_versioning()
+        .bucket(&bucket_name)
+        .status("Enabled")
+        .send()
+        .await?;
+    let result = obs
+        .get_bucket_versioning()
+        .bucket(&bucket_name)
+        .send()
+        .await?;
+    assert!(result.is_enabled());
+
+    // Two versions and a delete marker
+    for content in ["v1", "v2"] {
+        obs.put_object()
+            .bucket(&bucket_name)
+            .key(key)
+            .body(content.as_bytes().to_vec())
+            .send()
+            .await?;
+    }
+    let deleted = obs
+        .delete_object()
+        .bucket(&bucket_name)
+        .key(key)
+        .send()
+        .await?;
+    assert!(deleted.delete_marker());
+    assert!(deleted.version_id().is_some());
+
+    let versions = obs
+        .list_object_versions()
+        .bucket(&bucket_name)
+        .send()
+        .await?;
+    assert_eq!(versions.versions().len(), 2);
+    assert_eq!(versions.delete_markers().len(), 1);
+    assert!(versions.delete_markers()[0].is_latest());
+    assert_eq!(
+        versions.delete_markers()[0].version_id(),
+        deleted.version_id().unwrap()
+    );
+    for version in versions.versions() {
+        println!(
+            "  - {} {} (latest: {})",
+            version.key(),
+            version.version_id(),
+            version.is_latest()
+        );
+    }
+
+    // Remove every version so the bucket can be deleted
+    let result = obs
+        .delete_prefix()
+        .bucket(&bucket_name)
+        .prefix(key)
+        .all_versions(true)
+        .send()
+        .await?;
+    assert!(result.is_all_success());
+    assert_eq!(result.deleted().len(), 3);
+
+    obs.delete_bucket().bucket(&bucket_name).send().await?;
+
+    Ok(())
+}
@@ -2,7 +2,7 @@
 
 mod common;
 
-use huaweicloud_sdk_rust_obs::ObsError;
+use huaweicloud_sdk_rust_obs::{CannedAcl, Grant, Grantee, ObsError, Permission, TagSet};
 use std::env;
 
 /// 综合集成测试：测试所有对象操作
@@ -317,6 +317,71 @@ async fn test_copy_object() -> Result<(), ObsError> {
     Ok(())
 }
 
+/// 测试 copy_object - 替换元数据并使用条件复制
+#[tokio::test]
+async fn test_copy_object_replace_metadata() -> Result<(), ObsError> {
+    let obs = common::setup()?;
+    let bucket = env::var("OBS_BUCKET").expect("OBS_BUCKET must be set");
+    let test_id = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let src_key = format!("test-copy-src-{}/源 文件.txt", test_id);
+    let dest_key = format!("test-copy-dest-{}.json", test_id);
+
+    let put_result = obs
+        .put_object()
+        .bucket(&bucket)
+        .key(&src_key)
+        .body(b"{}".to_vec())
+        .content_type("text/plain")
+        .send()
+        .await?;
+    let etag = put_result.etag().expect("ETag should exist").to_string();
+
+    // ETag 不匹配时应拒绝复制
+    let result = obs
+        .copy_object()
+        .bucket(&bucket)
+        .key(&dest_key)
+        .copy_source(format!("{}/{}", bucket, src_key))
+        .copy_source_if_match("mismatched-etag")
+        .send()
+        .await;
+    assert!(result.is_err(), "Copy with mismatched ETag should fail");
+
+    // ETag 匹配时使用 REPLACE 替换元数据
+    obs.copy_object()
+        .bucket(&bucket)
+        .key(&dest_key)
+        .copy_source(format!("{}/{}", bucket, src_key))
+        .copy_source_if_match(&etag)
+        .metadata_directive("REPLACE")
+        .content_type("application/json")
+        .metadata("origin", "copy")
+        .send()
+        .await?;
+
+    let head_result = obs
+        .head_object()
+        .bucket(&bucket)
+        .key(&dest_key)
+        .send()
+        .await?;
+    assert_eq!(head_result.content_type(), Some("application/json"));
+
+    // 清理
+    obs.delete_object()
+        .bucket(&bucket)
+        .key(&src_key)
+        .send()
+        .await?;
+    obs.delete_object()
+        .bucket(&bucket)
+        .key(&dest_key)
+        .send()
+        .await?;
+
+    Ok(())
+}
+
 /// 单独测试 append_object
 #[tokio::test]
 async fn test_append_object() -> Result<(), ObsError> {
@@ -368,6 +433,203 @@ async fn test_append_object() -> Result<(), ObsError> {
     Ok(())
 }
 
+/// 测试 append_writer：自动追踪追加位置、断点续写以及位置冲突
+#[tokio::test]
+async fn test_append_writer() -> Result<(), ObsError> {
+    use tokio::io::AsyncWriteExt;
+
+    let obs = common::setup()?;
+    let bucket = env::var("OBS_BUCKET").expect("OBS_BUCKET must be set");
+    let key = format!(
+        "test-append-writer-{}.log",
+        chrono::Utc::now().timestamp_millis()
+    );
+
+    // 写入超过 flush_size 的数据，触发多次追加
+    let mut writer = obs
+        .append_writer()
+        .bucket(&bucket)
+        .key(&key)
+        .flush_size(8)
+        .content_type("text/plain")
+        .build()
+        .await?;
+    assert_eq!(writer.position(), 0);
+    writer.write_all(b"line one\nline two\n").await?;
+    writer.shutdown().await?;
+    assert_eq!(writer.position(), 18);
+
+    // 新建 writer 时从 next-append-position 续写
+    let mut resumed = obs
+        .append_writer()
+        .bucket(&bucket)
+        .key(&key)
+        .build()
+        .await?;
+    assert_eq!(resumed.position(), 18);
+    resumed.write_all(b"line three\n").await?;
+    resumed.flush().await?;
+
+    let get_result = obs.get_object().bucket(&bucket).key(&key).send().await?;
+    assert_eq!(
+        get_result.body().as_ref(),
+        b"line one\nline two\nline three\n"
+    );
+
+    // 使用过期的位置追加，应返回 AppendPositionConflict
+    let mut stale = obs
+        .append_writer()
+        .bucket(&bucket)
+        .key(&key)
+        .position(18)
+        .build()
+        .await?;
+    stale.write_all(b"stale\n").await?;
+    let err = stale
+        .shutdown()
+        .await
+        .expect_err("stale position must conflict");
+    match err.get_ref().and_then(|e| e.downcast_ref::<ObsError>()) {
+        Some(ObsError::AppendPositionConflict { position, .. }) => assert_eq!(*position, 18),
+        other => panic!("unexpected error: {:?}", other),
+    }
+    assert_eq!(stale.buffered(), 6);
+
+    // Clean up
+    obs.delete_object().bucket(&bucket).key(&key).send().await?;
+
+    Ok(())
+}
+
+/// 测试 object_writer：未知长度的数据流式写入，超过分段大小时自动使用分段上传
+#[tokio::test]
+async fn test_object_writer() -> Result<(), ObsError> {
+    use tokio::io::AsyncWriteExt;
+
+    let obs = common::setup()?;
+    let bucket = env::var("OBS_BUCKET").expect("OBS_BUCKET must be set");
+    let test_id = chrono::Utc::now().timestamp_millis();
+    let key = format!("test-object-writer-{}.bin", test_id);
+    let small_key = format!("test-object-writer-small-{}.txt", test_id);
+
+    // 写入约 250 KB 数据，分段大小 100 KiB，触发分段上传
+    let data: Vec<u8> = (0..250 * 1024).map(|i| (i % 251) as u8).collect();
+    let mut writer = obs
+        .object_writer()
+        .bucket(&bucket)
+        .key(&key)
+        .part_size(100 * 1024)
+        .concurrency(2)
+        .build()?;
+    for chunk in data.chunks(30 * 1024) {
+        writer.write_all(chunk).await?;
+    }
+    assert!(writer.upload_id().is_some());
+    writer.shutdown().await?;
+    assert_eq!(writer.bytes_written(), data.len() as u64);
+    assert!(writer.etag().is_some());
+
+    let output = obs.get_object().bucket(&bucket).key(&key).send().await?;
+    assert_eq!(output.body().as_ref(), data.as_slice());
+
+    // 小于分段大小的数据直接使用 put_object 上传
+    let mut small = obs
+        .object_writer()
+        .bucket(&bucket)
+        .key(&small_key)
+        .content_type("text/plain")
+        .build()?;
+    small.write_all(b"hello object writer").await?;
+    small.shutdown().await?;
+    assert!(small.upload_id().is_none());
+
+    let output = obs
+        .get_object()
+        .bucket(&bucket)
+        .key(&small_key)
+        .send()
+        .await?;
+    assert_eq!(output.body().as_ref(), b"hello object writer");
+
+    // Clean up
+    obs.delete_object().bucket(&bucket).key(&key).send().await?;
+    obs.delete_object()
+        .bucket(&bucket)
+        .key(&small_key)
+        .send()
+        .await?;
+
+    Ok(())
+}
+
+/// 测试 object_reader：范围读取、随机定位、预读以及 ETag 固定
+#[tokio::test]
+async fn test_object_reader() -> Result<(), ObsError> {
+    use std::io::SeekFrom;
+    use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+    let obs = common::setup()?;
+    let bucket = env::var("OBS_BUCKET").expect("OBS_BUCKET must be set");
+    let key = format!(
+        "test-object-reader-{}.bin",
+        chrono::Utc::now().timestamp_millis()
+    );
+
+    let data: Vec<u8> = (0..300 * 1024).map(|i| (i % 251) as u8).collect();
+    obs.put_object()
+        .bucket(&bucket)
+        .key(&key)
+        .body(data.clone())
+        .send()
+        .await?;
+
+    let mut reader = obs
+        .object_reader()
+        .bucket(&bucket)
+        .key(&key)
+        .block_size(64 * 1024)
+        .read_ahead(1)
+        .cache_blocks(2)
+        .build()
+        .await?;
+    assert_eq!(reader.size(), data.len() as u64);
+    assert!(reader.etag().is_some());
+
+    // 读取末尾 8 字节（跨块边界之外的随机读取）
+    let mut tail = [0u8; 8];
+    reader.seek(SeekFrom::End(-8)).await?;
+    reader.read_exact(&mut tail).await?;
+    assert_eq!(&tail[..], &data[data.len() - 8..]);
+
+    // 跨越块边界读取
+    let mut middle = vec![0u8; 1000];
+    reader.seek(SeekFrom::Start(64 * 1024 - 500)).await?;
+    reader.read_exact(&mut middle).await?;
+    assert_eq!(middle, &data[64 * 1024 - 500..64 * 1024 + 500]);
+
+    // 从头顺序读取整个对象
+    reader.seek(SeekFrom::Start(0)).await?;
+    let mut all = Vec::new();
+    reader.read_to_end(&mut all).await?;
+    assert_eq!(all, data);
+
+    // 对象被覆盖后，读取未缓存的块应失败
+    obs.put_object()
+        .bucket(&bucket)
+        .key(&key)
+        .body(b"overwritten".to_vec())
+        .send()
+        .await?;
+    reader.seek(SeekFrom::Start(0)).await?;
+    let mut buf = [0u8; 16];
+    assert!(reader.read_exact(&mut buf).await.is_err());
+
+    // Clean up
+    obs.delete_object().bucket(&bucket).key(&key).send().await?;
+
+    Ok(())
+}
+
 /// 单独测试 delete_object
 #[tokio::test]
 async fn test_delete_object() -> Result<(), ObsError> {
@@ -563,8 +825,91 @@ async fn test_delete_objects_with_nonexistent() -> Result<(), ObsError> {
     Ok(())
 }
 
+/// 测试批量删除超过 1000 个对象时自动分批
+#[tokio::test]
+async fn test_delete_objects_batching() -> Result<(), ObsError> {
+    let obs = common::setup()?;
+    let bucket = env::var("OBS_BUCKET").expect("OBS_BUCKET must be set");
+    let test_id = chrono::Utc::now().timestamp_millis();
+
+    // 删除不存在的对象同样返回成功，可用于验证分批结果的合并
+    let keys: Vec<String> = (0..2500)
+        .map(|i| format!("test-delete-batch-{}/{:04}.txt", test_id, i))
+        .collect();
+    let result = obs
+        .delete_objects()
+        .bucket(&bucket)
+        .keys(keys)
+        .concurrency(2)
+        .send()
+        .await?;
+
+    assert!(result.is_all_success());
+    assert_eq!(result.deleted().len(), 2500);
+
+    Ok(())
+}
+
+/// 测试按前缀删除：先试运行，再实际删除
+#[tokio::test]
+async fn test_delete_prefix() -> Result<(), ObsError> {
+    let obs = common::setup()?;
+    let bucket = env::var("OBS_BUCKET").expect("OBS_BUCKET must be set");
+    let prefix = format!(
+        "test-delete-prefix-{}/",
+        chrono::Utc::now().timestamp_millis()
+    );
+
+    for i in 0..5 {
+        obs.put_object()
+            .bucket(&bucket)
+            .key(format!("{}sub/{}.txt", prefix, i))
+            .body(b"test content".to_vec())
+            .send()
+            .await?;
+    }
+
+    // 试运行只列出对象，不删除
+    let preview = obs
+        .delete_prefix()
+        .bucket(&bucket)
+        .prefix(&prefix)
+        .dry_run(true)
+        .send()
+        .await?;
+    assert!(preview.is_dry_run());
+    assert_eq!(preview.deleted().len(), 5);
+    obs.head_object()
+        .bucket(&bucket)
+        .key(format!("{}sub/0.txt", prefix))
+        .send()
+        .await?;
+
+    // 实际删除
+    let result = obs
+        .delete_prefix()
+        .bucket(&bucket)
+        .prefix(&prefix)
+        .send()
+        .await?;
+    assert!(result.is_all_success());
+    assert_eq!(result.deleted().len(), 5);
+
+    let page = obs
+        .list_objects()
+        .bucket(&bucket)
+        .prefix(&prefix)
+        .delimiter("")
+        .send()
+        .await?;
+    assert!(page.contents().is_empty(), "前缀下的对象应该已被删除");
+
+    Ok(())
+}
+
 /// 测试对象 ACL 操作
 #[tokio::test]
+#[allow(deprecated)]
 async fn test_object_acl() -> Result<(), ObsError> {
     let obs = common::setup()?;
     let bucket = env::var("OBS_BUCKET").expect("OBS_BUCKET must be set");
@@ -646,6 +991,58 @@ async fn test_object_acl() -> Result<(), ObsError> {
     Ok(())
 }
 
+/// 测试上传时设置预定义 ACL，并读取-修改-写回对象 ACL
+#[tokio::test]
+async fn test_object_acl_update() -> Result<(), ObsError> {
+    let obs = common::setup()?;
+    let bucket = env::var("OBS_BUCKET").expect("OBS_BUCKET must be set");
+    let key = format!(
+        "test-acl-update-{}.txt",
+        chrono::Utc::now().timestamp_millis()
+    );
+
+    // 1. 上传时设置 public-read
+    obs.put_object()
+        .bucket(&bucket)
+        .key(&key)
+        .body(b"test content for acl".to_vec())
+        .canned_acl(CannedAcl::PublicRead)
+        .send()
+        .await?;
+
+    let mut acl = obs
+        .get_object_acl()
+        .bucket(&bucket)
+        .key(&key)
+        .send()
+        .await?;
+    let public_read = Grant::new(Grantee::everyone(), Permission::Read);
+    assert!(acl.grants().contains(&public_read));
+
+    // 2. 去掉公共读，再给拥有者增加 READ_ACP 后写回
+    assert!(acl.remove_grant(&Grantee::everyone(), None));
+    let owner_id = acl.owner().id().to_string();
+    acl.add_grant(Grant::new(Grantee::by_id(&owner_id), Permission::ReadAcp));
+    acl.write_back(&obs).send().await?;
+
+    // 3. 验证写回结果
+    let acl = obs
+        .get_object_acl()
+        .bucket(&bucket)
+        .key(&key)
+        .send()
+        .await?;
+    assert!(!acl.grants().contains(&public_read));
+    assert!(acl
+        .grants()
+        .iter()
+        .any(|g| g.grantee().id() == Some(owner_id.as_str())));
+
+    obs.delete_object().bucket(&bucket).key(&key).send().await?;
+
+    Ok(())
+}
+
 /// 测试流式上传
 #[tokio::test]
 async fn test_streaming_upload() -> Result<(), ObsError> {
@@ -802,3 +1199,171 @@ async fn test_streaming_upload_without_content_length() -> Result<(), ObsError>
 
     Ok(())
 }
+
+/// 测试恢复归档对象
+#[tokio::test]
+async fn test_restore_object() -> Result<(), ObsError> {
+    let obs = common::setup()?;
+    let bucket = env::var("OBS_BUCKET").expect("OBS_BUCKET must be set");
+    let key = format!(
+        "test-restore-{}.txt",
+        chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0)
+    );
+
+    // 上传一个冷存储对象
+    obs.put_object()
+        .bucket(&bucket)
+        .key(&key)
+        .body(b"archived content".to_vec())
+        .storage_class("COLD")
+        .send()
+        .await?;
+
+    // 发起恢复
+    let restore_result = obs
+        .restore_object()
+        .bucket(&bucket)
+        .key(&key)
+        .days(1)
+        .tier("Expedited")
+        .send()
+        .await?;
+    assert!(!restore_result.already_restored());
+
+    // 恢复中的对象应带有 x-obs-restore 头
+    let head_result = obs.head_object().bucket(&bucket).key(&key).send().await?;
+    let restore = head_result.restore().expect("Restore status should exist");
+    println!("Restore ongoing: {}", restore.is_ongoing());
+
+    // 清理
+    obs.delete_object().bucket(&bucket).key(&key).send().await?;
+
+    Ok(())
+}
+
+/// 测试修改对象元数据
+#[tokio::test]
+async fn test_set_object_metadata() -> Result<(), ObsError> {
+    let obs = common::setup()?;
+    let bucket = env::var("OBS_BUCKET").expect("OBS_BUCKET must be set");
+    let key = format!(
+        "test-metadata-{}.json",
+        chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0)
+    );
+
+    let mut metadata = std::collections::HashMap::new();
+    metadata.insert("owner".to_string(), "test".to_string());
+
+    obs.put_object()
+        .bucket(&bucket)
+        .key(&key)
+        .body(b"{}".to_vec())
+        .content_type("text/plain")
+        .metadata(metadata)
+        .send()
+        .await?;
+
+    // REPLACE_NEW: 修改 Content-Type 并新增元数据，保留原有元数据
+    obs.set_object_metadata()
+        .bucket(&bucket)
+        .key(&key)
+        .metadata_directive("REPLACE_NEW")
+        .content_type("application/json")
+        .cache_control("max-age=3600")
+        .metadata("source", "sdk")
+        .send()
+        .await?;
+
+    let head_result = obs.head_object().bucket(&bucket).key(&key).send().await?;
+    assert_eq!(head_result.content_type(), Some("application/json"));
+    assert_eq!(head_result.cache_control(), Some("max-age=3600"));
+    assert_eq!(
+        head_result.metadata().get("owner").map(|s| s.as_str()),
+        Some("test")
+    );
+    assert_eq!(
+        head_result.metadata().get("source").map(|s| s.as_str()),
+        Some("sdk")
+    );
+
+    // 缺少 metadata_directive 应该报错
+    let result = obs
+        .set_object_metadata()
+        .bucket(&bucket)
+        .key(&key)
+        .send()
+        .await;
+    assert!(matches!(result, Err(ObsError::InvalidInput(_))));
+
+    // 清理
+    obs.delete_object().bucket(&bucket).key(&key).send().await?;
+
+    Ok(())
+}
+
+/// 测试对象标签操作
+#[tokio::test]
+async fn test_object_tagging() -> Result<(), ObsError> {
+    let obs = common::setup()?;
+    let bucket = env::var("OBS_BUCKET").expect("OBS_BUCKET must be set");
+    let key = format!(
+        "test-tagging-{}.txt",
+        chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0)
+    );
+
+    // 上传时设置标签
+    obs.put_object()
+        .bucket(&bucket)
+        .key(&key)
+        .body(b"tagged content".to_vec())
+        .tag_set(TagSet::new().tag("project", "lake"))
+        .send()
+        .await?;
+
+    let tagging = obs
+        .get_object_tagging()
+        .bucket(&bucket)
+        .key(&key)
+        .send()
+        .await?;
+    assert_eq!(tagging.tag_set().get("project"), Some("lake"));
+
+    // 替换标签
+    obs.put_object_tagging()
+        .bucket(&bucket)
+        .key(&key)
+        .tag("cost-center", "42")
+        .tag("env", "test")
+        .send()
+        .await?;
+
+    let tagging = obs
+        .get_object_tagging()
+        .bucket(&bucket)
+        .key(&key)
+        .send()
+        .await?;
+    assert_eq!(tagging.tag_set().len(), 2);
+    assert_eq!(tagging.tag_set().get("project"), None);
+    assert_eq!(tagging.tag_set().get("cost-center"), Some("42"));
+
+    // 删除标签
+    obs.delete_object_tagging()
+        .bucket(&bucket)
+        .key(&key)
+        .send()
+        .await?;
+
+    let tagging = obs
+        .get_object_tagging()
+        .bucket(&bucket)
+        .key(&key)
+        .send()
+        .await?;
+    assert!(tagging.tag_set().is_empty());
+
+    // 清理
+    obs.delete_object().bucket(&bucket).key(&key).send().await?;
+
+    Ok(())
+}
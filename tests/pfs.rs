@@ -0,0 +1,97 @@
+//! Tests for parallel file system operations
+
+mod common;
+
+use huaweicloud_sdk_rust_obs::ObsError;
+use std::env;
+
+/// 综合集成测试：并行文件系统操作
+/// 按顺序测试：modify_object -> truncate_object -> rename_object -> rename_directory -> delete_directory
+#[tokio::test]
+async fn test_pfs_operations_integration() -> Result<(), ObsError> {
+    let obs = common::setup()?;
+    let Ok(bucket) = env::var("OBS_PFS_BUCKET") else {
+        println!("OBS_PFS_BUCKET not set, skipping test");
+        return Ok(());
+    };
+
+    let test_id = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let dir = format!("test-pfs-{}", test_id);
+    let key = format!("{}/sub/data.bin", dir);
+
+    // 1. 写入并修改对象
+    obs.put_object()
+        .bucket(&bucket)
+        .key(&key)
+        .body(b"0123456789".to_vec())
+        .send()
+        .await?;
+
+    obs.modify_object()
+        .bucket(&bucket)
+        .key(&key)
+        .position(2)
+        .body(b"ab".to_vec())
+        .send()
+        .await?;
+
+    let get_result = obs.get_object().bucket(&bucket).key(&key).send().await?;
+    assert_eq!(get_result.body().as_ref(), b"01ab456789");
+
+    // 2. 截断对象
+    obs.truncate_object()
+        .bucket(&bucket)
+        .key(&key)
+        .length(4)
+        .send()
+        .await?;
+
+    let head_result = obs.head_object().bucket(&bucket).key(&key).send().await?;
+    assert_eq!(head_result.content_length(), Some(4));
+
+    // 3. 重命名对象（新名称包含需要编码的字符）
+    let renamed_key = format!("{}/sub/a&b +测试.bin", dir);
+    obs.rename_object()
+        .bucket(&bucket)
+        .key(&key)
+        .new_key(&renamed_key)
+        .send()
+        .await?;
+
+    assert!(obs
+        .head_object()
+        .bucket(&bucket)
+        .key(&key)
+        .send()
+        .await
+        .is_err());
+
+    // 4. 重命名目录
+    let renamed_dir = format!("{}-renamed", dir);
+    obs.rename_directory()
+        .bucket(&bucket)
+        .directory(&dir)
+        .new_directory(&renamed_dir)
+        .send()
+        .await?;
+
+    let moved_key = format!("{}/sub/a&b +测试.bin", renamed_dir);
+    obs.head_object()
+        .bucket(&bucket)
+        .key(&moved_key)
+        .send()
+        .await?;
+
+    // 5. 递归删除目录
+    let delete_result = obs
+        .delete_directory()
+        .bucket(&bucket)
+        .directory(&renamed_dir)
+        .send()
+        .await?;
+
+    println!("Deleted {} entries", delete_result.deleted_count());
+    assert!(delete_result.is_all_success());
+
+    Ok(())
+}
@@ -0,0 +1,285 @@
+//! Tests for transfer helpers
+
+mod common;
+
+use huaweicloud_sdk_rust_obs::{ObsError, ProgressListener};
+use std::env;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// 记录进度事件的监听器
+#[derive(Default)]
+struct Recorder {
+    transferred: AtomicU64,
+    parts: AtomicU64,
+}
+
+impl ProgressListener for Recorder {
+    fn on_progress(&self, transferred: u64, _total: Option<u64>) {
+        self.transferred.store(transferred, Ordering::SeqCst);
+    }
+
+    fn on_part_completed(&self, _part_number: i32, _size: u64) {
+        self.parts.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// 测试 download_file：并发分段下载到本地文件
+#[tokio::test]
+async fn test_download_file() -> Result<(), ObsError> {
+    let obs = common::setup()?;
+    let bucket = env::var("OBS_BUCKET").expect("OBS_BUCKET must be set");
+    let test_id = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let key = format!("test-download-file-{}.bin", test_id);
+
+    // 上传 100 KB 测试数据
+    let data: Vec<u8> = (0..100 * 1024).map(|i| (i % 251) as u8).collect();
+    obs.put_object()
+        .bucket(&bucket)
+        .key(&key)
+        .body(data.clone())
+        .send()
+        .await?;
+
+    // 以 16 KB 分段、3 并发下载
+    let file = env::temp_dir().join(format!("obs-download-{}.bin", test_id));
+    let result = obs
+        .download_file()
+        .bucket(&bucket)
+        .key(&key)
+        .file(&file)
+        .part_size(16 * 1024)
+        .concurrency(3)
+        .send()
+        .await?;
+
+    assert_eq!(result.content_length(), data.len() as u64);
+    assert!(!result.resumed());
+    assert_eq!(std::fs::read(&file)?, data);
+
+    // 下载完成后不应残留检查点文件
+    let checkpoint = format!("{}.checkpoint", file.display());
+    assert!(!std::path::Path::new(&checkpoint).exists());
+
+    // Clean up
+    std::fs::remove_file(&file)?;
+    obs.delete_object().bucket(&bucket).key(&key).send().await?;
+
+    Ok(())
+}
+
+/// 测试 upload_file：并发分段上传本地文件
+#[tokio::test]
+async fn test_upload_file() -> Result<(), ObsError> {
+    let obs = common::setup()?;
+    let bucket = env::var("OBS_BUCKET").expect("OBS_BUCKET must be set");
+    let test_id = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let key = format!("test-upload-file-{}.bin", test_id);
+
+    // 生成 350 KB 测试文件
+    let data: Vec<u8> = (0..350 * 1024).map(|i| (i % 251) as u8).collect();
+    let file = env::temp_dir().join(format!("obs-upload-{}.bin", test_id));
+    std::fs::write(&file, &data)?;
+
+    // 以 100 KB 分段（共 4 段）、2 并发上传
+    let recorder = Arc::new(Recorder::default());
+    let result = obs
+        .upload_file()
+        .bucket(&bucket)
+        .key(&key)
+        .file(&file)
+        .part_size(100 * 1024)
+        .concurrency(2)
+        .content_type("application/octet-stream")
+        .progress_listener(recorder.clone())
+        .send()
+        .await?;
+
+    assert_eq!(result.file_size(), data.len() as u64);
+    assert_eq!(
+        recorder.transferred.load(Ordering::SeqCst),
+        data.len() as u64
+    );
+    assert_eq!(recorder.parts.load(Ordering::SeqCst), 4);
+    assert!(result.upload_id().is_some());
+    assert!(!result.resumed());
+
+    // 上传完成后不应残留检查点文件
+    let checkpoint = format!("{}.upload-checkpoint", file.display());
+    assert!(!std::path::Path::new(&checkpoint).exists());
+
+    // 验证对象内容
+    let output = obs.get_object().bucket(&bucket).key(&key).send().await?;
+    assert_eq!(output.body(), &data[..]);
+
+    // Clean up
+    std::fs::remove_file(&file)?;
+    obs.delete_object().bucket(&bucket).key(&key).send().await?;
+
+    Ok(())
+}
+
+/// 测试 TransferManager：目录上传与下载
+#[tokio::test]
+async fn test_transfer_manager_directory() -> Result<(), ObsError> {
+    let obs = common::setup()?;
+    let bucket = env::var("OBS_BUCKET").expect("OBS_BUCKET must be set");
+    let test_id = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let prefix = format!("test-transfer-manager-{}", test_id);
+
+    // 准备本地目录：一个小文件、一个需要分段的文件
+    let source = env::temp_dir().join(format!("obs-tm-src-{}", test_id));
+    std::fs::create_dir_all(source.join("nested"))?;
+    let small = b"hello transfer manager".to_vec();
+    let large: Vec<u8> = (0..300 * 1024).map(|i| (i % 251) as u8).collect();
+    std::fs::write(source.join("small.txt"), &small)?;
+    std::fs::write(source.join("nested/large.bin"), &large)?;
+
+    // 阈值 200 KB、分段 100 KB，大文件走分段上传
+    let manager = obs
+        .transfer_manager()
+        .multipart_threshold(200 * 1024)
+        .part_size(100 * 1024)
+        .max_concurrency(4)
+        .max_memory(1024 * 1024)
+        .build()?;
+
+    let upload = manager.upload_directory(&bucket, &prefix, &source);
+    let output = upload.join().await?;
+    assert!(output.is_all_success());
+    assert_eq!(output.transferred().len(), 2);
+    assert_eq!(
+        output.bytes_transferred(),
+        (small.len() + large.len()) as u64
+    );
+    let large_output = output
+        .transferred()
+        .iter()
+        .find(|t| t.key().ends_with("nested/large.bin"))
+        .expect("large file uploaded");
+    assert!(large_output.is_multipart());
+
+    // 下载到新目录并校验内容
+    let target = env::temp_dir().join(format!("obs-tm-dst-{}", test_id));
+    let download = manager.download_directory(&bucket, &prefix, &target);
+    let output = download.join().await?;
+    assert!(output.is_all_success());
+    assert_eq!(std::fs::read(target.join("small.txt"))?, small);
+    assert_eq!(std::fs::read(target.join("nested/large.bin"))?, large);
+
+    // Clean up
+    std::fs::remove_dir_all(&source)?;
+    std::fs::remove_dir_all(&target)?;
+    for key in ["small.txt", "nested/large.bin"] {
+        obs.delete_object()
+            .bucket(&bucket)
+            .key(format!("{}/{}", prefix, key))
+            .send()
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// 测试 copy_large_object：并发 copy_part 分段复制并保留元数据（含 Cache-Control、Content-Disposition）
+#[tokio::test]
+async fn test_copy_large_object() -> Result<(), ObsError> {
+    let obs = common::setup()?;
+    let bucket = env::var("OBS_BUCKET").expect("OBS_BUCKET must be set");
+    let test_id = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let source_key = format!("test-copy-large-source-{}.bin", test_id);
+    let dest_key = format!("test-copy-large-dest-{}.bin", test_id);
+    let replaced_key = format!("test-copy-large-replaced-{}.bin", test_id);
+
+    // 上传 350 KB 带自定义元数据的源对象
+    let data: Vec<u8> = (0..350 * 1024).map(|i| (i % 251) as u8).collect();
+    let mut metadata = std::collections::HashMap::new();
+    metadata.insert("origin".to_string(), "copy-test".to_string());
+    obs.put_object()
+        .bucket(&bucket)
+        .key(&source_key)
+        .body(data.clone())
+        .content_type("application/x-test")
+        .cache_control("max-age=60")
+        .content_disposition("attachment; filename=\"data.bin\"")
+        .website_redirect_location("/index.html")
+        .metadata(metadata)
+        .send()
+        .await?;
+
+    // 以 100 KB 分段（共 4 段）、2 并发复制，默认保留源对象元数据
+    let recorder = Arc::new(Recorder::default());
+    let result = obs
+        .copy_large_object()
+        .bucket(&bucket)
+        .key(&dest_key)
+        .copy_source(format!("{}/{}", bucket, source_key))
+        .part_size(100 * 1024)
+        .concurrency(2)
+        .progress_listener(recorder.clone())
+        .send()
+        .await?;
+
+    assert_eq!(result.size(), data.len() as u64);
+    assert!(result.upload_id().is_some());
+    assert_eq!(recorder.parts.load(Ordering::SeqCst), 4);
+    assert_eq!(
+        recorder.transferred.load(Ordering::SeqCst),
+        data.len() as u64
+    );
+
+    let output = obs
+        .get_object()
+        .bucket(&bucket)
+        .key(&dest_key)
+        .send()
+        .await?;
+    assert_eq!(output.body(), &data[..]);
+    let head = obs
+        .head_object()
+        .bucket(&bucket)
+        .key(&dest_key)
+        .send()
+        .await?;
+    assert_eq!(head.content_type(), Some("application/x-test"));
+    assert_eq!(head.cache_control(), Some("max-age=60"));
+    assert_eq!(
+        head.content_disposition(),
+        Some("attachment; filename=\"data.bin\"")
+    );
+    assert_eq!(head.website_redirect_location(), Some("/index.html"));
+    assert_eq!(
+        head.metadata().get("origin").map(|s| s.as_str()),
+        Some("copy-test")
+    );
+
+    // REPLACE 指令使用新的元数据
+    obs.copy_large_object()
+        .bucket(&bucket)
+        .key(&replaced_key)
+        .copy_source(format!("{}/{}", bucket, source_key))
+        .part_size(100 * 1024)
+        .metadata_directive("REPLACE")
+        .content_type("text/plain")
+        .metadata("origin", "replaced")
+        .send()
+        .await?;
+    let head = obs
+        .head_object()
+        .bucket(&bucket)
+        .key(&replaced_key)
+        .send()
+        .await?;
+    assert_eq!(head.content_type(), Some("text/plain"));
+    assert_eq!(
+        head.metadata().get("origin").map(|s| s.as_str()),
+        Some("replaced")
+    );
+
+    // Clean up
+    for key in [&source_key, &dest_key, &replaced_key] {
+        obs.delete_object().bucket(&bucket).key(key).send().await?;
+    }
+
+    Ok(())
+}
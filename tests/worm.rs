@@ -0,0 +1,81 @@
+//! Tests for WORM retention operations
+
+mod common;
+
+use huaweicloud_sdk_rust_obs::ObsError;
+use std::env;
+
+/// 综合集成测试：WORM 保护策略
+/// 按顺序测试：get_bucket_object_lock_configuration -> put_object_retention -> get_object_retention -> head_object
+///
+/// 注意：受保护的对象在保护期（1 天）结束前无法删除
+#[tokio::test]
+async fn test_worm_retention_integration() -> Result<(), ObsError> {
+    let obs = common::setup()?;
+    let Ok(bucket) = env::var("OBS_WORM_BUCKET") else {
+        println!("OBS_WORM_BUCKET not set, skipping test");
+        return Ok(());
+    };
+
+    // 1. 确认桶已开启 WORM
+    let lock_config = obs
+        .get_bucket_object_lock_configuration()
+        .bucket(&bucket)
+        .send()
+        .await?;
+    assert!(lock_config.object_lock_enabled());
+
+    // 2. 上传对象并设置保护期
+    let key = format!(
+        "test-worm-{}.txt",
+        chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0)
+    );
+    let put_result = obs
+        .put_object()
+        .bucket(&bucket)
+        .key(&key)
+        .body(b"immutable".to_vec())
+        .send()
+        .await?;
+
+    let retain_until = chrono::Utc::now() + chrono::Duration::days(1);
+    let mut put_retention = obs
+        .put_object_retention()
+        .bucket(&bucket)
+        .key(&key)
+        .retain_until_date(retain_until);
+    if let Some(version_id) = put_result.version_id() {
+        put_retention = put_retention.version_id(version_id);
+    }
+    put_retention.send().await?;
+
+    // 3. 读取保护策略
+    let get_result = obs
+        .get_object_retention()
+        .bucket(&bucket)
+        .key(&key)
+        .send()
+        .await?;
+    assert_eq!(get_result.retention().mode(), "COMPLIANCE");
+    assert_eq!(
+        get_result.retention().retain_until_date().timestamp(),
+        retain_until.timestamp()
+    );
+    assert!(get_result.retention().is_active());
+
+    // 4. HeadObject 返回保护信息
+    let head_result = obs.head_object().bucket(&bucket).key(&key).send().await?;
+    let retention = head_result
+        .retention()
+        .expect("retention should be returned");
+    assert_eq!(retention.mode(), "COMPLIANCE");
+
+    // 5. 保护期内删除应失败
+    let mut delete = obs.delete_object().bucket(&bucket).key(&key);
+    if let Some(version_id) = put_result.version_id() {
+        delete = delete.version_id(version_id);
+    }
+    assert!(delete.send().await.is_err());
+
+    Ok(())
+}